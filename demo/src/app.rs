@@ -56,13 +56,17 @@ fn TheRouter(is_routing: RwSignal<bool>) -> impl IntoView {
                 <Route path="/button" view=ButtonMdPage/>
                 <Route path="/calendar" view=CalendarMdPage/>
                 <Route path="/card" view=CardMdPage/>
+                <Route path="/cascader" view=CascaderMdPage/>
                 <Route path="/checkbox" view=CheckboxMdPage/>
                 <Route path="/collapse" view=CollapseMdPage/>
                 <Route path="/color-picker" view=ColorPickerMdPage/>
+                <Route path="/config-provider" view=ConfigProviderMdPage/>
                 <Route path="/date-picker" view=DatePickerMdPage/>
+                <Route path="/dialog" view=DialogMdPage/>
                 <Route path="/divider" view=DividerMdPage/>
                 <Route path="/drawer" view=DrawerMdPage/>
                 <Route path="/dropdown" view=DropdownMdPage/>
+                <Route path="/form" view=FormMdPage/>
                 <Route path="/grid" view=GridMdPage/>
                 <Route path="/icon" view=IconMdPage/>
                 <Route path="/image" view=ImageMdPage/>
@@ -73,6 +77,7 @@ fn TheRouter(is_routing: RwSignal<bool>) -> impl IntoView {
                 <Route path="/menu" view=MenuMdPage/>
                 <Route path="/message" view=MessageMdPage/>
                 <Route path="/modal" view=ModalMdPage/>
+                <Route path="/notification" view=NotificationMdPage/>
                 <Route path="/pagination" view=PaginationMdPage/>
                 <Route path="/popover" view=PopoverMdPage/>
                 <Route path="/progress" view=ProgressMdPage/>
@@ -83,12 +88,16 @@ fn TheRouter(is_routing: RwSignal<bool>) -> impl IntoView {
                 <Route path="/slider" view=SliderMdPage/>
                 <Route path="/space" view=SpaceMdPage/>
                 <Route path="/spinner" view=SpinnerMdPage/>
+                <Route path="/steps" view=StepsMdPage/>
                 <Route path="/switch" view=SwitchMdPage/>
                 <Route path="/table" view=TableMdPage/>
                 <Route path="/tabs" view=TabsMdPage/>
                 <Route path="/tag" view=TagMdPage/>
                 <Route path="/theme" view=ThemeMdPage/>
                 <Route path="/time-picker" view=TimePickerMdPage/>
+                <Route path="/transfer" view=TransferMdPage/>
+                <Route path="/tree" view=TreeMdPage/>
+                <Route path="/tree-select" view=TreeSelectMdPage/>
                 <Route path="/typography" view=TypographyMdPage/>
                 <Route path="/upload" view=UploadMdPage/>
             </Route>
@@ -120,7 +129,11 @@ fn TheProvider(children: Children) -> impl IntoView {
         <ThemeProvider theme>
             <GlobalStyle/>
             <MessageProvider>
-                <LoadingBarProvider>{children()}</LoadingBarProvider>
+                <NotificationProvider>
+                    <DialogProvider>
+                        <LoadingBarProvider>{children()}</LoadingBarProvider>
+                    </DialogProvider>
+                </NotificationProvider>
             </MessageProvider>
         </ThemeProvider>
     }
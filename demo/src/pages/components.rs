@@ -155,6 +155,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "auto-complete".into(),
                     label: "Auto Complete".into(),
                 },
+                MenuItemOption {
+                    value: "cascader".into(),
+                    label: "Cascader".into(),
+                },
                 MenuItemOption {
                     value: "color-picker".into(),
                     label: "Color Picker".into(),
@@ -167,6 +171,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "date-picker".into(),
                     label: "Date Picker".into(),
                 },
+                MenuItemOption {
+                    value: "form".into(),
+                    label: "Form".into(),
+                },
                 MenuItemOption {
                     value: "input".into(),
                     label: "Input".into(),
@@ -195,6 +203,14 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "time-picker".into(),
                     label: "Time Picker".into(),
                 },
+                MenuItemOption {
+                    value: "transfer".into(),
+                    label: "Transfer".into(),
+                },
+                MenuItemOption {
+                    value: "tree-select".into(),
+                    label: "Tree Select".into(),
+                },
                 MenuItemOption {
                     value: "upload".into(),
                     label: "Upload".into(),
@@ -216,6 +232,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "table".into(),
                     label: "Table".into(),
                 },
+                MenuItemOption {
+                    value: "tree".into(),
+                    label: "Tree".into(),
+                },
             ],
         },
         MenuGroupOption {
@@ -245,6 +265,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "pagination".into(),
                     label: "Pagination".into(),
                 },
+                MenuItemOption {
+                    value: "steps".into(),
+                    label: "Steps".into(),
+                },
                 MenuItemOption {
                     value: "tabs".into(),
                     label: "Tabs".into(),
@@ -262,6 +286,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "badge".into(),
                     label: "Badge".into(),
                 },
+                MenuItemOption {
+                    value: "dialog".into(),
+                    label: "Dialog".into(),
+                },
                 MenuItemOption {
                     value: "drawer".into(),
                     label: "Drawer".into(),
@@ -274,6 +302,10 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
                     value: "modal".into(),
                     label: "Modal".into(),
                 },
+                MenuItemOption {
+                    value: "notification".into(),
+                    label: "Notification".into(),
+                },
                 MenuItemOption {
                     value: "popover".into(),
                     label: "Popover".into(),
@@ -318,10 +350,16 @@ pub(crate) fn gen_menu_data() -> Vec<MenuGroupOption> {
         },
         MenuGroupOption {
             label: "Config Components".into(),
-            children: vec![MenuItemOption {
-                value: "theme".into(),
-                label: "Theme".into(),
-            }],
+            children: vec![
+                MenuItemOption {
+                    value: "config-provider".into(),
+                    label: "Config Provider".into(),
+                },
+                MenuItemOption {
+                    value: "theme".into(),
+                    label: "Theme".into(),
+                },
+            ],
         },
         MenuGroupOption {
             label: "Mobile Components".into(),
@@ -38,12 +38,16 @@ pub fn include_md(_token_stream: proc_macro::TokenStream) -> proc_macro::TokenSt
         "ButtonMdPage" => "../docs/button/mod.md",
         "CalendarMdPage" => "../docs/calendar/mod.md",
         "CardMdPage" => "../docs/card/mod.md",
+        "CascaderMdPage" => "../docs/cascader/mod.md",
         "CheckboxMdPage" => "../docs/checkbox/mod.md",
         "CollapseMdPage" => "../docs/collapse/mod.md",
         "ColorPickerMdPage" => "../docs/color_picker/mod.md",
+        "ConfigProviderMdPage" => "../docs/config_provider/mod.md",
         "DatePickerMdPage" => "../docs/date_picker/mod.md",
+        "DialogMdPage" => "../docs/dialog/mod.md",
         "DividerMdPage" => "../docs/divider/mod.md",
         "DrawerMdPage" => "../docs/drawer/mod.md",
+        "FormMdPage" => "../docs/form/mod.md",
         "GridMdPage" => "../docs/grid/mod.md",
         "IconMdPage" => "../docs/icon/mod.md",
         "ImageMdPage" => "../docs/image/mod.md",
@@ -54,6 +58,7 @@ pub fn include_md(_token_stream: proc_macro::TokenStream) -> proc_macro::TokenSt
         "MenuMdPage" => "../docs/menu/mod.md",
         "MessageMdPage" => "../docs/message/mod.md",
         "ModalMdPage" => "../docs/modal/mod.md",
+        "NotificationMdPage" => "../docs/notification/mod.md",
         "PaginationMdPage" => "../docs/pagination/mod.md",
         "PopoverMdPage" => "../docs/popover/mod.md",
         "ProgressMdPage" => "../docs/progress/mod.md",
@@ -64,12 +69,16 @@ pub fn include_md(_token_stream: proc_macro::TokenStream) -> proc_macro::TokenSt
         "SliderMdPage" => "../docs/slider/mod.md",
         "SpaceMdPage" => "../docs/space/mod.md",
         "SpinnerMdPage" => "../docs/spinner/mod.md",
+        "StepsMdPage" => "../docs/steps/mod.md",
         "SwitchMdPage" => "../docs/switch/mod.md",
         "TableMdPage" => "../docs/table/mod.md",
         "TabsMdPage" => "../docs/tabs/mod.md",
         "TagMdPage" => "../docs/tag/mod.md",
         "ThemeMdPage" => "../docs/theme/mod.md",
         "TimePickerMdPage" => "../docs/time_picker/mod.md",
+        "TransferMdPage" => "../docs/transfer/mod.md",
+        "TreeMdPage" => "../docs/tree/mod.md",
+        "TreeSelectMdPage" => "../docs/tree_select/mod.md",
         "TypographyMdPage" => "../docs/typography/mod.md",
         "UploadMdPage" => "../docs/upload/mod.md",
         "DropdownMdPage" => "../docs/dropdown/mod.md"
@@ -1,6 +1,7 @@
 use crate::theme::ThemeMethod;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlertTheme {
     pub success_background_color: String,
     pub success_border_color: String,
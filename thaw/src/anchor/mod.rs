@@ -4,7 +4,7 @@ mod theme;
 pub use anchor_link::AnchorLink;
 pub use theme::AnchorTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use std::cmp::Ordering;
 use thaw_utils::{add_event_listener_with_bool, class_list, mount_style, throttle, OptionalProp};
@@ -16,30 +16,8 @@ pub fn Anchor(
     #[prop(into, optional)] offset_target: Option<OffsetTarget>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("anchor", include_str!("./anchor.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = Memo::new(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-rail-background-color: {};",
-                theme.anchor.rail_background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-title-color-hover: {};",
-                theme.common.color_primary_hover
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-title-color-active: {};",
-                theme.common.color_primary
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-link-background-color: {}1a;",
-                theme.common.color_primary
-            ));
-        });
-        css_vars
-    });
     let anchor_ref = NodeRef::new();
     let background_ref = NodeRef::<html::Div>::new();
     let bar_ref = NodeRef::new();
@@ -126,7 +104,6 @@ pub fn Anchor(
         <div
             class=class_list!["thaw-anchor", class.map(| c | move || c.get())]
             ref=anchor_ref
-            style=move || css_vars.get()
         >
             <div class="thaw-anchor-rail">
                 <div
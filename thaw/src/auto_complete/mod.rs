@@ -2,10 +2,19 @@ mod theme;
 
 pub use theme::AutoCompleteTheme;
 
-use crate::{use_theme, ComponentRef, Input, InputPrefix, InputRef, InputSuffix, Theme};
+use crate::{
+    select::{SearchedOptions, VIRTUAL_MENU_HEIGHT, VIRTUAL_MENU_ITEM_HEIGHT},
+    theme::mount_theme_vars,
+    use_locale, ComponentRef, Input, InputPrefix, InputRef, InputSuffix, OptionSearch, Spinner,
+    SpinnerSize,
+};
 use leptos::*;
-use thaw_components::{Binder, CSSTransition, Follower, FollowerPlacement, FollowerWidth};
-use thaw_utils::{class_list, mount_style, Model, OptionalProp, StoredMaybeSignal};
+use std::time::Duration;
+use thaw_components::{
+    Binder, CSSTransition, Follower, FollowerPlacement, FollowerWidth, VirtualList,
+    VirtualListScroll,
+};
+use thaw_utils::{class_list, mount_style, Model, OptionalProp};
 
 #[derive(Clone, PartialEq)]
 pub struct AutoCompleteOption {
@@ -23,6 +32,12 @@ pub struct AutoCompleteSuffix {
     children: Children,
 }
 
+/// Shown in the menu when there are no options.
+#[slot]
+pub struct AutoCompleteEmpty {
+    children: ChildrenFn,
+}
+
 #[component]
 pub fn AutoComplete(
     #[prop(optional, into)] value: Model<String>,
@@ -35,28 +50,26 @@ pub fn AutoComplete(
     #[prop(optional, into)] allow_free_input: bool,
     #[prop(optional, into)] invalid: MaybeSignal<bool>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional)] virtual_scroll: bool,
+    /// Loads the options matching the typed text from a remote source.
+    /// Only the result of the latest search is shown, `options` are shown while nothing is typed.
+    #[prop(optional, into)]
+    on_search: Option<OptionSearch<AutoCompleteOption>>,
+    /// Milliseconds `on_search` waits for the typing to stop.
+    #[prop(default = 300)]
+    search_debounce: u64,
+    /// Shows a spinner in the menu.
+    #[prop(optional, into)]
+    loading: MaybeSignal<bool>,
     #[prop(optional)] auto_complete_prefix: Option<AutoCompletePrefix>,
     #[prop(optional)] auto_complete_suffix: Option<AutoCompleteSuffix>,
+    #[prop(optional)] auto_complete_empty: Option<AutoCompleteEmpty>,
     #[prop(optional)] comp_ref: ComponentRef<AutoCompleteRef>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("auto-complete", include_str!("./auto-complete.css"));
-    let theme = use_theme(Theme::light);
-    let menu_css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.select.menu_background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.select.menu_background_color_hover
-            ));
-        });
-        css_vars
-    });
-
+    let locale = use_locale();
     let input_ref = ComponentRef::<InputRef>::new();
 
     let default_index = if allow_free_input { None } else { Some(0) };
@@ -65,15 +78,31 @@ pub fn AutoComplete(
     let menu_ref = create_node_ref::<html::Div>();
     let is_show_menu = create_rw_signal(false);
     let auto_complete_ref = create_node_ref::<html::Div>();
-    let options = StoredMaybeSignal::from(options);
+    let searched_options = SearchedOptions::new(on_search, Duration::from_millis(search_debounce));
+    let is_remote = searched_options.is_remote();
+    let options = searched_options.options(options);
+    let loading = searched_options.loading(loading);
     let open_menu = move || {
         select_option_index.set(default_index);
         is_show_menu.set(true);
     };
-    let allow_value = move |_| {
+    let close_menu = move || {
+        is_show_menu.set(false);
+        searched_options.reset();
+    };
+    let on_focus = move |_| {
+        open_menu();
+        if is_remote {
+            searched_options.search(value.get_untracked());
+        }
+    };
+    let allow_value = move |input_value: String| {
         if !is_show_menu.get_untracked() {
             open_menu();
         }
+        if is_remote {
+            searched_options.search(input_value);
+        }
         true
     };
 
@@ -89,7 +118,7 @@ pub fn AutoComplete(
         if allow_free_input {
             select_option_index.set(None);
         }
-        is_show_menu.set(false);
+        close_menu();
         if blur_after_select.get_untracked() {
             if let Some(input_ref) = input_ref.get_untracked() {
                 input_ref.blur();
@@ -157,6 +186,76 @@ pub fn AutoComplete(
         comp_ref.load(AutoCompleteRef { input_ref });
     });
 
+    let menu_scroll = VirtualListScroll::new(menu_ref, VIRTUAL_MENU_ITEM_HEIGHT);
+    let on_menu_scroll = move |_| {
+        if virtual_scroll {
+            menu_scroll.on_scroll();
+        }
+    };
+    if virtual_scroll {
+        menu_scroll.reveal(move || {
+            if is_show_menu.get() {
+                select_option_index.get()
+            } else {
+                None
+            }
+        });
+    }
+
+    let render_option = move |(index, option): (usize, AutoCompleteOption)| {
+        let AutoCompleteOption {
+            value: option_value,
+            label,
+        } = option;
+        let menu_item_ref = create_node_ref::<html::Div>();
+        let on_click = move |_| {
+            select_value(option_value.clone());
+        };
+        let on_mouseenter = move |_| {
+            select_option_index.set(Some(index));
+        };
+        let on_mousedown = move |ev: ev::MouseEvent| {
+            ev.prevent_default();
+        };
+        if !virtual_scroll {
+            create_effect(move |_| {
+                if Some(index) == select_option_index.get() {
+                    if !is_show_menu.get() {
+                        return;
+                    }
+                    if let Some(menu_item_ref) = menu_item_ref.get() {
+                        let menu_ref = menu_ref.get().unwrap();
+                        let menu_rect = menu_ref.get_bounding_client_rect();
+                        let item_rect = menu_item_ref.get_bounding_client_rect();
+                        if item_rect.y() < menu_rect.y() {
+                            menu_item_ref.scroll_into_view_with_bool(true);
+                        } else if item_rect.y() + item_rect.height()
+                            > menu_rect.y() + menu_rect.height()
+                        {
+                            menu_item_ref.scroll_into_view_with_bool(false);
+                        }
+                    }
+                }
+            });
+        }
+        view! {
+            <div
+                class="thaw-auto-complete__menu-item"
+                class=(
+                    "thaw-auto-complete__menu-item--selected",
+                    move || Some(index) == select_option_index.get(),
+                )
+
+                on:click=on_click
+                on:mousedown=on_mousedown
+                on:mouseenter=on_mouseenter
+                ref=menu_item_ref
+            >
+                {label}
+            </div>
+        }
+    };
+
     view! {
         <Binder target_ref=auto_complete_ref>
             <div
@@ -170,8 +269,8 @@ pub fn AutoComplete(
                     placeholder
                     disabled
                     invalid
-                    on_focus=move |_| open_menu()
-                    on_blur=move |_| is_show_menu.set(false)
+                    on_focus
+                    on_blur=move |_| close_menu()
                     allow_value
                     comp_ref=input_ref
                 >
@@ -210,71 +309,64 @@ pub fn AutoComplete(
                 >
                     <div
                         class="thaw-auto-complete__menu"
-                        style=move || {
-                            display
-                                .get()
-                                .map(|d| d.to_string())
-                                .unwrap_or_else(|| menu_css_vars.get())
-                        }
+                        class=("thaw-auto-complete__menu--virtual", virtual_scroll)
+                        style=move || display.get()
 
                         ref=menu_ref
+                        on:scroll=on_menu_scroll
                     >
 
-                        {move || {
-                            options
-                                .get()
-                                .into_iter()
-                                .enumerate()
-                                .map(|(index, v)| {
-                                    let AutoCompleteOption { value: option_value, label } = v;
-                                    let menu_item_ref = create_node_ref::<html::Div>();
-                                    let on_click = move |_| {
-                                        select_value(option_value.clone());
-                                    };
-                                    let on_mouseenter = move |_| {
-                                        select_option_index.set(Some(index));
-                                    };
-                                    let on_mousedown = move |ev: ev::MouseEvent| {
-                                        ev.prevent_default();
-                                    };
-                                    create_effect(move |_| {
-                                        if Some(index) == select_option_index.get() {
-                                            if !is_show_menu.get() {
-                                                return;
-                                            }
-                                            if let Some(menu_item_ref) = menu_item_ref.get() {
-                                                let menu_ref = menu_ref.get().unwrap();
-                                                let menu_rect = menu_ref.get_bounding_client_rect();
-                                                let item_rect = menu_item_ref.get_bounding_client_rect();
-                                                if item_rect.y() < menu_rect.y() {
-                                                    menu_item_ref.scroll_into_view_with_bool(true);
-                                                } else if item_rect.y() + item_rect.height()
-                                                    > menu_rect.y() + menu_rect.height()
-                                                {
-                                                    menu_item_ref.scroll_into_view_with_bool(false);
-                                                }
-                                            }
-                                        }
-                                    });
-                                    view! {
-                                        <div
-                                            class="thaw-auto-complete__menu-item"
-                                            class=(
-                                                "thaw-auto-complete__menu-item--selected",
-                                                move || Some(index) == select_option_index.get(),
-                                            )
-
-                                            on:click=on_click
-                                            on:mousedown=on_mousedown
-                                            on:mouseenter=on_mouseenter
-                                            ref=menu_item_ref
-                                        >
-                                            {label}
+                        {if virtual_scroll {
+                            view! {
+                                <VirtualList
+                                    each=Signal::derive(move || {
+                                        options.get().into_iter().enumerate().collect::<Vec<_>>()
+                                    })
+                                    key=|(index, option)| (*index, option.value.clone())
+                                    item_size=VIRTUAL_MENU_ITEM_HEIGHT
+                                    scroll_offset=menu_scroll.scroll_offset()
+                                    viewport_size=Signal::derive(|| VIRTUAL_MENU_HEIGHT)
+                                    children=render_option
+                                />
+                            }
+                                .into_view()
+                        } else {
+                            (move || {
+                                options
+                                    .get()
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(render_option)
+                                    .collect_view()
+                            })
+                                .into_view()
+                        }}
+                        {
+                            let show_empty = is_remote || auto_complete_empty.is_some();
+                            move || {
+                                if loading.get() {
+                                    return view! {
+                                        <div class="thaw-auto-complete__menu-loading">
+                                            <Spinner size=SpinnerSize::Tiny/>
                                         </div>
                                     }
-                                })
-                                .collect_view()
-                        }}
+                                        .into_view();
+                                }
+                                if !show_empty || options.with(|options| !options.is_empty()) {
+                                    return ().into_view();
+                                }
+                                let children = auto_complete_empty
+                                    .as_ref()
+                                    .map_or_else(
+                                        || (move || locale.with(|locale| locale.no_data.clone())).into_view(),
+                                        |empty| (empty.children)().into_view(),
+                                    );
+                                view! {
+                                    <div class="thaw-auto-complete__menu-empty">{children}</div>
+                                }
+                                    .into_view()
+                            }
+                        }
 
                     </div>
                 </CSSTransition>
@@ -1,6 +1,7 @@
 use crate::theme::ThemeMethod;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AutoCompleteTheme {
     pub menu_background_color: String,
     pub menu_background_color_hover: String,
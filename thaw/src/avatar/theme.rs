@@ -1,6 +1,7 @@
 use crate::theme::ThemeMethod;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AvatarTheme {
     pub background_color: String,
 }
@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::BackTopTheme;
 
-use crate::{use_theme, Icon, Theme};
+use crate::{theme::mount_theme_vars, Icon};
 use leptos::{html::ToHtmlElement, *};
 use thaw_components::{CSSTransition, Fallback, OptionComp, Teleport};
 use thaw_utils::{
@@ -18,26 +18,12 @@ pub fn BackTop(
     #[prop(default=180.into(), into)] visibility_height: MaybeSignal<i32>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("back-top", include_str!("./back-top.css"));
-    let theme = use_theme(Theme::light);
     let style = Memo::new(move |_| {
         let mut style = String::new();
         style.push_str(&format!("right: {}px;", right.get_untracked()));
         style.push_str(&format!("bottom: {}px;", bottom.get_untracked()));
-        theme.with(|theme| {
-            style.push_str(&format!(
-                "--thaw-icon-color-hover: {};",
-                theme.common.color_primary_hover
-            ));
-            style.push_str(&format!(
-                "--thaw-icon-color-active: {};",
-                theme.common.color_primary_active
-            ));
-            style.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.back_top.background_color
-            ));
-        });
         style
     });
     let placeholder_ref = NodeRef::<html::Div>::new();
@@ -1,4 +1,4 @@
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -11,11 +11,11 @@ pub enum BadgeVariant {
 }
 
 impl BadgeVariant {
-    fn theme_color(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            BadgeVariant::Success => theme.common.color_success.clone(),
-            BadgeVariant::Warning => theme.common.color_warning.clone(),
-            BadgeVariant::Error => theme.common.color_error.clone(),
+            BadgeVariant::Success => "success",
+            BadgeVariant::Warning => "warning",
+            BadgeVariant::Error => "error",
         }
     }
 }
@@ -29,19 +29,8 @@ pub fn Badge(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     children: Children,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
+    mount_theme_vars();
     mount_style("badge", include_str!("./badge.css"));
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        css_vars.push_str("--thaw-font-color: #fff;");
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                variant.get().theme_color(theme)
-            ));
-        });
-        css_vars
-    });
     let value = create_memo(move |_| {
         let value = value.get();
         let max_value = max.get();
@@ -55,7 +44,7 @@ pub fn Badge(
     });
 
     view! {
-        <div class="thaw-badge" style=move || css_vars.get()>
+        <div class=move || format!("thaw-badge thaw-badge--{}", variant.get().as_str())>
             <div class=class_list![
                 "thaw-badge__sup", ("thaw-badge__sup--value", move || ! dot.get() && ! value.get()
                 .is_empty()), ("thaw-badge__sup--dot", move || dot.get()), class.map(| c | move || c
@@ -3,10 +3,10 @@ mod theme;
 
 pub use theme::BreadcrumbTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 pub use breadcrumb_item::BreadcrumbItem;
 use leptos::*;
-use thaw_utils::{class_list, mount_style, OptionalProp};
+use thaw_utils::{class_list, mount_style, use_direction, OptionalProp};
 
 #[component]
 pub fn Breadcrumb(
@@ -14,32 +14,17 @@ pub fn Breadcrumb(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("breadcrumb", include_str!("./breadcrumb.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-font-color: {};",
-                theme.breadcrumb.item_font_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-hover: {};",
-                theme.breadcrumb.item_font_color_hover
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.breadcrumb.item_background_color_hover
-            ));
-        });
-        css_vars
-    });
+    let dir = use_direction();
 
     view! {
         <Provider value=BreadcrumbSeparatorInjection(separator)>
             <nav
-                class=class_list!["thaw-breadcrumb", class.map(| c | move || c.get())]
-                style=move || css_vars.get()
+                class=class_list![
+                    "thaw-breadcrumb", ("thaw-breadcrumb--rtl", move || dir.is_some_and(| dir |
+                    dir.get().is_rtl())), class.map(| c | move || c.get())
+                ]
             >
                 <ul>{children()}</ul>
             </nav>
@@ -4,7 +4,7 @@ mod theme;
 pub use button_group::ButtonGroup;
 pub use theme::ButtonTheme;
 
-use crate::{icon::Icon, theme::*};
+use crate::{icon::Icon, theme::mount_theme_vars};
 use leptos::*;
 use thaw_components::{OptionComp, Wave, WaveRef};
 use thaw_utils::{class_list, mount_style, ComponentRef, OptionalMaybeSignal, OptionalProp};
@@ -28,29 +28,12 @@ pub enum ButtonColor {
 }
 
 impl ButtonColor {
-    fn theme_color(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            ButtonColor::Primary => theme.common.color_primary.clone(),
-            ButtonColor::Success => theme.common.color_success.clone(),
-            ButtonColor::Warning => theme.common.color_warning.clone(),
-            ButtonColor::Error => theme.common.color_error.clone(),
-        }
-    }
-    fn theme_color_hover(&self, theme: &Theme) -> String {
-        match self {
-            ButtonColor::Primary => theme.common.color_primary_hover.clone(),
-            ButtonColor::Success => theme.common.color_success_hover.clone(),
-            ButtonColor::Warning => theme.common.color_warning_hover.clone(),
-            ButtonColor::Error => theme.common.color_error_hover.clone(),
-        }
-    }
-
-    fn theme_color_active(&self, theme: &Theme) -> String {
-        match self {
-            ButtonColor::Primary => theme.common.color_primary_active.clone(),
-            ButtonColor::Success => theme.common.color_success_active.clone(),
-            ButtonColor::Warning => theme.common.color_warning_active.clone(),
-            ButtonColor::Error => theme.common.color_error_active.clone(),
+            ButtonColor::Primary => "primary",
+            ButtonColor::Success => "success",
+            ButtonColor::Warning => "warning",
+            ButtonColor::Error => "error",
         }
     }
 }
@@ -65,30 +48,12 @@ pub enum ButtonSize {
 }
 
 impl ButtonSize {
-    fn theme_font_size(&self, theme: &Theme) -> String {
-        match self {
-            ButtonSize::Tiny => theme.common.font_size_tiny.clone(),
-            ButtonSize::Small => theme.common.font_size_small.clone(),
-            ButtonSize::Medium => theme.common.font_size_medium.clone(),
-            ButtonSize::Large => theme.common.font_size_large.clone(),
-        }
-    }
-
-    fn theme_height(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            ButtonSize::Tiny => theme.common.height_tiny.clone(),
-            ButtonSize::Small => theme.common.height_small.clone(),
-            ButtonSize::Medium => theme.common.height_medium.clone(),
-            ButtonSize::Large => theme.common.height_large.clone(),
-        }
-    }
-
-    fn theme_padding(&self, theme: &Theme) -> String {
-        match self {
-            ButtonSize::Tiny => theme.button.padding_tiny.clone(),
-            ButtonSize::Small => theme.button.padding_small.clone(),
-            ButtonSize::Medium => theme.button.padding_medium.clone(),
-            ButtonSize::Large => theme.button.padding_large.clone(),
+            ButtonSize::Tiny => "tiny",
+            ButtonSize::Small => "small",
+            ButtonSize::Medium => "medium",
+            ButtonSize::Large => "large",
         }
     }
 }
@@ -109,89 +74,11 @@ pub fn Button(
     #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let bg_color = color.get().theme_color(theme);
-            css_vars.push_str(&format!(
-                "--thaw-font-color-disabled: {};",
-                theme.button.color_text_disabled
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-size: {};",
-                size.get().theme_font_size(theme)
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-height: {};",
-                size.get().theme_height(theme)
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-padding: {};",
-                size.get().theme_padding(theme)
-            ));
-
-            match variant.get() {
-                ButtonVariant::Primary => {
-                    let bg_color_hover = color.get().theme_color_hover(theme);
-                    let bg_color_active = color.get().theme_color_active(theme);
-                    css_vars.push_str(&format!("--thaw-background-color: {bg_color};"));
-                    css_vars.push_str(&format!("--thaw-background-color-hover: {bg_color_hover};"));
-                    css_vars.push_str(&format!(
-                        "--thaw-background-color-active: {bg_color_active};"
-                    ));
-                    css_vars.push_str("--thaw-font-color: #fff;");
-                    css_vars.push_str(&format!("--thaw-border-color: {bg_color};"));
-                    css_vars.push_str(&format!("--thaw-border-color-hover: {bg_color};"));
-                    css_vars.push_str(&format!("--thaw-ripple-color: {bg_color};"));
-
-                    css_vars.push_str(&format!(
-                        "--thaw-background-color-disabled: {};",
-                        theme.button.color_background_disabled
-                    ));
-                    css_vars.push_str(&format!(
-                        "--thaw-border-color-disabled: {};",
-                        theme.button.color_border_disabled
-                    ));
-                }
-                ButtonVariant::Outlined => {
-                    css_vars.push_str(&format!("--thaw-font-color-hover: {bg_color};"));
-                    css_vars.push_str(&format!(
-                        "--thaw-border-color: {};",
-                        theme.button.border_color_outlined
-                    ));
-                    css_vars.push_str(&format!("--thaw-border-color-hover: {bg_color};"));
-                    css_vars.push_str(&format!("--thaw-ripple-color: {bg_color};"));
-                    css_vars.push_str(&format!(
-                        "--thaw-border-color-disabled: {};",
-                        theme.button.color_border_disabled
-                    ));
-                }
-                ButtonVariant::Text => {
-                    css_vars.push_str(&format!("--thaw-font-color-hover: {bg_color};"));
-                    css_vars.push_str(&format!(
-                        "--thaw-background-color-hover: {};",
-                        theme.button.color_text_hover
-                    ));
-                    css_vars.push_str(&format!(
-                        "--thaw-background-color-active: {};",
-                        theme.button.color_text_active
-                    ));
-                    css_vars.push_str("--thaw-ripple-color: #0000;");
-                }
-                ButtonVariant::Link => {
-                    css_vars.push_str(&format!("--thaw-font-color-hover: {bg_color};"));
-                    css_vars.push_str("--thaw-ripple-color: #0000;");
-                }
-            }
-        });
-
-        css_vars
-    });
+    mount_theme_vars();
     mount_style("button", include_str!("./button.css"));
 
     let icon_style = if children.is_some() {
-        "margin-right: 6px"
+        "margin-inline-end: 6px"
     } else {
         ""
     };
@@ -227,13 +114,13 @@ pub fn Button(
                 ButtonVariant::Text), ("thaw-button--link", move || variant.get() ==
                 ButtonVariant::Link), ("thaw-button--round", move || round.get()),
                 ("thaw-button--circle", move || circle.get()), ("thaw-button--disabled", move ||
-                disabled.get()), ("thaw-button--block", move || block.get()), class.map(| c | move
-                || c.get())
+                disabled.get()), ("thaw-button--block", move || block.get()), move ||
+                format!("thaw-button--color-{}", color.get().as_str()), move ||
+                format!("thaw-button--size-{}", size.get().as_str()), class.map(| c | move || c
+                .get())
             ]
 
-            style=move || {
-                format!("{}{}", css_vars.get(), style.as_ref().map(|s| s.get()).unwrap_or_default())
-            }
+            style=move || style.as_ref().map(|s| s.get())
 
             disabled=move || disabled.get()
             on:click=on_click
@@ -2,8 +2,8 @@ mod theme;
 
 pub use theme::CalendarTheme;
 
-use crate::{use_theme, Button, ButtonGroup, ButtonVariant, Theme};
-use chrono::{Datelike, Days, Local, Month, Months, NaiveDate};
+use crate::{theme::mount_theme_vars, use_locale, Button, ButtonGroup, ButtonVariant};
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
 use leptos::*;
 use std::ops::Deref;
 use thaw_utils::{class_list, mount_style, Model, OptionalProp};
@@ -13,30 +13,9 @@ pub fn Calendar(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional, into)] value: Model<Option<NaiveDate>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("calendar", include_str!("./calendar.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color-today: {};",
-                theme.common.color_primary
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-other-month: {};",
-                theme.calendar.other_month_font_color,
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                theme.calendar.border_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.calendar.background_color_hover
-            ));
-        });
-        css_vars
-    });
+    let locale = use_locale();
     let show_date = create_rw_signal(value.get_untracked().unwrap_or(now_date()));
     create_effect(move |_| {
         if let Some(selected_date) = value.get() {
@@ -50,52 +29,8 @@ pub fn Calendar(
     });
 
     let dates = create_memo(move |_| {
-        let show_date = show_date.get();
-        let show_date_month = show_date.month();
-        let mut dates = vec![];
-
-        let mut current_date = show_date;
-        let mut current_weekday_number = None::<u32>;
-        loop {
-            let date = current_date - Days::new(1);
-            if date.month() != show_date_month {
-                if current_weekday_number.is_none() {
-                    current_weekday_number = Some(current_date.weekday().num_days_from_sunday());
-                }
-                let weekday_number = current_weekday_number.unwrap();
-                if weekday_number == 0 {
-                    break;
-                }
-                current_weekday_number = Some(weekday_number - 1);
-
-                dates.push(CalendarItemDate::Previous(date));
-            } else {
-                dates.push(CalendarItemDate::Current(date));
-            }
-            current_date = date;
-        }
-        dates.reverse();
-        dates.push(CalendarItemDate::Current(show_date));
-        current_date = show_date;
-        current_weekday_number = None;
-        loop {
-            let date = current_date + Days::new(1);
-            if date.month() != show_date_month {
-                if current_weekday_number.is_none() {
-                    current_weekday_number = Some(current_date.weekday().num_days_from_sunday());
-                }
-                let weekday_number = current_weekday_number.unwrap();
-                if weekday_number == 6 {
-                    break;
-                }
-                current_weekday_number = Some(weekday_number + 1);
-                dates.push(CalendarItemDate::Next(date));
-            } else {
-                dates.push(CalendarItemDate::Current(date));
-            }
-            current_date = date;
-        }
-        dates
+        let first_day_of_week = locale.with(|locale| locale.first_day_of_week);
+        month_dates(show_date.get(), first_day_of_week)
     });
 
     let previous_month = move |_| {
@@ -115,20 +50,12 @@ pub fn Calendar(
     view! {
         <div
             class=class_list!["thaw-calendar", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
         >
             <div class="thaw-calendar__header">
                 <span class="thaw-calendar__header-title">
 
                     {move || {
-                        show_date
-                            .with(|date| {
-                                format!(
-                                    "{} {}",
-                                    Month::try_from(date.month() as u8).unwrap().name(),
-                                    date.year(),
-                                )
-                            })
+                        show_date.with(|date| locale.with(|locale| locale.format_month_year(date)))
                     }}
 
                 </span>
@@ -140,7 +67,7 @@ pub fn Calendar(
                             on_click=previous_month
                         />
                         <Button variant=ButtonVariant::Outlined on_click=today>
-                            "Today"
+                            {move || locale.with(|locale| locale.today.clone())}
                         </Button>
                         <Button
                             variant=ButtonVariant::Outlined
@@ -178,7 +105,7 @@ fn CalendarItem(
         let date = date.clone();
         move |_| value.with(|value_date| value_date.as_ref() == Some(date.deref()))
     });
-    let weekday_str = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    let locale = use_locale();
     let on_click = {
         let date = date.clone();
         move |_| {
@@ -197,8 +124,11 @@ fn CalendarItem(
                 <span class="thaw-calendar-item__header-day">{date.day()}</span>
 
                 {if index < 7 {
+                    let weekday = date.weekday().num_days_from_sunday() as usize;
                     view! {
-                        <span class="thaw-calendar-item__header-title">{weekday_str[index]}</span>
+                        <span class="thaw-calendar-item__header-title">
+                            {move || locale.with(|locale| locale.weekdays[weekday].clone())}
+                        </span>
                     }
                         .into()
                 } else {
@@ -211,7 +141,7 @@ fn CalendarItem(
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum CalendarItemDate {
     Previous(NaiveDate),
     Current(NaiveDate),
@@ -245,6 +175,74 @@ impl Deref for CalendarItemDate {
     }
 }
 
+/// The dates shown for the month of `show_date`, padded with the days of
+/// the previous and next month to complete the weeks.
+pub(crate) fn month_dates(
+    show_date: NaiveDate,
+    first_day_of_week: Weekday,
+) -> Vec<CalendarItemDate> {
+    let days_from_first_day = |date: NaiveDate| {
+        (date.weekday().num_days_from_sunday() + 7 - first_day_of_week.num_days_from_sunday()) % 7
+    };
+    let first_date = show_date.with_day(1).unwrap();
+    let last_date = first_date + Months::new(1) - Days::new(1);
+    let start_date = first_date - Days::new(days_from_first_day(first_date).into());
+    let end_date = last_date + Days::new((6 - days_from_first_day(last_date)).into());
+
+    start_date
+        .iter_days()
+        .take_while(|date| *date <= end_date)
+        .map(|date| {
+            if date < first_date {
+                CalendarItemDate::Previous(date)
+            } else if date > last_date {
+                CalendarItemDate::Next(date)
+            } else {
+                CalendarItemDate::Current(date)
+            }
+        })
+        .collect()
+}
+
 pub(crate) fn now_date() -> NaiveDate {
     Local::now().date_naive()
 }
+
+#[cfg(test)]
+mod test {
+    use super::{month_dates, CalendarItemDate};
+    use chrono::{NaiveDate, Weekday};
+
+    #[test]
+    fn month_dates_first_day_of_week() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let dates = month_dates(date, Weekday::Sun);
+        assert_eq!(dates.len(), 42);
+        assert_eq!(
+            dates.first(),
+            Some(&CalendarItemDate::Previous(
+                NaiveDate::from_ymd_opt(2024, 2, 25).unwrap()
+            ))
+        );
+        assert_eq!(
+            dates.last(),
+            Some(&CalendarItemDate::Next(
+                NaiveDate::from_ymd_opt(2024, 4, 6).unwrap()
+            ))
+        );
+        let dates = month_dates(date, Weekday::Mon);
+        assert_eq!(dates.len(), 35);
+        assert_eq!(
+            dates.first(),
+            Some(&CalendarItemDate::Previous(
+                NaiveDate::from_ymd_opt(2024, 2, 26).unwrap()
+            ))
+        );
+        assert_eq!(
+            dates.last(),
+            Some(&CalendarItemDate::Current(
+                NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+            ))
+        );
+    }
+}
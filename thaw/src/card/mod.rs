@@ -1,4 +1,4 @@
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_components::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
@@ -29,27 +29,11 @@ pub fn Card(
     children: Children,
     #[prop(optional)] card_footer: Option<CardFooter>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("card", include_str!("./card.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.common.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                theme.common.border_color
-            ));
-        });
-        css_vars
-    });
-
     view! {
         <div
             class=class_list!["thaw-card", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
         >
 
             {if card_header.is_some() || title.is_some() {
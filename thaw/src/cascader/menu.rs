@@ -0,0 +1,95 @@
+use super::CascaderColumnItem;
+use crate::Icon;
+use leptos::*;
+use thaw_utils::{class_list, use_direction};
+
+/// The columns of the opened nodes, `active_path` holds the keys of the
+/// nodes whose children are shown.
+#[component]
+pub(super) fn CascaderMenu<K>(
+    columns: Memo<Vec<Vec<CascaderColumnItem<K>>>>,
+    active_path: RwSignal<Vec<K>>,
+    #[prop(into)] is_selected: Callback<Vec<K>, bool>,
+    #[prop(into)] on_select: Callback<Vec<K>>,
+) -> impl IntoView
+where
+    K: PartialEq + Clone + 'static,
+{
+    let dir = use_direction();
+    let arrow_class = Signal::derive(move || {
+        if dir.is_some_and(|dir| dir.get().is_rtl()) {
+            String::from("thaw-cascader-menu__arrow thaw-cascader-menu__arrow--rtl")
+        } else {
+            String::from("thaw-cascader-menu__arrow")
+        }
+    });
+    let column_path = move |level: usize, key: &K| {
+        let mut path = active_path.with_untracked(|path| path[..level.min(path.len())].to_vec());
+        path.push(key.clone());
+        path
+    };
+
+    view! {
+        {move || {
+            columns
+                .get()
+                .into_iter()
+                .enumerate()
+                .map(|(level, column)| {
+                    view! {
+                        <div class="thaw-cascader-menu__column">
+                            {column
+                                .into_iter()
+                                .map(|item| {
+                                    let CascaderColumnItem { key, label, is_leaf, disabled } = item;
+                                    let key = StoredValue::new(key);
+                                    let is_active = move || {
+                                        active_path
+                                            .with(|path| {
+                                                key.with_value(|key| path.get(level) == Some(key))
+                                            })
+                                    };
+                                    let on_click = move |_| {
+                                        if disabled {
+                                            return;
+                                        }
+                                        let path = key.with_value(|key| column_path(level, key));
+                                        active_path.set(path.clone());
+                                        if is_leaf {
+                                            on_select.call(path);
+                                        }
+                                    };
+                                    view! {
+                                        <div
+                                            class=class_list![
+                                                "thaw-select-menu__item thaw-cascader-menu__item",
+                                                ("thaw-select-menu__item--active", is_active),
+                                                ("thaw-select-menu__item-selected", move || {
+                                                    is_leaf && key.with_value(|key| is_selected.call(column_path(level, key)))
+                                                }),
+                                                ("thaw-cascader-menu__item--disabled", move || disabled)
+                                            ]
+                                            on:click=on_click
+                                            on:mousedown=|ev| ev.prevent_default()
+                                        >
+                                            <span class="thaw-cascader-menu__label">{label}</span>
+                                            {(!is_leaf)
+                                                .then(|| {
+                                                    view! {
+                                                        <Icon
+                                                            class=arrow_class
+                                                            icon=icondata_ai::AiRightOutlined
+                                                        />
+                                                    }
+                                                })}
+                                        </div>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    }
+                })
+                .collect_view()
+        }}
+    }
+}
@@ -0,0 +1,200 @@
+mod menu;
+mod multi;
+
+pub use multi::*;
+
+use crate::{
+    form::use_form_field,
+    select::{RawSelect, SelectIcon, SelectMenu},
+    theme::mount_theme_vars,
+    FormFieldValue, Icon, SelectLabel, SelectOption, TreeNode,
+};
+use leptos::*;
+use menu::CascaderMenu;
+use std::{hash::Hash, rc::Rc};
+use thaw_utils::{mount_style, Model, OptionalProp};
+
+#[component]
+pub fn Cascader<K>(
+    /// The keys from the root to the selected node.
+    #[prop(optional, into)]
+    value: Model<Vec<K>>,
+    #[prop(into)] data: MaybeSignal<Vec<TreeNode<K>>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    /// Separates the labels of the selected path.
+    #[prop(default = " / ".into(), into)]
+    separator: String,
+) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    mount_theme_vars();
+    mount_style("cascader", include_str!("./cascader.css"));
+
+    let value_label = Signal::derive({
+        let data = data.clone();
+        move || {
+            with!(|value, data| {
+                path_labels(data, value)
+                    .map(|labels| labels.join(&separator))
+                    .unwrap_or_default()
+            })
+        }
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            let label = value_label.get();
+            if label.is_empty() {
+                FormFieldValue::Empty
+            } else {
+                FormFieldValue::Text(label)
+            }
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+    let is_menu_visible = create_rw_signal(false);
+    let show_menu = move |_| is_menu_visible.set(true);
+    let hide_menu = move |_| is_menu_visible.set(false);
+    let active_path = create_rw_signal(vec![]);
+    // The columns of the selected path are opened with the menu.
+    create_effect(move |_| {
+        if is_menu_visible.get() {
+            active_path.set(value.get_untracked());
+        }
+    });
+    let columns = create_memo(move |_| {
+        data.with(|data| active_path.with(|active_path| cascader_columns(data, active_path)))
+    });
+    let is_selected = Callback::new(move |path: Vec<K>| value.with(|value| value == &path));
+    let on_select = Callback::new(move |path| {
+        value.set(path);
+        hide_menu(());
+    });
+
+    let select_label = select_label.unwrap_or_else(|| SelectLabel {
+        children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
+    });
+    let select_icon = SelectIcon {
+        children: Rc::new(move || {
+            Fragment::new(vec![
+                view! { <Icon class="thaw-select-dropdown-icon" icon=icondata_ai::AiDownOutlined/> }.into_view()
+            ])
+        }),
+    };
+    let select_menu = SelectMenu {
+        class: "thaw-cascader-menu",
+        children: Rc::new(move || {
+            Fragment::new(vec![view! {
+                <CascaderMenu columns active_path is_selected on_select/>
+            }
+            .into_view()])
+        }),
+    };
+
+    view! {
+        <RawSelect
+            options={Vec::<SelectOption<K>>::new()}
+            class
+            select_label
+            select_icon
+            select_menu
+            is_menu_visible
+            on_select=|_| {}
+            show_menu
+            hide_menu
+            is_selected=|_: &K| false
+            invalid
+            filter=None
+            on_search=None
+            select_empty=None
+        />
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) struct CascaderColumnItem<K> {
+    key: K,
+    label: String,
+    is_leaf: bool,
+    disabled: bool,
+}
+
+/// The roots and the children of every node of `active_path` that has any.
+fn cascader_columns<K: PartialEq + Clone>(
+    data: &[TreeNode<K>],
+    active_path: &[K],
+) -> Vec<Vec<CascaderColumnItem<K>>> {
+    let mut columns = vec![];
+    let mut nodes = data;
+    let mut active_path = active_path.iter();
+    while !nodes.is_empty() {
+        columns.push(
+            nodes
+                .iter()
+                .map(|node| CascaderColumnItem {
+                    key: node.key.clone(),
+                    label: node.label.clone(),
+                    is_leaf: node.children.is_empty(),
+                    disabled: node.disabled,
+                })
+                .collect(),
+        );
+        let Some(key) = active_path.next() else {
+            break;
+        };
+        match nodes.iter().find(|node| &node.key == key) {
+            Some(node) => nodes = &node.children,
+            None => break,
+        }
+    }
+    columns
+}
+
+/// The labels of the nodes of the path, `None` when the path does not exist.
+fn path_labels<K: PartialEq>(data: &[TreeNode<K>], path: &[K]) -> Option<Vec<String>> {
+    let mut nodes = data;
+    let mut labels = vec![];
+    for key in path {
+        let node = nodes.iter().find(|node| &node.key == key)?;
+        labels.push(node.label.clone());
+        nodes = &node.children;
+    }
+    (!labels.is_empty()).then_some(labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cascader_columns, path_labels};
+    use crate::TreeNode;
+
+    #[test]
+    fn cascader_path() {
+        let data = vec![
+            TreeNode::new(1, "Asia").with_children(vec![
+                TreeNode::new(2, "China").with_children(vec![TreeNode::new(3, "Beijing")]),
+                TreeNode::new(4, "Japan"),
+            ]),
+            TreeNode::new(5, "Europe"),
+        ];
+
+        let columns = cascader_columns(&data, &[1, 2, 3]);
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[1].len(), 2);
+        assert!(columns[2][0].is_leaf);
+        assert_eq!(cascader_columns(&data, &[5]).len(), 1);
+        assert_eq!(cascader_columns(&data, &[6]).len(), 1);
+
+        assert_eq!(
+            path_labels(&data, &[1, 2, 3]),
+            Some(vec!["Asia".into(), "China".into(), "Beijing".into()])
+        );
+        assert_eq!(path_labels(&data, &[1, 5]), None);
+        assert_eq!(path_labels::<i32>(&data, &[]), None);
+    }
+}
@@ -0,0 +1,125 @@
+use super::{cascader_columns, menu::CascaderMenu, path_labels};
+use crate::{
+    form::use_form_field,
+    select::{multiple_class, multiple_select_icon, tags_select_label, RawSelect, SelectMenu},
+    theme::mount_theme_vars,
+    FormFieldValue, SelectLabel, SelectOption, TagVariant, TreeNode,
+};
+use leptos::*;
+use std::{hash::Hash, rc::Rc};
+use thaw_utils::{mount_style, Model, OptionalProp};
+
+#[component]
+pub fn MultiCascader<K>(
+    /// The paths of the selected nodes, each one holding the keys from the root.
+    #[prop(optional, into)]
+    value: Model<Vec<Vec<K>>>,
+    #[prop(into)] data: MaybeSignal<Vec<TreeNode<K>>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] clearable: MaybeSignal<bool>,
+    #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    /// Separates the labels of a selected path.
+    #[prop(default = " / ".into(), into)]
+    separator: String,
+) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    mount_theme_vars();
+    mount_style("cascader", include_str!("./cascader.css"));
+
+    let tags = Signal::derive({
+        let data = data.clone();
+        move || {
+            with!(|value, data| {
+                value
+                    .iter()
+                    .filter_map(|path| {
+                        let label = path_labels(data, path)?.join(&separator);
+                        Some((path.clone(), label, TagVariant::Default))
+                    })
+                    .collect::<Vec<_>>()
+            })
+        }
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            tags.with(|tags| {
+                FormFieldValue::List(tags.iter().map(|(_, label, _)| label.clone()).collect())
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+    let class = multiple_class(class);
+    let is_menu_visible = create_rw_signal(false);
+    let show_menu = move |_| is_menu_visible.set(true);
+    let hide_menu = move |_| is_menu_visible.set(false);
+    let active_path = create_rw_signal(vec![]);
+    let columns = create_memo(move |_| {
+        data.with(|data| active_path.with(|active_path| cascader_columns(data, active_path)))
+    });
+    let is_selected = Callback::new(move |path: Vec<K>| value.with(|value| value.contains(&path)));
+    let toggle_path = Callback::new(move |path: Vec<K>| {
+        value.update(|value| match value.iter().position(|v| v == &path) {
+            Some(i) => {
+                value.remove(i);
+            }
+            None => value.push(path),
+        });
+    });
+
+    let select_label = select_label.unwrap_or_else(|| tags_select_label(tags, toggle_path));
+    let is_hovered = RwSignal::new(false);
+    let show_clear_icon = Signal::derive(move || {
+        clearable.get()
+            && ((is_hovered.get() || is_menu_visible.get()) && with!(|value| !value.is_empty()))
+    });
+    let on_hover_enter = Callback::new(move |_| is_hovered.set(true));
+    let on_hover_exit = Callback::new(move |_| is_hovered.set(false));
+    let select_icon =
+        multiple_select_icon(show_clear_icon, Callback::new(move |_| value.set(vec![])));
+    let select_menu = SelectMenu {
+        class: "thaw-cascader-menu",
+        children: Rc::new(move || {
+            Fragment::new(vec![view! {
+                <CascaderMenu columns active_path is_selected on_select=toggle_path/>
+            }
+            .into_view()])
+        }),
+    };
+
+    // Trigger the following menu to resync when the value is updated
+    let _ = watch(
+        move || value.track(),
+        move |_, _, _| {
+            is_menu_visible.update(|_| {});
+        },
+        false,
+    );
+
+    view! {
+        <RawSelect
+            options={Vec::<SelectOption<K>>::new()}
+            class
+            select_label
+            select_icon
+            select_menu
+            is_menu_visible
+            on_select=|_| {}
+            show_menu
+            hide_menu
+            on_hover_enter
+            on_hover_exit
+            is_selected=|_: &K| false
+            invalid
+            filter=None
+            on_search=None
+            select_empty=None
+        />
+    }
+}
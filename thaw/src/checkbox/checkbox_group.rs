@@ -1,3 +1,4 @@
+use crate::{form::use_form_field, FormFieldValue};
 use leptos::*;
 use std::collections::HashSet;
 use thaw_utils::Model;
@@ -7,6 +8,20 @@ pub fn CheckboxGroup(
     #[prop(optional, into)] value: Model<HashSet<String>>,
     children: Children,
 ) -> impl IntoView {
+    let _ = use_form_field(
+        false.into(),
+        move || {
+            value.with(|value| {
+                let mut keys: Vec<_> = value.iter().cloned().collect();
+                keys.sort();
+                FormFieldValue::List(keys)
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
     view! { <Provider value=CheckboxGroupInjection(value) children/> }
 }
 
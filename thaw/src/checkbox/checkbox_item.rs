@@ -7,6 +7,9 @@ pub fn CheckboxItem(
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(into)] key: String,
+    /// Rendered instead of the `label`.
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
     let checkbox_group_value = use_checkbox_group().0;
     let checked = RwSignal::new(false);
@@ -32,7 +35,13 @@ pub fn CheckboxItem(
         }
     });
 
-    if let Some(label) = label {
+    if let Some(children) = children {
+        view! {
+            <Checkbox class value=(is_checked, checked.write_only())>
+                {children()}
+            </Checkbox>
+        }
+    } else if let Some(label) = label {
         view! {
             <Checkbox class value=(is_checked, checked.write_only())>
                 {label}
@@ -4,7 +4,7 @@ mod checkbox_item;
 pub use checkbox_group::CheckboxGroup;
 pub use checkbox_item::CheckboxItem;
 
-use crate::{icon::*, theme::use_theme, Theme};
+use crate::{icon::*, theme::mount_theme_vars};
 use leptos::*;
 use thaw_components::*;
 use thaw_utils::{class_list, mount_style, Model, OptionalProp};
@@ -12,39 +12,54 @@ use thaw_utils::{class_list, mount_style, Model, OptionalProp};
 #[component]
 pub fn Checkbox(
     #[prop(optional, into)] value: Model<bool>,
+    /// Shows a dash instead of the check mark while not checked,
+    /// e.g. when only some of the nested options are checked.
+    #[prop(optional, into)]
+    indeterminate: MaybeSignal<bool>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
+    mount_theme_vars();
     mount_style("checkbox", include_str!("./checkbox.css"));
 
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color-checked: {};",
-                theme.common.color_primary
-            ));
-        });
-        css_vars
-    });
-
     view! {
         <div
             class=class_list![
-                "thaw-checkbox", ("thaw-checkbox--checked", move || value.get()), class.map(| c |
-                move || c.get())
+                "thaw-checkbox", ("thaw-checkbox--checked", move || value.get()),
+                ("thaw-checkbox--indeterminate", move || indeterminate.get() && !value.get()),
+                class.map(| c | move || c.get())
             ]
 
-            style=move || css_vars.get()
             on:click=move |_| value.set(!value.get_untracked())
+            on:keydown=move |ev| {
+                if matches!(ev.key().as_str(), " " | "Enter") {
+                    ev.prevent_default();
+                    ev.stop_propagation();
+                    value.set(!value.get_untracked());
+                }
+            }
+
+            role="checkbox"
+            tabindex="0"
+            aria-checked=move || {
+                if value.get() {
+                    "true"
+                } else if indeterminate.get() {
+                    "mixed"
+                } else {
+                    "false"
+                }
+            }
         >
-            <input class="thaw-checkbox__input" type="checkbox"/>
+            <input class="thaw-checkbox__input" type="checkbox" tabindex="-1" aria-hidden="true"/>
             <div class="thaw-checkbox__dot">
                 <If cond=value.signal()>
                     <Then slot>
                         <Icon icon=icondata_ai::AiCheckOutlined style="color: white"/>
                     </Then>
+                    <ElseIf slot cond=indeterminate>
+                        <div class="thaw-checkbox__indeterminate"></div>
+                    </ElseIf>
                 </If>
             </div>
             <OptionComp value=children let:children>
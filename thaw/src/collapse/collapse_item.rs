@@ -21,7 +21,7 @@ pub fn CollapseItem(
             .with(|keys| key.with(|key| keys.contains(key)))
     });
 
-    let on_click = move |_| {
+    let toggle = move || {
         collapse.value.update(|keys| {
             if collapse.accordion {
                 keys.clear();
@@ -40,7 +40,20 @@ pub fn CollapseItem(
             "thaw-collapse-item", ("thaw-collapse-item--active", move || is_show_content.get()),
             class.map(| c | move || c.get())
         ]>
-            <div class="thaw-collapse-item__header" on:click=on_click>
+            <div
+                class="thaw-collapse-item__header"
+                on:click=move |_| toggle()
+                on:keydown=move |ev| {
+                    if matches!(ev.key().as_str(), " " | "Enter") {
+                        ev.prevent_default();
+                        toggle();
+                    }
+                }
+
+                role="button"
+                tabindex="0"
+                aria-expanded=move || if is_show_content.get() { "true" } else { "false" }
+            >
                 <Icon icon=icondata_ai::AiRightOutlined class="thaw-collapse-item-arrow"/>
                 {move || title.get()}
             </div>
@@ -52,6 +65,7 @@ pub fn CollapseItem(
             >
                 <div
                     class="thaw-collapse-item__content"
+                    role="region"
                     ref=content_ref
                     style=move || display.get()
                 >
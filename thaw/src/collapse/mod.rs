@@ -4,7 +4,7 @@ mod theme;
 pub use collapse_item::CollapseItem;
 pub use theme::CollapseTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use std::collections::HashSet;
 use thaw_utils::{class_list, mount_style, Model, OptionalProp};
@@ -16,11 +16,8 @@ pub fn Collapse(
     #[prop(optional)] accordion: bool,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("collapser", include_str!("./collapse.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        theme.with(|theme| format!("--thaw-border-color: {};", theme.collapse.border_color))
-    });
 
     view! {
         <Provider value=CollapseInjection {
@@ -29,7 +26,6 @@ pub fn Collapse(
         }>
             <div
                 class=class_list!["thaw-collapse", class.map(| c | move || c.get())]
-                style=move || css_vars.get()
             >
                 {children()}
             </div>
@@ -4,7 +4,7 @@ mod theme;
 pub use color::*;
 pub use theme::ColorPickerTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::leptos_dom::helpers::WindowListenerHandle;
 use leptos::*;
 use palette::{Hsv, IntoColor, Srgb};
@@ -16,17 +16,8 @@ pub fn ColorPicker(
     #[prop(optional, into)] value: Model<Color>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("color-picker", include_str!("./color-picker.css"));
-    let theme = use_theme(Theme::light);
-    let popover_css_vars = create_memo(move |_| {
-        theme.with(|theme| {
-            format!(
-                "--thaw-background-color: {};",
-                theme.color_picker.popover_background_color
-            )
-        })
-    });
-
     let hue = create_rw_signal(0f32);
     let sv = create_rw_signal((0f32, 0f32));
     let label = create_rw_signal(String::new());
@@ -159,12 +150,7 @@ pub fn ColorPicker(
                     <div
                         class="thaw-color-picker-popover"
                         ref=popover_ref
-                        style=move || {
-                            display
-                                .get()
-                                .map(|d| d.to_string())
-                                .unwrap_or_else(|| popover_css_vars.get())
-                        }
+                        style=move || display.get()
                     >
 
                         <ColorPanel hue=hue.read_only() sv/>
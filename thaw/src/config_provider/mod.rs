@@ -0,0 +1,48 @@
+pub use thaw_utils::Direction;
+
+use crate::{modal::ModalManagerInjection, Locale, Theme, ThemeProvider};
+use leptos::*;
+use thaw_utils::{use_direction, DirectionInjection};
+
+/// Provides the theme, the locale and the direction to all the components inside.
+///
+/// Settings that are not passed are inherited from the outer providers.
+#[component]
+pub fn ConfigProvider(
+    #[prop(optional, into)] theme: Option<RwSignal<Theme>>,
+    #[prop(optional, into)] locale: Option<RwSignal<Locale>>,
+    #[prop(optional, into)] dir: Option<MaybeSignal<Direction>>,
+    children: Children,
+) -> impl IntoView {
+    // An inherited theme already has its tokens emitted by the outer provider.
+    let theme = theme.or_else(|| {
+        use_context::<RwSignal<Theme>>()
+            .is_none()
+            .then(|| create_rw_signal(Theme::light()))
+    });
+    let locale = locale.unwrap_or_else(|| {
+        use_context::<RwSignal<Locale>>().unwrap_or_else(|| create_rw_signal(Locale::en_us()))
+    });
+    let dir = dir
+        .map(|dir| Signal::derive(move || dir.get()))
+        .or_else(use_direction);
+    if let Some(dir) = dir {
+        provide_context(DirectionInjection(dir));
+    }
+    ModalManagerInjection::provide();
+
+    let children = move || {
+        view! {
+            <Provider value=locale>
+                <div class="thaw-config-provider" dir=dir.map(|dir| move || dir.get().as_str())>
+                    {children()}
+                </div>
+            </Provider>
+        }
+    };
+    if let Some(theme) = theme {
+        view! { <ThemeProvider theme>{children()}</ThemeProvider> }
+    } else {
+        children().into_view()
+    }
+}
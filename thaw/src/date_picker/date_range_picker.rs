@@ -0,0 +1,163 @@
+use super::panel::{RangePanel, RangePanelRef};
+use crate::{
+    form::use_form_field, theme::mount_theme_vars, use_locale, FormFieldValue, Icon, Input,
+    InputSuffix, SignalWatch,
+};
+use chrono::NaiveDate;
+use leptos::*;
+use thaw_components::{Binder, Follower, FollowerPlacement};
+use thaw_utils::{mount_style, now_date, ComponentRef, Model, OptionalProp};
+
+const RANGE_SEPARATOR: &str = " ~ ";
+
+#[component]
+pub fn DateRangePicker(
+    #[prop(optional, into)] value: Model<Option<(NaiveDate, NaiveDate)>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+) -> impl IntoView {
+    mount_theme_vars();
+    mount_style("date-picker", include_str!("./date-picker.css"));
+    let date_picker_ref = create_node_ref::<html::Div>();
+    let is_show_panel = create_rw_signal(false);
+    let locale = use_locale();
+    let format = create_memo(move |_| locale.with(|locale| locale.date_format.clone()));
+    let show_range_text = create_rw_signal(String::new());
+    let update_show_range_text = move || {
+        value.with_untracked(move |range| {
+            let text = range.as_ref().map_or(String::new(), |range| {
+                format.with_untracked(|format| format_range(range, format))
+            });
+            show_range_text.set(text);
+        });
+    };
+    update_show_range_text();
+    create_effect(move |_| {
+        format.track();
+        update_show_range_text();
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            value.with(|range| {
+                range.as_ref().map_or(FormFieldValue::Empty, |range| {
+                    FormFieldValue::Text(format.with(|format| format_range(range, format)))
+                })
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || {
+                value.set(initial_value);
+                update_show_range_text();
+            }
+        },
+    );
+    let panel_ref = ComponentRef::<RangePanelRef>::default();
+    let panel_selected_range = create_rw_signal(None::<(NaiveDate, NaiveDate)>);
+    _ = panel_selected_range.watch(move |range| {
+        let text = range.as_ref().map_or(String::new(), |range| {
+            format.with_untracked(|format| format_range(range, format))
+        });
+        show_range_text.set(text);
+    });
+
+    let on_input_blur = Callback::new(move |_| {
+        let range = show_range_text
+            .with_untracked(|text| format.with_untracked(|format| parse_range(text, format)));
+        if range.is_some() && value.get_untracked() != range {
+            value.set(range);
+        }
+        update_show_range_text();
+    });
+
+    let close_panel = Callback::new(move |range: Option<(NaiveDate, NaiveDate)>| {
+        if range.is_some() && value.get_untracked() != range {
+            value.set(range);
+        }
+        update_show_range_text();
+        is_show_panel.set(false);
+    });
+
+    let open_panel = Callback::new(move |_| {
+        panel_selected_range.set(value.get_untracked());
+        if let Some(panel_ref) = panel_ref.get_untracked() {
+            panel_ref.init_panel(value.get_untracked().map_or(now_date(), |(start, _)| start));
+        }
+        is_show_panel.set(true);
+    });
+
+    view! {
+        <Binder target_ref=date_picker_ref>
+            <div ref=date_picker_ref>
+                <Input
+                    attrs
+                    class
+                    value=show_range_text
+                    invalid
+                    on_focus=open_panel
+                    on_blur=on_input_blur
+                >
+                    <InputSuffix slot>
+                        <Icon icon=icondata_ai::AiCalendarOutlined style="font-size: 18px"/>
+                    </InputSuffix>
+                </Input>
+            </div>
+            <Follower slot show=is_show_panel placement=FollowerPlacement::BottomStart>
+                <RangePanel
+                    date_picker_ref
+                    close_panel
+                    selected_range=panel_selected_range
+                    comp_ref=panel_ref
+                    is_show_panel
+                />
+            </Follower>
+        </Binder>
+    }
+}
+
+fn format_range((start, end): &(NaiveDate, NaiveDate), format: &str) -> String {
+    format!(
+        "{}{RANGE_SEPARATOR}{}",
+        start.format(format),
+        end.format(format)
+    )
+}
+
+fn parse_range(text: &str, format: &str) -> Option<(NaiveDate, NaiveDate)> {
+    let (start, end) = text.split_once(RANGE_SEPARATOR.trim())?;
+    let start = NaiveDate::parse_from_str(start.trim(), format).ok()?;
+    let end = NaiveDate::parse_from_str(end.trim(), format).ok()?;
+    Some(if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_range, parse_range};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn range_text() {
+        let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        let format = "%Y-%m-%d";
+        assert_eq!(
+            format_range(&(start, end), format),
+            "2024-03-01 ~ 2024-03-09"
+        );
+        assert_eq!(
+            parse_range("2024-03-01 ~ 2024-03-09", format),
+            Some((start, end))
+        );
+        assert_eq!(
+            parse_range("2024-03-09~2024-03-01", format),
+            Some((start, end))
+        );
+        assert_eq!(parse_range("2024-03-01", format), None);
+    }
+}
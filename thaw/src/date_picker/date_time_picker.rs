@@ -0,0 +1,143 @@
+use super::panel::{DateTimePanel, DateTimePanelRef};
+use crate::{
+    form::use_form_field, theme::mount_theme_vars, use_locale, FormFieldValue, Icon, Input,
+    InputSuffix,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use leptos::*;
+use thaw_components::{Binder, Follower, FollowerPlacement};
+use thaw_utils::{mount_style, now_date, ComponentRef, Model, OptionalProp};
+
+#[component]
+pub fn DateTimePicker(
+    #[prop(optional, into)] value: Model<Option<NaiveDateTime>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+) -> impl IntoView {
+    mount_theme_vars();
+    mount_style("date-picker", include_str!("./date-picker.css"));
+    mount_style(
+        "time-picker",
+        include_str!("../time_picker/time-picker.css"),
+    );
+    let date_picker_ref = create_node_ref::<html::Div>();
+    let is_show_panel = create_rw_signal(false);
+    let locale = use_locale();
+    let format = create_memo(move |_| locale.with(|locale| locale.date_time_format.clone()));
+    let show_date_time_text = create_rw_signal(String::new());
+    let update_show_date_time_text = move || {
+        value.with_untracked(move |date_time| {
+            let text = date_time.as_ref().map_or(String::new(), |date_time| {
+                format.with_untracked(|format| date_time.format(format).to_string())
+            });
+            show_date_time_text.set(text);
+        });
+    };
+    update_show_date_time_text();
+    create_effect(move |_| {
+        format.track();
+        update_show_date_time_text();
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            value.with(|date_time| {
+                date_time
+                    .as_ref()
+                    .map_or(FormFieldValue::Empty, |date_time| {
+                        FormFieldValue::Text(
+                            format.with(|format| date_time.format(format).to_string()),
+                        )
+                    })
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || {
+                value.set(initial_value);
+                update_show_date_time_text();
+            }
+        },
+    );
+    let panel_ref = ComponentRef::<DateTimePanelRef>::default();
+    let panel_selected_date = create_rw_signal(None::<NaiveDate>);
+    let panel_selected_time = create_rw_signal(None::<NaiveTime>);
+    // The input previews the panel selection.
+    create_effect(move |prev: Option<()>| {
+        let date = panel_selected_date.get();
+        let time = panel_selected_time.get();
+        if prev.is_none() {
+            return;
+        }
+        if let Some(date) = date {
+            let date_time = NaiveDateTime::new(date, time.unwrap_or(NaiveTime::MIN));
+            show_date_time_text
+                .set(format.with_untracked(|format| date_time.format(format).to_string()));
+        }
+    });
+
+    let on_input_blur = Callback::new(move |_| {
+        let date_time = format.with_untracked(|format| {
+            NaiveDateTime::parse_from_str(&show_date_time_text.get_untracked(), format)
+        });
+        if let Ok(date_time) = date_time {
+            if value.get_untracked() != Some(date_time) {
+                value.set(Some(date_time));
+            }
+        }
+        update_show_date_time_text();
+    });
+
+    let close_panel = Callback::new(move |date_time: Option<NaiveDateTime>| {
+        if date_time.is_some() && value.get_untracked() != date_time {
+            value.set(date_time);
+        }
+        update_show_date_time_text();
+        is_show_panel.set(false);
+    });
+
+    let open_panel = Callback::new(move |_| {
+        let date_time = value.get_untracked();
+        panel_selected_date.set(date_time.map(|date_time| date_time.date()));
+        panel_selected_time.set(date_time.map(|date_time| date_time.time()));
+        if let Some(panel_ref) = panel_ref.get_untracked() {
+            panel_ref.init_panel(date_time.map_or(now_date(), |date_time| date_time.date()));
+        }
+        is_show_panel.set(true);
+        request_animation_frame(move || {
+            if let Some(panel_ref) = panel_ref.get_untracked() {
+                panel_ref.scroll_time_into_view();
+            }
+        });
+    });
+
+    view! {
+        <Binder target_ref=date_picker_ref>
+            <div ref=date_picker_ref>
+                <Input
+                    attrs
+                    class
+                    value=show_date_time_text
+                    invalid
+                    on_focus=open_panel
+                    on_blur=on_input_blur
+                >
+                    <InputSuffix slot>
+                        <Icon icon=icondata_ai::AiCalendarOutlined style="font-size: 18px"/>
+                    </InputSuffix>
+                </Input>
+            </div>
+            <Follower slot show=is_show_panel placement=FollowerPlacement::BottomStart>
+                <DateTimePanel
+                    date_picker_ref
+                    close_panel
+                    selected_date=panel_selected_date
+                    selected_time=panel_selected_time
+                    comp_ref=panel_ref
+                    is_show_panel
+                />
+            </Follower>
+        </Binder>
+    }
+}
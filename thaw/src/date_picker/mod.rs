@@ -1,12 +1,19 @@
+mod date_range_picker;
+mod date_time_picker;
 mod panel;
 mod theme;
 
+pub use date_range_picker::DateRangePicker;
+pub use date_time_picker::DateTimePicker;
 pub use theme::DatePickerTheme;
 
-use crate::{Icon, Input, InputSuffix, SignalWatch};
+use crate::{
+    form::use_form_field, locale::can_format, theme::mount_theme_vars, use_locale, FormFieldValue,
+    Icon, Input, InputSuffix, SignalWatch,
+};
 use chrono::NaiveDate;
 use leptos::*;
-use panel::{Panel, PanelRef};
+use panel::{DateLimits, Panel, PanelRef};
 use thaw_components::{Binder, Follower, FollowerPlacement};
 use thaw_utils::{mount_style, now_date, ComponentRef, Model, OptionalProp};
 
@@ -14,35 +21,81 @@ use thaw_utils::{mount_style, now_date, ComponentRef, Model, OptionalProp};
 pub fn DatePicker(
     #[prop(optional, into)] value: Model<Option<NaiveDate>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    /// Used to show and to parse the date, see `chrono::format::strftime`.
+    /// Defaults to the date format of the locale, which is also used for an invalid format.
+    #[prop(optional, into)]
+    format: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] min: MaybeSignal<Option<NaiveDate>>,
+    #[prop(optional, into)] max: MaybeSignal<Option<NaiveDate>>,
+    /// Returning `true` makes the date unselectable.
+    #[prop(optional, into)]
+    is_date_disabled: Option<Callback<NaiveDate, bool>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("date-picker", include_str!("./date-picker.css"));
     let date_picker_ref = create_node_ref::<html::Div>();
     let is_show_panel = create_rw_signal(false);
     let show_date_text = create_rw_signal(String::new());
-    let show_date_format = "%Y-%m-%d";
+    let locale = use_locale();
+    // An invalid format falls back to the one of the locale instead of panicking.
+    let format = create_memo(move |_| {
+        format
+            .as_ref()
+            .map(|format| format.get())
+            .filter(|format| can_format(NaiveDate::default().format(format)))
+            .unwrap_or_else(|| locale.with(|locale| locale.date_format.clone()))
+    });
+    let limits = DateLimits {
+        min: Signal::derive(move || min.get()),
+        max: Signal::derive(move || max.get()),
+        is_date_disabled,
+    };
     let update_show_date_text = move || {
         value.with_untracked(move |date| {
             let text = date.as_ref().map_or(String::new(), |date| {
-                date.format(show_date_format).to_string()
+                format.with_untracked(|format| date.format(format).to_string())
             });
             show_date_text.set(text);
         });
     };
     update_show_date_text();
+    create_effect(move |_| {
+        format.track();
+        update_show_date_text();
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            value.with(|date| {
+                date.as_ref().map_or(FormFieldValue::Empty, |date| {
+                    FormFieldValue::Text(format.with(|format| date.format(format).to_string()))
+                })
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || {
+                value.set(initial_value);
+                update_show_date_text();
+            }
+        },
+    );
     let panel_ref = ComponentRef::<PanelRef>::default();
     let panel_selected_date = create_rw_signal(None::<NaiveDate>);
     _ = panel_selected_date.watch(move |date| {
         let text = date.as_ref().map_or(String::new(), |date| {
-            date.format(show_date_format).to_string()
+            format.with_untracked(|format| date.format(format).to_string())
         });
         show_date_text.set(text);
     });
 
     let on_input_blur = Callback::new(move |_| {
-        if let Ok(date) =
-            NaiveDate::parse_from_str(&show_date_text.get_untracked(), show_date_format)
-        {
+        let date = format.with_untracked(|format| {
+            NaiveDate::parse_from_str(&show_date_text.get_untracked(), format).ok()
+        });
+        if let Some(date) = date.filter(|date| !limits.is_disabled(*date)) {
             if value.get_untracked() != Some(date) {
                 value.set(Some(date));
                 update_show_date_text();
@@ -73,7 +126,14 @@ pub fn DatePicker(
     view! {
         <Binder target_ref=date_picker_ref>
             <div ref=date_picker_ref>
-                <Input attrs class value=show_date_text on_focus=open_panel on_blur=on_input_blur>
+                <Input
+                    attrs
+                    class
+                    value=show_date_text
+                    invalid
+                    on_focus=open_panel
+                    on_blur=on_input_blur
+                >
                     <InputSuffix slot>
                         <Icon icon=icondata_ai::AiCalendarOutlined style="font-size: 18px"/>
                     </InputSuffix>
@@ -86,6 +146,7 @@ pub fn DatePicker(
                     selected_date=panel_selected_date
                     comp_ref=panel_ref
                     is_show_panel
+                    limits
                 />
             </Follower>
         </Binder>
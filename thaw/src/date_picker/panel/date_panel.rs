@@ -1,6 +1,8 @@
-use super::PanelVariant;
-use crate::{Button, ButtonSize, ButtonVariant, CalendarItemDate};
-use chrono::{Datelike, Days, Month, Months, NaiveDate};
+use super::{DateLimits, PanelVariant};
+use crate::{
+    calendar::month_dates, use_locale, Button, ButtonSize, ButtonVariant, CalendarItemDate,
+};
+use chrono::{Datelike, Months, NaiveDate};
 use leptos::*;
 use std::ops::Deref;
 use thaw_utils::now_date;
@@ -11,54 +13,13 @@ pub fn DatePanel(
     show_date: RwSignal<NaiveDate>,
     close_panel: Callback<Option<NaiveDate>>,
     panel_variant: RwSignal<PanelVariant>,
+    #[prop(default = true)] show_footer: bool,
+    #[prop(optional)] limits: DateLimits,
 ) -> impl IntoView {
+    let locale = use_locale();
     let dates = create_memo(move |_| {
-        let show_date = show_date.get();
-        let show_date_month = show_date.month();
-        let mut dates = vec![];
-
-        let mut current_date = show_date;
-        let mut current_weekday_number = None::<u32>;
-        loop {
-            let date = current_date - Days::new(1);
-            if date.month() != show_date_month {
-                if current_weekday_number.is_none() {
-                    current_weekday_number = Some(current_date.weekday().num_days_from_sunday());
-                }
-                let weekday_number = current_weekday_number.unwrap();
-                if weekday_number == 0 {
-                    break;
-                }
-                current_weekday_number = Some(weekday_number - 1);
-
-                dates.push(CalendarItemDate::Previous(date));
-            } else {
-                dates.push(CalendarItemDate::Current(date));
-            }
-            current_date = date;
-        }
-        dates.reverse();
-        dates.push(CalendarItemDate::Current(show_date));
-        current_date = show_date;
-        current_weekday_number = None;
-        loop {
-            let date = current_date + Days::new(1);
-            if date.month() != show_date_month {
-                if current_weekday_number.is_none() {
-                    current_weekday_number = Some(current_date.weekday().num_days_from_sunday());
-                }
-                let weekday_number = current_weekday_number.unwrap();
-                if weekday_number == 6 {
-                    break;
-                }
-                current_weekday_number = Some(weekday_number + 1);
-                dates.push(CalendarItemDate::Next(date));
-            } else {
-                dates.push(CalendarItemDate::Current(date));
-            }
-            current_date = date;
-        }
-        dates
+        let first_day_of_week = locale.with(|locale| locale.first_day_of_week);
+        month_dates(show_date.get(), first_day_of_week)
     });
     let previous_year = move |_| {
         show_date.update(|date| {
@@ -105,7 +66,9 @@ pub fn DatePanel(
                             size=ButtonSize::Small
                             on_click=move |_| panel_variant.set(PanelVariant::Month)
                         >
-                            {move || Month::try_from(show_date.get().month() as u8).unwrap().name()}
+                            {move || {
+                                show_date.with(|date| locale.with(|locale| locale.month(date).to_string()))
+                            }}
                         </Button>
                         <Button
                             variant=ButtonVariant::Text
@@ -128,15 +91,7 @@ pub fn DatePanel(
                         on_click=next_year
                     />
                 </div>
-                <div class="thaw-date-picker-date-panel__weekdays">
-                    <span>"Su"</span>
-                    <span>"Mo"</span>
-                    <span>"Tu"</span>
-                    <span>"We"</span>
-                    <span>"Th"</span>
-                    <span>"Fr"</span>
-                    <span>"Sa"</span>
-                </div>
+                <PanelWeekdays/>
                 <div class="thaw-date-picker-date-panel__dates">
                     {move || {
                         dates
@@ -146,37 +101,58 @@ pub fn DatePanel(
                                 let on_click = {
                                     let date = date.clone();
                                     move |_| {
-                                        close_panel.call(Some(*date.deref()));
+                                        if !limits.is_disabled(*date.deref()) {
+                                            close_panel.call(Some(*date.deref()));
+                                        }
                                     }
                                 };
-                                view! { <DatePanelItem value date=date on:click=on_click/> }
+                                view! { <DatePanelItem value date=date limits on:click=on_click/> }
                             })
                             .collect_view()
                     }}
 
                 </div>
             </div>
-            <div class="thaw-date-picker-date-panel__footer">
-                <Button variant=ButtonVariant::Outlined size=ButtonSize::Tiny on_click=now>
-                    "Now"
-                </Button>
-            </div>
+            {show_footer
+                .then(|| {
+                    view! {
+                        <div class="thaw-date-picker-date-panel__footer">
+                            <Button
+                                variant=ButtonVariant::Outlined
+                                size=ButtonSize::Tiny
+                                disabled=Signal::derive(move || limits.is_disabled(now_date()))
+                                on_click=now
+                            >
+                                {move || locale.with(|locale| locale.now.clone())}
+                            </Button>
+                        </div>
+                    }
+                })}
         </div>
     }
 }
 
 #[component]
-fn DatePanelItem(value: RwSignal<Option<NaiveDate>>, date: CalendarItemDate) -> impl IntoView {
+fn DatePanelItem(
+    value: RwSignal<Option<NaiveDate>>,
+    date: CalendarItemDate,
+    limits: DateLimits,
+) -> impl IntoView {
     let is_selected = create_memo({
         let date = date.clone();
         move |_| value.with(|value_date| value_date.as_ref() == Some(date.deref()))
     });
+    let is_disabled = create_memo({
+        let date = *date.deref();
+        move |_| limits.is_disabled(date)
+    });
 
     view! {
         <div
             class="thaw-date-picker-date-panel__item"
             class=("thaw-date-picker-date-panel__item--other-month", date.is_other_month())
             class=("thaw-date-picker-date-panel__item--selected", move || is_selected.get())
+            class=("thaw-date-picker-date-panel__item--disabled", move || is_disabled.get())
         >
             <div class="thaw-date-picker-date-panel__item-day">
                 {date.day()}
@@ -190,3 +166,26 @@ fn DatePanelItem(value: RwSignal<Option<NaiveDate>>, date: CalendarItemDate) ->
         </div>
     }
 }
+
+/// The weekday names above the dates, starting from the first day of the week.
+#[component]
+pub(super) fn PanelWeekdays() -> impl IntoView {
+    let locale = use_locale();
+    view! {
+        <div class="thaw-date-picker-date-panel__weekdays">
+            {move || {
+                locale
+                    .with(|locale| {
+                        locale
+                            .week()
+                            .map(|weekday| {
+                                let name = locale.min_weekdays[weekday.num_days_from_sunday() as usize]
+                                    .clone();
+                                view! { <span>{name}</span> }
+                            })
+                            .collect_view()
+                    })
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,128 @@
+use super::{
+    date_panel::DatePanel, month_panel::MonthPanel, on_click_outside_panel, year_panel::YearPanel,
+    PanelVariant,
+};
+use crate::{
+    time_picker::{now_time, TimePanelColumns, TimePanelColumnsRef},
+    use_locale, Button, ButtonSize, ButtonVariant,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use leptos::*;
+use thaw_components::CSSTransition;
+use thaw_utils::{now_date, ComponentRef};
+
+/// The date panel with the hour, minute and second columns of the time picker.
+#[component]
+pub fn DateTimePanel(
+    selected_date: RwSignal<Option<NaiveDate>>,
+    selected_time: RwSignal<Option<NaiveTime>>,
+    date_picker_ref: NodeRef<html::Div>,
+    close_panel: Callback<Option<NaiveDateTime>>,
+    #[prop(into)] is_show_panel: MaybeSignal<bool>,
+    #[prop(optional)] comp_ref: ComponentRef<DateTimePanelRef>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let panel_ref = create_node_ref::<html::Div>();
+    on_click_outside_panel(panel_ref, date_picker_ref, move || close_panel.call(None));
+
+    let panel_variant = create_rw_signal(PanelVariant::Date);
+    let show_date = create_rw_signal(selected_date.get_untracked().unwrap_or(now_date()));
+    let time_columns_ref = ComponentRef::<TimePanelColumnsRef>::new();
+    comp_ref.load(DateTimePanelRef {
+        show_date,
+        variant: panel_variant,
+        time_columns_ref,
+    });
+
+    let select_date = Callback::new(move |date: Option<NaiveDate>| {
+        if let Some(date) = date {
+            selected_date.set(Some(date));
+            show_date.set(date);
+        }
+    });
+    let now = Callback::new(move |_| {
+        close_panel.call(Some(NaiveDateTime::new(now_date(), now_time())));
+    });
+    let ok = Callback::new(move |_| {
+        let date = selected_date.get_untracked();
+        let time = selected_time.get_untracked();
+        let date_time = (date.is_some() || time.is_some()).then(|| {
+            NaiveDateTime::new(date.unwrap_or(now_date()), time.unwrap_or(NaiveTime::MIN))
+        });
+        close_panel.call(date_time);
+    });
+
+    view! {
+        <CSSTransition
+            node_ref=panel_ref
+            name="fade-in-scale-up-transition"
+            appear=is_show_panel.get_untracked()
+            show=is_show_panel
+            let:display
+        >
+            <div
+                class="thaw-date-picker-panel thaw-date-picker-panel--date-time"
+                style=move || display.get()
+                ref=panel_ref
+            >
+                <div class="thaw-date-picker-date-time-panel__content">
+                    <div class="thaw-date-picker-date-time-panel__date">
+                        {move || {
+                            match panel_variant.get() {
+                                PanelVariant::Date => {
+                                    view! {
+                                        <DatePanel
+                                            value=selected_date
+                                            show_date
+                                            close_panel=select_date
+                                            panel_variant
+                                            show_footer=false
+                                        />
+                                    }
+                                }
+                                PanelVariant::Month => {
+                                    view! { <MonthPanel date_panel_show_date=show_date panel_variant/> }
+                                }
+                                PanelVariant::Year => {
+                                    view! { <YearPanel date_panel_show_date=show_date panel_variant/> }
+                                }
+                            }
+                        }}
+                    </div>
+                    <div class="thaw-date-picker-date-time-panel__time">
+                        <TimePanelColumns selected_time comp_ref=time_columns_ref/>
+                    </div>
+                </div>
+                <div class="thaw-date-picker-date-panel__footer">
+                    <Button size=ButtonSize::Tiny on_click=ok>
+                        {move || locale.with(|locale| locale.ok.clone())}
+                    </Button>
+                    <Button variant=ButtonVariant::Outlined size=ButtonSize::Tiny on_click=now>
+                        {move || locale.with(|locale| locale.now.clone())}
+                    </Button>
+                </div>
+            </div>
+        </CSSTransition>
+    }
+}
+
+#[derive(Clone)]
+pub struct DateTimePanelRef {
+    show_date: RwSignal<NaiveDate>,
+    variant: RwSignal<PanelVariant>,
+    time_columns_ref: ComponentRef<TimePanelColumnsRef>,
+}
+
+impl DateTimePanelRef {
+    pub fn init_panel(&self, show_date: NaiveDate) {
+        self.show_date.set(show_date);
+        self.variant.set(PanelVariant::Date);
+    }
+
+    /// Scrolls the selected hour, minute and second to the top of their columns.
+    pub fn scroll_time_into_view(&self) {
+        if let Some(time_columns_ref) = self.time_columns_ref.get_untracked() {
+            time_columns_ref.scroll_into_view();
+        }
+    }
+}
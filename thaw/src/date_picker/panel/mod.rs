@@ -1,9 +1,13 @@
 mod date_panel;
+mod date_time_panel;
 mod month_panel;
+mod range_panel;
 mod year_panel;
 
-use crate::{use_theme, Theme};
-use chrono::NaiveDate;
+pub use date_time_panel::{DateTimePanel, DateTimePanelRef};
+pub use range_panel::{RangePanel, RangePanelRef};
+
+use chrono::{Months, NaiveDate};
 use date_panel::DatePanel;
 use leptos::*;
 use month_panel::MonthPanel;
@@ -18,70 +22,10 @@ pub fn Panel(
     close_panel: Callback<Option<NaiveDate>>,
     #[prop(into)] is_show_panel: MaybeSignal<bool>,
     #[prop(optional)] comp_ref: ComponentRef<PanelRef>,
+    #[prop(optional)] limits: DateLimits,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color-today: {};",
-                theme.common.color_primary
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-other-month: {};",
-                theme.date_picker.panel_other_month_font_color,
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.date_picker.panel_background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-item-background-color-hover: {};",
-                theme.date_picker.panel_date_item_background_color_hover
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-item-border-color: {};",
-                theme.date_picker.panel_border_color
-            ));
-        });
-        css_vars
-    });
-
     let panel_ref = create_node_ref::<html::Div>();
-    #[cfg(any(feature = "csr", feature = "hydrate"))]
-    {
-        use leptos::wasm_bindgen::__rt::IntoJsResult;
-        let handle = window_event_listener(ev::click, move |ev| {
-            let el = ev.target();
-            let mut el: Option<web_sys::Element> =
-                el.into_js_result().map_or(None, |el| Some(el.into()));
-            let body = document().body().unwrap();
-            loop {
-                let Some(current_el) = el else {
-                    return;
-                };
-                if current_el == *body {
-                    break;
-                };
-                if panel_ref.get().is_none() {
-                    return;
-                }
-                if current_el == ***panel_ref.get_untracked().unwrap()
-                    || current_el == ***date_picker_ref.get_untracked().unwrap()
-                {
-                    return;
-                }
-                el = current_el.parent_element();
-            }
-            close_panel.call(None);
-        });
-        on_cleanup(move || handle.remove());
-    }
-    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
-    {
-        _ = date_picker_ref;
-        _ = panel_ref;
-    }
+    on_click_outside_panel(panel_ref, date_picker_ref, move || close_panel.call(None));
     let panel_variant = create_rw_signal(PanelVariant::Date);
     let show_date = create_rw_signal(selected_date.get_untracked().unwrap_or(now_date()));
     comp_ref.load(PanelRef {
@@ -99,7 +43,7 @@ pub fn Panel(
         >
             <div
                 class="thaw-date-picker-panel"
-                style=move || display.get().map(|d| d.to_string()).unwrap_or_else(|| css_vars.get())
+                style=move || display.get()
                 ref=panel_ref
             >
 
@@ -107,14 +51,20 @@ pub fn Panel(
                     match panel_variant.get() {
                         PanelVariant::Date => {
                             view! {
-                                <DatePanel value=selected_date show_date close_panel panel_variant/>
+                                <DatePanel
+                                    value=selected_date
+                                    show_date
+                                    close_panel
+                                    panel_variant
+                                    limits
+                                />
                             }
                         }
                         PanelVariant::Month => {
-                            view! { <MonthPanel date_panel_show_date=show_date panel_variant/> }
+                            view! { <MonthPanel date_panel_show_date=show_date panel_variant limits/> }
                         }
                         PanelVariant::Year => {
-                            view! { <YearPanel date_panel_show_date=show_date panel_variant/> }
+                            view! { <YearPanel date_panel_show_date=show_date panel_variant limits/> }
                         }
                     }
                 }}
@@ -144,3 +94,88 @@ pub enum PanelVariant {
     Month,
     Year,
 }
+
+/// The dates that can't be picked in the panels.
+#[derive(Clone, Copy, Default)]
+pub struct DateLimits {
+    pub min: Signal<Option<NaiveDate>>,
+    pub max: Signal<Option<NaiveDate>>,
+    pub is_date_disabled: Option<Callback<NaiveDate, bool>>,
+}
+
+impl DateLimits {
+    pub fn is_disabled(&self, date: NaiveDate) -> bool {
+        self.min.get().is_some_and(|min| date < min)
+            || self.max.get().is_some_and(|max| date > max)
+            || self
+                .is_date_disabled
+                .is_some_and(|is_date_disabled| is_date_disabled.call(date))
+    }
+
+    /// Whether no date from `first` to `last` can be picked.
+    pub fn is_range_disabled(&self, first: NaiveDate, last: NaiveDate) -> bool {
+        if self.min.get().is_some_and(|min| last < min)
+            || self.max.get().is_some_and(|max| first > max)
+        {
+            return true;
+        }
+        if self.is_date_disabled.is_none() {
+            return false;
+        }
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .all(|date| self.is_disabled(date))
+    }
+
+    pub(super) fn is_month_disabled(&self, first_day: NaiveDate) -> bool {
+        let last_day = first_day
+            .checked_add_months(Months::new(1))
+            .and_then(|date| date.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+        self.is_range_disabled(first_day, last_day)
+    }
+}
+
+/// Calls `on_click` when a click lands outside of the panel and the picker.
+pub(super) fn on_click_outside_panel(
+    panel_ref: NodeRef<html::Div>,
+    date_picker_ref: NodeRef<html::Div>,
+    on_click: impl Fn() + 'static,
+) {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        use leptos::wasm_bindgen::__rt::IntoJsResult;
+        let handle = window_event_listener(ev::click, move |ev| {
+            let el = ev.target();
+            let mut el: Option<web_sys::Element> =
+                el.into_js_result().map_or(None, |el| Some(el.into()));
+            let body = document().body().unwrap();
+            loop {
+                let Some(current_el) = el else {
+                    return;
+                };
+                if current_el == *body {
+                    break;
+                };
+                if panel_ref.get().is_none() {
+                    return;
+                }
+                if current_el == ***panel_ref.get_untracked().unwrap()
+                    || current_el == ***date_picker_ref.get_untracked().unwrap()
+                {
+                    return;
+                }
+                el = current_el.parent_element();
+            }
+            on_click();
+        });
+        on_cleanup(move || handle.remove());
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    {
+        _ = date_picker_ref;
+        _ = panel_ref;
+        _ = on_click;
+    }
+}
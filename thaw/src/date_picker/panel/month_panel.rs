@@ -1,5 +1,5 @@
-use super::PanelVariant;
-use crate::{Button, ButtonSize, ButtonVariant};
+use super::{DateLimits, PanelVariant};
+use crate::{use_locale, Button, ButtonSize, ButtonVariant};
 use chrono::{Datelike, Month, Months, NaiveDate};
 use leptos::*;
 
@@ -7,6 +7,7 @@ use leptos::*;
 pub fn MonthPanel(
     date_panel_show_date: RwSignal<NaiveDate>,
     panel_variant: RwSignal<PanelVariant>,
+    #[prop(optional)] limits: DateLimits,
 ) -> impl IntoView {
     let show_date = create_rw_signal(date_panel_show_date.get_untracked());
     let previous_year = move |_| {
@@ -49,7 +50,14 @@ pub fn MonthPanel(
                 {(1..=12)
                     .map(|index| {
                         let month = Month::try_from(index).unwrap();
+                        let is_disabled = create_memo(move |_| {
+                            NaiveDate::from_ymd_opt(show_date.get().year(), index.into(), 1)
+                                .is_some_and(|first_day| limits.is_month_disabled(first_day))
+                        });
                         let on_click = move |_| {
+                            if is_disabled.get_untracked() {
+                                return;
+                            }
                             date_panel_show_date
                                 .update(|date| {
                                     let show_date = show_date.get_untracked();
@@ -61,7 +69,7 @@ pub fn MonthPanel(
                                 });
                             panel_variant.set(PanelVariant::Date);
                         };
-                        view! { <MonthPanelItem date_panel_show_date month on:click=on_click/> }
+                        view! { <MonthPanelItem date_panel_show_date month is_disabled on:click=on_click/> }
                     })
                     .collect_view()}
 
@@ -71,17 +79,24 @@ pub fn MonthPanel(
 }
 
 #[component]
-fn MonthPanelItem(date_panel_show_date: RwSignal<NaiveDate>, month: Month) -> impl IntoView {
+fn MonthPanelItem(
+    date_panel_show_date: RwSignal<NaiveDate>,
+    month: Month,
+    is_disabled: Memo<bool>,
+) -> impl IntoView {
+    let locale = use_locale();
     let is_selected = create_memo(move |_| {
         date_panel_show_date.with(|date| date.month() == month.number_from_month())
     });
+    let month_index = month.number_from_month() as usize - 1;
 
     view! {
         <div
             class="thaw-date-picker-month-panel__item"
             class=("thaw-date-picker-month-panel__item--selected", move || is_selected.get())
+            class=("thaw-date-picker-month-panel__item--disabled", move || is_disabled.get())
         >
-            <div class="thaw-date-picker-month-panel__item-month">{month.name().split_at(3).0}</div>
+            <div class="thaw-date-picker-month-panel__item-month">{move || locale.with(|locale| locale.short_months[month_index].clone())}</div>
         </div>
     }
 }
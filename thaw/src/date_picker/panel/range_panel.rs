@@ -0,0 +1,217 @@
+use super::{date_panel::PanelWeekdays, on_click_outside_panel};
+use crate::{
+    calendar::month_dates, use_locale, Button, ButtonSize, ButtonVariant, CalendarItemDate,
+};
+use chrono::{Datelike, Months, NaiveDate};
+use leptos::*;
+use std::ops::Deref;
+use thaw_components::CSSTransition;
+use thaw_utils::{now_date, ComponentRef};
+
+/// Two linked calendars of consecutive months, the first click picks the start
+/// and the second one the end of the range.
+#[component]
+pub fn RangePanel(
+    selected_range: RwSignal<Option<(NaiveDate, NaiveDate)>>,
+    date_picker_ref: NodeRef<html::Div>,
+    close_panel: Callback<Option<(NaiveDate, NaiveDate)>>,
+    #[prop(into)] is_show_panel: MaybeSignal<bool>,
+    #[prop(optional)] comp_ref: ComponentRef<RangePanelRef>,
+) -> impl IntoView {
+    let panel_ref = create_node_ref::<html::Div>();
+    on_click_outside_panel(panel_ref, date_picker_ref, move || close_panel.call(None));
+
+    let start_show_date = create_rw_signal(
+        selected_range
+            .get_untracked()
+            .map_or(now_date(), |(start, _)| start),
+    );
+    let end_show_date = Signal::derive(move || start_show_date.get() + Months::new(1));
+    let pending_start = create_rw_signal(None::<NaiveDate>);
+    let hover_date = create_rw_signal(None::<NaiveDate>);
+    comp_ref.load(RangePanelRef {
+        show_date: start_show_date,
+        pending_start,
+    });
+
+    // While the end is not picked yet, the range follows the hovered date.
+    let preview_range = create_memo(move |_| match pending_start.get() {
+        Some(start) => Some(ordered_range(start, hover_date.get().unwrap_or(start))),
+        None => selected_range.get(),
+    });
+    let on_select = Callback::new(move |date: NaiveDate| match pending_start.get_untracked() {
+        Some(start) => {
+            pending_start.set(None);
+            let range = ordered_range(start, date);
+            selected_range.set(Some(range));
+            close_panel.call(Some(range));
+        }
+        None => pending_start.set(Some(date)),
+    });
+
+    let shift_months = move |months: i32| {
+        start_show_date.update(|date| {
+            *date = if months < 0 {
+                *date - Months::new(months.unsigned_abs())
+            } else {
+                *date + Months::new(months as u32)
+            }
+        });
+    };
+
+    view! {
+        <CSSTransition
+            node_ref=panel_ref
+            name="fade-in-scale-up-transition"
+            appear=is_show_panel.get_untracked()
+            show=is_show_panel
+            let:display
+        >
+            <div
+                class="thaw-date-picker-panel thaw-date-picker-panel--range"
+                style=move || display.get()
+                ref=panel_ref
+                on:mouseleave=move |_| hover_date.set(None)
+            >
+                <div class="thaw-date-picker-date-panel__calendar">
+                    <div class="thaw-date-picker-range-panel__header">
+                        <Button
+                            variant=ButtonVariant::Link
+                            size=ButtonSize::Small
+                            icon=icondata_ai::AiArrowLeftOutlined
+                            on_click=move |_| shift_months(-12)
+                        />
+                        <Button
+                            variant=ButtonVariant::Link
+                            size=ButtonSize::Small
+                            icon=icondata_ai::AiLeftOutlined
+                            on_click=move |_| shift_months(-1)
+                        />
+                        <RangePanelTitle show_date=start_show_date/>
+                    </div>
+                    <RangePanelDates show_date=start_show_date preview_range hover_date on_select/>
+                </div>
+                <div class="thaw-date-picker-date-panel__calendar">
+                    <div class="thaw-date-picker-range-panel__header thaw-date-picker-range-panel__header--end">
+                        <RangePanelTitle show_date=end_show_date/>
+                        <Button
+                            variant=ButtonVariant::Link
+                            size=ButtonSize::Small
+                            icon=icondata_ai::AiRightOutlined
+                            on_click=move |_| shift_months(1)
+                        />
+                        <Button
+                            variant=ButtonVariant::Link
+                            size=ButtonSize::Small
+                            icon=icondata_ai::AiArrowRightOutlined
+                            on_click=move |_| shift_months(12)
+                        />
+                    </div>
+                    <RangePanelDates show_date=end_show_date preview_range hover_date on_select/>
+                </div>
+            </div>
+        </CSSTransition>
+    }
+}
+
+#[component]
+fn RangePanelTitle(#[prop(into)] show_date: Signal<NaiveDate>) -> impl IntoView {
+    let locale = use_locale();
+    view! {
+        <div class="thaw-date-picker-range-panel__title">
+            {move || show_date.with(|date| locale.with(|locale| locale.format_month_year(date)))}
+        </div>
+    }
+}
+
+#[component]
+fn RangePanelDates(
+    #[prop(into)] show_date: Signal<NaiveDate>,
+    preview_range: Memo<Option<(NaiveDate, NaiveDate)>>,
+    hover_date: RwSignal<Option<NaiveDate>>,
+    on_select: Callback<NaiveDate>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let dates = create_memo(move |_| {
+        let first_day_of_week = locale.with(|locale| locale.first_day_of_week);
+        month_dates(show_date.get(), first_day_of_week)
+    });
+    view! {
+        <PanelWeekdays/>
+        <div class="thaw-date-picker-date-panel__dates">
+            {move || {
+                dates
+                    .get()
+                    .into_iter()
+                    .map(|date| view! { <RangePanelItem date preview_range hover_date on_select/> })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn RangePanelItem(
+    date: CalendarItemDate,
+    preview_range: Memo<Option<(NaiveDate, NaiveDate)>>,
+    hover_date: RwSignal<Option<NaiveDate>>,
+    on_select: Callback<NaiveDate>,
+) -> impl IntoView {
+    let item_date = *date.deref();
+    // Days of the neighbouring months are already shown by the other calendar.
+    let is_other_month = date.is_other_month();
+    let is_edge = create_memo(move |_| {
+        !is_other_month
+            && preview_range
+                .get()
+                .is_some_and(|(start, end)| item_date == start || item_date == end)
+    });
+    let is_in_range = create_memo(move |_| {
+        !is_other_month
+            && preview_range
+                .get()
+                .is_some_and(|(start, end)| start < item_date && item_date < end)
+    });
+
+    view! {
+        <div
+            class="thaw-date-picker-date-panel__item"
+            class=("thaw-date-picker-date-panel__item--other-month", is_other_month)
+            class=("thaw-date-picker-date-panel__item--selected", move || is_edge.get())
+            class=("thaw-date-picker-date-panel__item--in-range", move || is_in_range.get())
+            on:mouseenter=move |_| hover_date.set(Some(item_date))
+            on:click=move |_| on_select.call(item_date)
+        >
+            <div class="thaw-date-picker-date-panel__item-day">
+                {date.day()}
+                {if date.is_today() {
+                    view! { <div class="thaw-date-picker-date-panel__item-sup"></div> }.into()
+                } else {
+                    None
+                }}
+
+            </div>
+        </div>
+    }
+}
+
+fn ordered_range(a: NaiveDate, b: NaiveDate) -> (NaiveDate, NaiveDate) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[derive(Clone)]
+pub struct RangePanelRef {
+    show_date: RwSignal<NaiveDate>,
+    pending_start: RwSignal<Option<NaiveDate>>,
+}
+
+impl RangePanelRef {
+    pub fn init_panel(&self, show_date: NaiveDate) {
+        self.show_date.set(show_date);
+        self.pending_start.set(None);
+    }
+}
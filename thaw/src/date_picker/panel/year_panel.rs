@@ -1,4 +1,4 @@
-use super::PanelVariant;
+use super::{DateLimits, PanelVariant};
 use crate::{Button, ButtonSize, ButtonVariant};
 use chrono::{Datelike, NaiveDate};
 use leptos::*;
@@ -10,6 +10,7 @@ const MIN_YEAR: i32 = (i32::MIN >> 13) / 10 + 1;
 pub fn YearPanel(
     date_panel_show_date: RwSignal<NaiveDate>,
     panel_variant: RwSignal<PanelVariant>,
+    #[prop(optional)] limits: DateLimits,
 ) -> impl IntoView {
     let show_min_year = create_rw_signal(date_panel_show_date.get_untracked().year() / 10);
     let previous_year_range = move |_| {
@@ -55,14 +56,26 @@ pub fn YearPanel(
                     (-1..=10)
                         .map(|index| {
                             let year = show_min_year.get() * 10 + index;
+                            let is_disabled = create_memo(move |_| {
+                                let first_day = NaiveDate::from_ymd_opt(year, 1, 1);
+                                let last_day = NaiveDate::from_ymd_opt(year, 12, 31);
+                                first_day
+                                    .zip(last_day)
+                                    .is_some_and(|(first_day, last_day)| {
+                                        limits.is_range_disabled(first_day, last_day)
+                                    })
+                            });
                             let on_click = move |_| {
+                                if is_disabled.get_untracked() {
+                                    return;
+                                }
                                 date_panel_show_date
                                     .update(|date| {
                                         *date = date.with_year(year).unwrap();
                                     });
                                 panel_variant.set(PanelVariant::Month);
                             };
-                            view! { <YearPanelItem date_panel_show_date year on:click=on_click/> }
+                            view! { <YearPanelItem date_panel_show_date year is_disabled on:click=on_click/> }
                         })
                         .collect_view()
                 }}
@@ -73,13 +86,18 @@ pub fn YearPanel(
 }
 
 #[component]
-fn YearPanelItem(date_panel_show_date: RwSignal<NaiveDate>, year: i32) -> impl IntoView {
+fn YearPanelItem(
+    date_panel_show_date: RwSignal<NaiveDate>,
+    year: i32,
+    is_disabled: Memo<bool>,
+) -> impl IntoView {
     let is_selected = create_memo(move |_| date_panel_show_date.with(|date| date.year() == year));
 
     view! {
         <div
             class="thaw-date-picker-year-panel__item"
             class=("thaw-date-picker-year-panel__item--selected", move || is_selected.get())
+            class=("thaw-date-picker-year-panel__item--disabled", move || is_disabled.get())
         >
             <div class="thaw-date-picker-year-panel__item-year">{year}</div>
         </div>
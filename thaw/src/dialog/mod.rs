@@ -0,0 +1,181 @@
+use crate::{
+    modal::ModalManagerInjection, use_locale, Button, ButtonVariant, Modal, ModalFooter, Space,
+    SpaceJustify,
+};
+use futures_channel::oneshot;
+use leptos::*;
+use std::{cell::RefCell, future::Future, rc::Rc};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DialogOptions {
+    pub title: String,
+    pub content: Option<ViewFn>,
+    /// Text of the confirm button, the `ok` text of the locale when `None`.
+    pub positive_text: Option<String>,
+    /// Text of the cancel button, the `cancel` text of the locale when `None`.
+    pub negative_text: Option<String>,
+    pub width: String,
+    pub mask_closeable: bool,
+}
+
+impl Default for DialogOptions {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            content: None,
+            positive_text: None,
+            negative_text: None,
+            width: "420px".into(),
+            mask_closeable: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct DialogItem {
+    id: Uuid,
+    options: DialogOptions,
+    is_confirm: bool,
+    sender: Rc<RefCell<Option<oneshot::Sender<bool>>>>,
+}
+
+#[component]
+pub fn DialogProvider(children: Children) -> impl IntoView {
+    ModalManagerInjection::provide();
+    let injection = DialogInjection {
+        dialog_list: create_rw_signal(vec![]),
+    };
+
+    let on_after_leave = move |id| {
+        injection.dialog_list.update(move |dialog_list| {
+            dialog_list.retain(|dialog| dialog.id != id);
+        });
+    };
+
+    view! {
+        <Provider value=injection>
+            {children()}
+            <For
+                each=move || injection.dialog_list.get()
+                key=|dialog| dialog.id
+                children=move |dialog| view! { <Dialog dialog on_after_leave/> }
+            />
+        </Provider>
+    }
+}
+
+#[component]
+fn Dialog(dialog: DialogItem, #[prop(into)] on_after_leave: Callback<Uuid>) -> impl IntoView {
+    let DialogItem {
+        id,
+        options,
+        is_confirm,
+        sender,
+    } = dialog;
+    let locale = use_locale();
+    let show = create_rw_signal(true);
+    let confirmed = store_value(false);
+
+    // Closing by the close button, the mask or Esc answers `false`.
+    create_effect(move |_| {
+        if !show.get() {
+            if let Some(sender) = sender.borrow_mut().take() {
+                _ = sender.send(confirmed.get_value());
+            }
+        }
+    });
+
+    let on_positive_click = move |_| {
+        confirmed.set_value(true);
+        show.set(false);
+    };
+    let on_negative_click = move |_| show.set(false);
+    let DialogOptions {
+        title,
+        content,
+        positive_text,
+        negative_text,
+        width,
+        mask_closeable,
+    } = options;
+    let positive_text = StoredValue::new(positive_text);
+    let negative_text = StoredValue::new(negative_text);
+
+    view! {
+        <Modal
+            show
+            title
+            width
+            mask_closeable
+            on_after_leave=move |_| on_after_leave.call(id)
+        >
+            {content.map(|content| content.run())}
+            <ModalFooter slot>
+                <Space justify=SpaceJustify::End>
+                    {is_confirm
+                        .then(|| {
+                            view! {
+                                <Button
+                                    variant=ButtonVariant::Outlined
+                                    on_click=on_negative_click
+                                >
+                                    {move || {
+                                        negative_text
+                                            .get_value()
+                                            .unwrap_or_else(|| locale.with(|locale| locale.cancel.clone()))
+                                    }}
+                                </Button>
+                            }
+                        })}
+                    <Button on_click=on_positive_click>
+                        {move || {
+                            positive_text
+                                .get_value()
+                                .unwrap_or_else(|| locale.with(|locale| locale.ok.clone()))
+                        }}
+                    </Button>
+                </Space>
+            </ModalFooter>
+        </Modal>
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DialogInjection {
+    dialog_list: RwSignal<Vec<DialogItem>>,
+}
+
+impl DialogInjection {
+    /// Shows a dialog with confirm and cancel buttons, resolving to `true` when
+    /// it was confirmed and to `false` when it was canceled or closed.
+    pub fn confirm(&self, options: DialogOptions) -> impl Future<Output = bool> {
+        let receiver = self.create(options, true);
+        async move { receiver.await.unwrap_or(false) }
+    }
+
+    /// Shows a dialog with a single confirm button, resolving once it is closed.
+    pub fn alert(&self, options: DialogOptions) -> impl Future<Output = ()> {
+        let receiver = self.create(options, false);
+        async move {
+            _ = receiver.await;
+        }
+    }
+
+    fn create(&self, options: DialogOptions, is_confirm: bool) -> oneshot::Receiver<bool> {
+        let (sender, receiver) = oneshot::channel();
+        self.dialog_list.update(move |dialog_list| {
+            dialog_list.push(DialogItem {
+                id: Uuid::new_v4(),
+                options,
+                is_confirm,
+                sender: Rc::new(RefCell::new(Some(sender))),
+            });
+        });
+        receiver
+    }
+}
+
+pub fn use_dialog() -> DialogInjection {
+    expect_context::<DialogInjection>()
+}
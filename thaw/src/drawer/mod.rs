@@ -1,7 +1,10 @@
 use crate::{Card, Scrollbar};
-use leptos::*;
+use leptos::{leptos_dom::helpers::WindowListenerHandle, *};
 use thaw_components::{CSSTransition, FocusTrap, Teleport};
-use thaw_utils::{class_list, mount_style, use_lock_html_scroll, Model, OptionalProp};
+use thaw_utils::{
+    class_list, mount_style, use_direction, use_lock_html_scroll, Model, OptionalMaybeSignal,
+    OptionalProp,
+};
 
 #[component]
 pub fn Drawer(
@@ -15,14 +18,38 @@ pub fn Drawer(
     #[prop(default = 2000.into(), into)] z_index: MaybeSignal<i16>,
     #[prop(optional, into)] mount: DrawerMount,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// Whether the drawer can be resized by dragging its inner edge.
+    #[prop(optional, into)]
+    resizable: MaybeSignal<bool>,
+    /// The width of a left or right drawer, or the height of a top or bottom one.
+    /// Overrides `width` or `height` once set, and is written when the drawer is resized.
+    #[prop(optional, into)]
+    size: Option<Model<String>>,
+    /// The minimum size in pixels when resizing.
+    #[prop(default = 200.0.into(), into)]
+    min_size: MaybeSignal<f64>,
+    /// The maximum size in pixels when resizing, the size of the container when `None`.
+    #[prop(optional, into)]
+    max_size: OptionalMaybeSignal<f64>,
     children: Children,
 ) -> impl IntoView {
     mount_style("drawer", include_str!("./drawer.css"));
+    let size = size.unwrap_or_else(|| Model::from(String::new()));
     let style = create_memo(move |_| {
         let mut style = String::new();
+        let size = size.get();
+        let is_vertical = matches!(
+            placement.get(),
+            DrawerPlacement::Top | DrawerPlacement::Bottom
+        );
+        let (width, height) = match (size.is_empty(), is_vertical) {
+            (true, _) => (width.get(), height.get()),
+            (false, true) => (width.get(), size),
+            (false, false) => (size, height.get()),
+        };
 
-        style.push_str(&format!("--thaw-width: {};", width.get()));
-        style.push_str(&format!("--thaw-height: {};", height.get()));
+        style.push_str(&format!("--thaw-width: {};", width));
+        style.push_str(&format!("--thaw-height: {};", height));
 
         style.push_str(&format!("z-index: {};", z_index.get()));
         style
@@ -37,14 +64,27 @@ pub fn Drawer(
         placement: MaybeSignal<DrawerPlacement>,
         class: OptionalProp<MaybeSignal<String>>,
         style: Memo<String>,
+        resizable: MaybeSignal<bool>,
+        size: Model<String>,
+        min_size: MaybeSignal<f64>,
+        max_size: OptionalMaybeSignal<f64>,
         children: Children,
     ) -> impl IntoView {
+        let container_ref = NodeRef::<html::Div>::new();
         let mask_ref = NodeRef::<html::Div>::new();
         let drawer_ref = NodeRef::<html::Div>::new();
 
         let is_css_transition = RwSignal::new(false);
+        let dir = use_direction();
+        let actual_placement = Memo::new(move |_| {
+            if dir.is_some_and(|dir| dir.get().is_rtl()) {
+                placement.get().mirror()
+            } else {
+                placement.get()
+            }
+        });
         let placement = Memo::new(move |prev| {
-            let placement = placement.get().as_str();
+            let placement = actual_placement.get().as_str();
             let Some(prev) = prev else {
                 return placement;
             };
@@ -82,9 +122,85 @@ pub fn Drawer(
             show.set(false);
         });
 
+        // A nested drawer pushes its parent aside while it is shown.
+        let pushed_count = RwSignal::new(0usize);
+        if let Some(parent) = use_context::<DrawerInjection>() {
+            Effect::new(move |prev: Option<bool>| {
+                let is_show = show.get();
+                match (prev.unwrap_or(false), is_show) {
+                    (false, true) => parent.pushed_count.update(|count| *count += 1),
+                    (true, false) => parent
+                        .pushed_count
+                        .update(|count| *count = count.saturating_sub(1)),
+                    _ => {}
+                }
+                is_show
+            });
+            on_cleanup(move || {
+                if show.get_untracked() {
+                    parent
+                        .pushed_count
+                        .try_update(|count| *count = count.saturating_sub(1));
+                }
+            });
+        }
+
+        let resize_handles = StoredValue::new(Vec::<WindowListenerHandle>::new());
+        let stop_resize = move || {
+            resize_handles.update_value(|handles| {
+                for handle in handles.drain(..) {
+                    handle.remove();
+                }
+            });
+        };
+        on_cleanup(stop_resize);
+        let on_resize_start = move |ev: ev::MouseEvent| {
+            if ev.button() != 0 {
+                return;
+            }
+            let (Some(container_el), Some(drawer_el)) =
+                (container_ref.get_untracked(), drawer_ref.get_untracked())
+            else {
+                return;
+            };
+            ev.prevent_default();
+            stop_resize();
+            let placement = actual_placement.get_untracked();
+            let is_vertical = matches!(placement, DrawerPlacement::Top | DrawerPlacement::Bottom);
+            let (start_size, container_size) = if is_vertical {
+                (drawer_el.offset_height(), container_el.client_height())
+            } else {
+                (drawer_el.offset_width(), container_el.client_width())
+            };
+            let max = max_size
+                .get_untracked()
+                .map_or(f64::from(container_size), |max_size| {
+                    max_size.min(f64::from(container_size))
+                });
+            let min = min_size.get_untracked();
+            let start = (f64::from(ev.client_x()), f64::from(ev.client_y()));
+
+            let on_mousemove = window_event_listener(ev::mousemove, move |ev| {
+                let delta = (
+                    f64::from(ev.client_x()) - start.0,
+                    f64::from(ev.client_y()) - start.1,
+                );
+                let new_size =
+                    resized_drawer_size(placement, f64::from(start_size), delta, min, max);
+                size.set(format!("{new_size}px"));
+            });
+            let on_mouseup = window_event_listener(ev::mouseup, move |_| stop_resize());
+            resize_handles.set_value(vec![on_mousemove, on_mouseup]);
+        };
+
         view! {
             <FocusTrap disabled=!close_on_esc active=show.signal() on_esc>
-                <div class="thaw-drawer-container" style=move || style.get()>
+                <div
+                    class="thaw-drawer-container"
+                    style=move || style.get()
+                    dir=dir.map(|dir| move || dir.get().as_str())
+                    ref=container_ref
+                >
                     <CSSTransition
                         node_ref=mask_ref
                         appear=show.get_untracked()
@@ -114,7 +230,8 @@ pub fn Drawer(
                         <div
                             class=class_list![
                                 "thaw-drawer", move || format!("thaw-drawer--placement-{}",
-                                placement.get()), class.map(| c | move || c.get())
+                                placement.get()), ("thaw-drawer--pushed", move || pushed_count.get() > 0),
+                                class.map(| c | move || c.get())
                             ]
 
                             style=move || display.get()
@@ -122,9 +239,23 @@ pub fn Drawer(
                             role="dialog"
                             aria-modal="true"
                         >
+                            {move || {
+                                resizable
+                                    .get()
+                                    .then(|| {
+                                        view! {
+                                            <div
+                                                class="thaw-drawer__resize-handle"
+                                                on:mousedown=on_resize_start
+                                            ></div>
+                                        }
+                                    })
+                            }}
                             <Card title>
                                 <Scrollbar content_style="padding: 20px 28px;">
-                                    {children()}
+                                    <Provider value=DrawerInjection { pushed_count }>
+                                        {children()}
+                                    </Provider>
                                 </Scrollbar>
                             </Card>
                         </div>
@@ -136,17 +267,69 @@ pub fn Drawer(
 
     match mount {
         DrawerMount::None => {
-            view! { <DrawerInnr show mask_closeable close_on_esc title placement class style children/> }
+            view! {
+                <DrawerInnr
+                    show
+                    mask_closeable
+                    close_on_esc
+                    title
+                    placement
+                    class
+                    style
+                    resizable
+                    size
+                    min_size
+                    max_size
+                    children
+                />
+            }
         }
         DrawerMount::Body => view! {
             <Teleport immediate=show.signal()>
-                <DrawerInnr show mask_closeable close_on_esc title placement class style children/>
+                <DrawerInnr
+                    show
+                    mask_closeable
+                    close_on_esc
+                    title
+                    placement
+                    class
+                    style
+                    resizable
+                    size
+                    min_size
+                    max_size
+                    children
+                />
             </Teleport>
         },
     }
 }
 
-#[derive(Clone, Default)]
+/// Lets the drawers nested in a drawer push it aside.
+#[derive(Clone, Copy)]
+struct DrawerInjection {
+    pushed_count: RwSignal<usize>,
+}
+
+/// The size of a drawer at `placement` after its resize handle was dragged by `delta`,
+/// the handle is on the edge facing the center of the container.
+fn resized_drawer_size(
+    placement: DrawerPlacement,
+    start_size: f64,
+    delta: (f64, f64),
+    min: f64,
+    max: f64,
+) -> f64 {
+    let size = match placement {
+        DrawerPlacement::Top => start_size + delta.1,
+        DrawerPlacement::Bottom => start_size - delta.1,
+        DrawerPlacement::Left => start_size + delta.0,
+        DrawerPlacement::Right => start_size - delta.0,
+    };
+    size.min(max).max(min)
+}
+
+#[derive(Clone, Default, PartialEq)]
 pub enum DrawerPlacement {
     Top,
     Bottom,
@@ -166,6 +349,15 @@ impl DrawerPlacement {
             Self::Right => "right",
         }
     }
+
+    /// The placement for right-to-left layouts, the left and the right are swapped.
+    pub fn mirror(&self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            placement => *placement,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -174,3 +366,32 @@ pub enum DrawerMount {
     #[default]
     Body,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{resized_drawer_size, DrawerPlacement};
+
+    #[test]
+    fn resized_drawer_size_follows_the_inner_edge() {
+        assert_eq!(
+            resized_drawer_size(DrawerPlacement::Right, 400.0, (-50.0, 0.0), 200.0, 800.0),
+            450.0
+        );
+        assert_eq!(
+            resized_drawer_size(DrawerPlacement::Left, 400.0, (-50.0, 0.0), 200.0, 800.0),
+            350.0
+        );
+        assert_eq!(
+            resized_drawer_size(DrawerPlacement::Bottom, 300.0, (0.0, 80.0), 200.0, 800.0),
+            220.0
+        );
+        assert_eq!(
+            resized_drawer_size(DrawerPlacement::Top, 300.0, (0.0, 900.0), 200.0, 800.0),
+            800.0
+        );
+        assert_eq!(
+            resized_drawer_size(DrawerPlacement::Right, 400.0, (500.0, 0.0), 200.0, 800.0),
+            200.0
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use leptos::*;
 use thaw_components::{Fallback, If, OptionComp, Then};
-use thaw_utils::{class_list, mount_style, OptionalMaybeSignal, OptionalProp};
+use thaw_utils::{class_list, mount_style, OptionalMaybeSignal, OptionalProp, StoredMaybeSignal};
 
 use crate::{
     dropdown::{HasIcon, OnSelect},
-    use_theme, Icon, Theme,
+    theme::mount_theme_vars,
+    Icon,
 };
 
 #[component]
@@ -15,23 +16,8 @@ pub fn DropdownItem(
     #[prop(optional, into)] disabled: MaybeSignal<bool>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("dropdown-item", include_str!("./dropdown-item.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.dropdown.item_color_hover
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-disabled: {};",
-                theme.dropdown.font_color_disabled
-            ));
-        });
-        css_vars
-    });
-
     let has_icon = use_context::<HasIcon>().expect("HasIcon not provided").0;
 
     if icon.get().is_some() {
@@ -39,8 +25,9 @@ pub fn DropdownItem(
     }
 
     let on_select = use_context::<OnSelect>().expect("OnSelect not provided").0;
+    let key: StoredMaybeSignal<_> = key.into();
 
-    let on_click = move |_| {
+    let on_click = move || {
         if disabled.get() {
             return;
         }
@@ -54,20 +41,29 @@ pub fn DropdownItem(
                 class.map(| c | move || c.get())
             ]
 
-            style=move || css_vars.get()
-            on:click=on_click
+            on:click=move |_| on_click()
+            on:keydown=move |ev| {
+                if matches!(ev.key().as_str(), " " | "Enter") {
+                    ev.prevent_default();
+                    on_click();
+                }
+            }
+
+            role="menuitem"
+            tabindex="-1"
+            aria-disabled=move || disabled.get().then_some("true")
         >
 
             <OptionComp value=icon.get() let:icon>
                 <Fallback slot>
                     <If cond=has_icon>
                         <Then slot>
-                            <span style="width: 18px; margin-right: 8px"></span>
+                            <span style="width: 18px; margin-inline-end: 8px"></span>
                         </Then>
                     </If>
                 </Fallback>
 
-                <Icon icon=icon style="font-size: 18px; margin-right: 8px"/>
+                <Icon icon=icon style="font-size: 18px; margin-inline-end: 8px"/>
             </OptionComp>
             <span style="flex-grow: 1">{label}</span>
         </div>
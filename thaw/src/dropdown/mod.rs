@@ -10,10 +10,11 @@ pub use theme::DropdownTheme;
 
 use leptos::{leptos_dom::helpers::TimeoutHandle, *};
 use thaw_utils::{
-    add_event_listener, call_on_click_outside, class_list, mount_style, OptionalProp,
+    add_event_listener, call_on_click_outside, class_list, mount_style, use_roving_focus,
+    OptionalProp, RovingFocusOrientation,
 };
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 
 #[slot]
 pub struct DropdownTrigger {
@@ -37,23 +38,47 @@ pub fn Dropdown(
     #[prop(into)] on_select: Callback<String>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("dropdown", include_str!("./dropdown.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.dropdown.background_color
-            ));
-            css_vars.push_str(&format!("--thaw-font-color: {};", theme.common.font_color));
-        });
-        css_vars
-    });
     let dropdown_ref = create_node_ref::<html::Div>();
     let target_ref = create_node_ref::<html::Div>();
     let is_show_dropdown = create_rw_signal(false);
     let show_dropdown_handle = store_value(None::<TimeoutHandle>);
+    let roving_focus = use_roving_focus(
+        dropdown_ref,
+        ".thaw-dropdown-item",
+        RovingFocusOrientation::Vertical,
+    );
+
+    let on_trigger_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() != "ArrowDown" {
+            return;
+        }
+        ev.prevent_default();
+        is_show_dropdown.set(true);
+        // The items are mounted in the next frame.
+        request_animation_frame(move || roving_focus.focus_first());
+    };
+    let on_dropdown_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Escape" {
+            is_show_dropdown.set(false);
+            // Gives the focus back to the trigger, e.g. a button.
+            let trigger_el = target_ref.get_untracked().and_then(|target_el| {
+                target_el
+                    .query_selector("button, a[href], input, [tabindex]")
+                    .ok()
+                    .flatten()
+            });
+            if let Some(trigger_el) = trigger_el {
+                use wasm_bindgen::JsCast;
+                if let Ok(trigger_el) = trigger_el.dyn_into::<web_sys::HtmlElement>() {
+                    _ = trigger_el.focus();
+                }
+            }
+        } else {
+            roving_focus.on_keydown(ev);
+        }
+    };
 
     let on_mouse_enter = move |_| {
         if trigger_type != DropdownTriggerType::Hover {
@@ -118,6 +143,9 @@ pub fn Dropdown(
                 ref=target_ref
                 on:mouseenter=on_mouse_enter
                 on:mouseleave=on_mouse_leave
+                on:keydown=on_trigger_keydown
+                aria-haspopup="menu"
+                aria-expanded=move || if is_show_dropdown.get() { "true" } else { "false" }
             >
                 {trigger_children()}
             </div>
@@ -131,13 +159,13 @@ pub fn Dropdown(
                 >
                     <div
                         class="thaw-dropdown"
-                        style=move || {
-                            display.get().map(|d| d.to_string()).unwrap_or_else(|| css_vars.get())
-                        }
+                        style=move || display.get()
 
                         ref=dropdown_ref
                         on:mouseenter=on_mouse_enter
                         on:mouseleave=on_mouse_leave
+                        on:keydown=on_dropdown_keydown
+                        role="menu"
                     >
                         <div class=class.map(|c| move || c.get())>{children()}</div>
                     </div>
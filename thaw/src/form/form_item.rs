@@ -0,0 +1,155 @@
+use super::{
+    rule::validate_rules, use_form, FormFieldValue, FormItemHandle, FormItemValidateFuture,
+    FormRule,
+};
+use crate::{theme::mount_theme_vars, use_locale};
+use leptos::*;
+use thaw_components::OptionComp;
+use thaw_utils::{class_list, mount_style, OptionalProp};
+
+#[component]
+pub fn FormItem(
+    #[prop(optional, into)] label: OptionalProp<MaybeSignal<String>>,
+    /// Shows the required mark and adds a `FormRule::Required` rule if there is none.
+    #[prop(optional)]
+    required: bool,
+    #[prop(optional)] rules: Vec<FormRule>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    children: Children,
+) -> impl IntoView {
+    mount_theme_vars();
+    mount_style("form", include_str!("./form.css"));
+    let mut rules = rules;
+    if required
+        && !rules
+            .iter()
+            .any(|rule| matches!(rule, FormRule::Required { .. }))
+    {
+        let message = use_locale().with_untracked(|locale| {
+            label.as_ref().map_or_else(
+                || locale.required.clone(),
+                |label| locale.format_required(&label.get_untracked()),
+            )
+        });
+        rules.insert(0, FormRule::required(message));
+    }
+    let rules = StoredValue::new(rules);
+    let field = RwSignal::new(None::<FormField>);
+    let error = RwSignal::new(None::<String>);
+    // Results of outdated validations are dropped.
+    let validation_id = StoredValue::new(0usize);
+    let is_resetting = StoredValue::new(false);
+
+    let validate = Callback::new(move |_| {
+        let value = field
+            .with_untracked(|field| field.map(|field| field.value.get_untracked()))
+            .unwrap_or(FormFieldValue::Empty);
+        validation_id.update_value(|id| *id += 1);
+        let id = validation_id.get_value();
+        let rules = rules.get_value();
+        Box::pin(async move {
+            let result = validate_rules(rules, value).await;
+            if validation_id.try_get_value() == Some(id) {
+                error.try_set(result.as_ref().err().cloned());
+            }
+            result.is_ok()
+        }) as FormItemValidateFuture
+    });
+    let reset = Callback::new(move |_| {
+        validation_id.update_value(|id| *id += 1);
+        is_resetting.set_value(true);
+        if let Some(field) = field.get_untracked() {
+            field.reset.call(());
+        }
+        is_resetting.set_value(false);
+        error.set(None);
+    });
+
+    if let Some(form) = use_form() {
+        let id = uuid::Uuid::new_v4();
+        form.register_item(FormItemHandle {
+            id,
+            validate,
+            reset,
+        });
+        on_cleanup(move || form.unregister_item(&id));
+    }
+
+    create_effect(move |prev: Option<Option<FormFieldValue>>| {
+        let value = field.with(|field| field.map(|field| field.value.get()));
+        if let Some(Some(prev)) = prev {
+            if value.as_ref() != Some(&prev) && !is_resetting.get_value() {
+                spawn_local(async move {
+                    validate.call(()).await;
+                });
+            }
+        }
+        value
+    });
+
+    let invalid = Signal::derive(move || error.with(|error| error.is_some()));
+    let is_required = rules.with_value(|rules| {
+        rules
+            .iter()
+            .any(|rule| matches!(rule, FormRule::Required { .. }))
+    });
+
+    view! {
+        <Provider value=FormItemInjection { field, invalid }>
+            <div
+                class=class_list![
+                    "thaw-form-item", ("thaw-form-item--invalid", move || invalid.get()), class
+                    .map(| c | move || c.get())
+                ]
+            >
+                <OptionComp value=label.into_option() let:label>
+                    <label class="thaw-form-item__label">
+                        {move || label.get()}
+                        {is_required
+                            .then(|| {
+                                view! { <span class="thaw-form-item__required-mark">"*"</span> }
+                            })}
+                    </label>
+                </OptionComp>
+                <div class="thaw-form-item__content">{children()}</div>
+                <div class="thaw-form-item__feedback">{move || error.get()}</div>
+            </div>
+        </Provider>
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FormField {
+    value: Signal<FormFieldValue>,
+    reset: Callback<()>,
+}
+
+#[derive(Clone, Copy)]
+struct FormItemInjection {
+    field: RwSignal<Option<FormField>>,
+    invalid: Signal<bool>,
+}
+
+/// Registers a form control with the surrounding `FormItem` and merges the
+/// validation state into the control's own `invalid` prop.
+///
+/// Only the outermost control is registered, so components wrapping an `Input`
+/// (like `DatePicker`) are validated by their own value. Custom controls call it
+/// to take part in `validate()` and `reset()` of the `Form`.
+pub fn use_form_field(
+    invalid: MaybeSignal<bool>,
+    value: impl Fn() -> FormFieldValue + 'static,
+    reset: impl Fn() + 'static,
+) -> MaybeSignal<bool> {
+    let Some(form_item) = use_context::<FormItemInjection>() else {
+        return invalid;
+    };
+    if form_item.field.with_untracked(|field| field.is_none()) {
+        form_item.field.set(Some(FormField {
+            value: Signal::derive(value),
+            reset: Callback::new(move |_| reset()),
+        }));
+    }
+
+    MaybeSignal::derive(move || invalid.get() || form_item.invalid.get())
+}
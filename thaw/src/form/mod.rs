@@ -0,0 +1,118 @@
+mod form_item;
+mod rule;
+
+pub use form_item::{use_form_field, FormItem};
+pub use rule::{FormFieldValue, FormRule, FormValidatorFuture};
+
+use leptos::*;
+use std::{future::Future, pin::Pin};
+use thaw_utils::{class_list, mount_style, ComponentRef, OptionalProp};
+use uuid::Uuid;
+
+#[component]
+pub fn Form(
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// Called after a submission passed the validation.
+    #[prop(optional, into)]
+    on_submit: Option<Callback<()>>,
+    #[prop(optional)] comp_ref: ComponentRef<FormRef>,
+    children: Children,
+) -> impl IntoView {
+    mount_style("form", include_str!("./form.css"));
+    let items = StoredValue::new(Vec::<FormItemHandle>::new());
+    let form_ref = FormRef { items };
+    comp_ref.load(form_ref.clone());
+
+    let on_form_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let form_ref = form_ref.clone();
+        spawn_local(async move {
+            if form_ref.validate().await {
+                if let Some(on_submit) = on_submit {
+                    on_submit.call(());
+                }
+            }
+        });
+    };
+
+    view! {
+        <Provider value=FormInjection { items }>
+            <form
+                class=class_list!["thaw-form", class.map(| c | move || c.get())]
+                on:submit=on_form_submit
+                novalidate
+            >
+                {children()}
+            </form>
+        </Provider>
+    }
+}
+
+pub(crate) type FormItemValidateFuture = Pin<Box<dyn Future<Output = bool>>>;
+
+#[derive(Clone, Copy)]
+pub(crate) struct FormItemHandle {
+    id: Uuid,
+    validate: Callback<(), FormItemValidateFuture>,
+    reset: Callback<()>,
+}
+
+/// Provided by `Form` to the controls and the `FormItem`s inside it.
+#[derive(Clone, Copy)]
+pub struct FormInjection {
+    items: StoredValue<Vec<FormItemHandle>>,
+}
+
+impl FormInjection {
+    /// Validates every `FormItem` of the form, same as [`FormRef::validate`].
+    pub async fn validate(&self) -> bool {
+        FormRef { items: self.items }.validate().await
+    }
+
+    /// Resets every `FormItem` of the form, same as [`FormRef::reset`].
+    pub fn reset(&self) {
+        FormRef { items: self.items }.reset()
+    }
+
+    fn register_item(&self, handle: FormItemHandle) {
+        self.items.update_value(|items| items.push(handle));
+    }
+
+    fn unregister_item(&self, id: &Uuid) {
+        self.items
+            .try_update_value(|items| items.retain(|item| &item.id != id));
+    }
+}
+
+/// The surrounding `Form`, e.g. for a custom submit button.
+pub fn use_form() -> Option<FormInjection> {
+    use_context()
+}
+
+#[derive(Clone)]
+pub struct FormRef {
+    items: StoredValue<Vec<FormItemHandle>>,
+}
+
+impl FormRef {
+    /// Validates every `FormItem` and resolves to `true` if all of them passed.
+    pub async fn validate(&self) -> bool {
+        let items = self.items.get_value();
+        let mut is_valid = true;
+        for item in items {
+            if !item.validate.call(()).await {
+                is_valid = false;
+            }
+        }
+        is_valid
+    }
+
+    /// Restores the initial values and clears the validation messages.
+    pub fn reset(&self) {
+        self.items.with_value(|items| {
+            for item in items {
+                item.reset.call(());
+            }
+        });
+    }
+}
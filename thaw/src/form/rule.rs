@@ -0,0 +1,206 @@
+use leptos::*;
+use std::{future::Future, pin::Pin};
+
+/// The value of a form control as seen by the validation rules.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormFieldValue {
+    Empty,
+    Text(String),
+    List(Vec<String>),
+}
+
+impl FormFieldValue {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Empty => true,
+            Self::Text(text) => text.is_empty(),
+            Self::List(list) => list.is_empty(),
+        }
+    }
+
+    /// Number of characters of a text or number of items of a list.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Text(text) => text.chars().count(),
+            Self::List(list) => list.len(),
+        }
+    }
+}
+
+impl From<String> for FormFieldValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl<T: Into<FormFieldValue>> From<Option<T>> for FormFieldValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Empty, Into::into)
+    }
+}
+
+pub type FormValidatorFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+#[derive(Clone)]
+pub enum FormRule {
+    Required {
+        message: String,
+    },
+    MinLength {
+        len: usize,
+        message: String,
+    },
+    MaxLength {
+        len: usize,
+        message: String,
+    },
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    Pattern {
+        regex: regex::Regex,
+        message: String,
+    },
+    Validator(Callback<FormFieldValue, FormValidatorFuture>),
+}
+
+impl FormRule {
+    pub fn required(message: impl Into<String>) -> Self {
+        Self::Required {
+            message: message.into(),
+        }
+    }
+
+    pub fn min_length(len: usize, message: impl Into<String>) -> Self {
+        Self::MinLength {
+            len,
+            message: message.into(),
+        }
+    }
+
+    pub fn max_length(len: usize, message: impl Into<String>) -> Self {
+        Self::MaxLength {
+            len,
+            message: message.into(),
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn pattern(regex: regex::Regex, message: impl Into<String>) -> Self {
+        Self::Pattern {
+            regex,
+            message: message.into(),
+        }
+    }
+
+    /// Custom validation, an `Err` message marks the field as invalid.
+    pub fn validator<Fut>(validator: impl Fn(FormFieldValue) -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self::Validator(Callback::new(move |value| {
+            Box::pin(validator(value)) as FormValidatorFuture
+        }))
+    }
+
+    /// Returns `None` when the rule can only be checked asynchronously.
+    fn validate_sync(&self, value: &FormFieldValue) -> Option<Result<(), String>> {
+        let is_valid = match self {
+            Self::Required { .. } => !value.is_empty(),
+            // Empty values are left to `Required`.
+            Self::MinLength { len, .. } => value.is_empty() || value.len() >= *len,
+            Self::MaxLength { len, .. } => value.len() <= *len,
+            #[cfg(feature = "regex")]
+            Self::Pattern { regex, .. } => match value {
+                FormFieldValue::Text(text) if !text.is_empty() => regex.is_match(text),
+                _ => true,
+            },
+            Self::Validator(_) => return None,
+        };
+        if is_valid {
+            Some(Ok(()))
+        } else {
+            Some(Err(self.message().to_string()))
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::Required { message }
+            | Self::MinLength { message, .. }
+            | Self::MaxLength { message, .. } => message,
+            #[cfg(feature = "regex")]
+            Self::Pattern { message, .. } => message,
+            Self::Validator(_) => "",
+        }
+    }
+}
+
+/// Checks the rules in order and returns the message of the first failed rule.
+pub(crate) async fn validate_rules(
+    rules: Vec<FormRule>,
+    value: FormFieldValue,
+) -> Result<(), String> {
+    for rule in rules.iter() {
+        if let Some(result) = rule.validate_sync(&value) {
+            result?;
+        } else if let FormRule::Validator(validator) = rule {
+            validator.call(value.clone()).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FormFieldValue, FormRule};
+
+    #[test]
+    fn validate_sync() {
+        let text = |s: &str| FormFieldValue::Text(s.to_string());
+
+        let rule = FormRule::required("required");
+        assert_eq!(
+            rule.validate_sync(&FormFieldValue::Empty),
+            Some(Err("required".to_string()))
+        );
+        assert_eq!(
+            rule.validate_sync(&text("")),
+            Some(Err("required".to_string()))
+        );
+        assert_eq!(rule.validate_sync(&text("a")), Some(Ok(())));
+
+        let rule = FormRule::min_length(3, "min");
+        assert_eq!(rule.validate_sync(&text("")), Some(Ok(())));
+        assert_eq!(
+            rule.validate_sync(&text("ab")),
+            Some(Err("min".to_string()))
+        );
+        assert_eq!(rule.validate_sync(&text("abc")), Some(Ok(())));
+
+        let rule = FormRule::max_length(2, "max");
+        assert_eq!(rule.validate_sync(&text("ab")), Some(Ok(())));
+        assert_eq!(
+            rule.validate_sync(&FormFieldValue::List(vec![
+                "a".into(),
+                "b".into(),
+                "c".into()
+            ])),
+            Some(Err("max".to_string()))
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn validate_pattern() {
+        let text = |s: &str| FormFieldValue::Text(s.to_string());
+
+        let rule = FormRule::pattern(regex::Regex::new("^[0-9]+$").unwrap(), "pattern");
+        assert_eq!(rule.validate_sync(&text("")), Some(Ok(())));
+        assert_eq!(rule.validate_sync(&text("12")), Some(Ok(())));
+        assert_eq!(
+            rule.validate_sync(&text("1a")),
+            Some(Err("pattern".to_string()))
+        );
+    }
+}
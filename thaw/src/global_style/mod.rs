@@ -1,8 +1,13 @@
-use crate::{use_theme, Theme};
+use crate::{theme::mount_theme_vars, use_theme, Theme};
 use leptos::*;
+use thaw_utils::mount_style;
 
 #[component]
 pub fn GlobalStyle() -> impl IntoView {
+    mount_theme_vars();
+    // Styles the body from the design tokens before hydration, so a server
+    // rendered dark theme paints dark from the first frame.
+    mount_style("global-style", include_str!("./global-style.css"));
     let theme = use_theme(Theme::light);
     create_effect(move |_| {
         theme.with(|theme| {
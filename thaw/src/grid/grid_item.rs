@@ -19,7 +19,7 @@ pub fn GridItem(
 
         if offset > 0 {
             style.push_str(&format!(
-                "margin-left: calc((100% - {}px) / {} * {} + {}px);",
+                "margin-inline-start: calc((100% - {}px) / {} * {} + {}px);",
                 (column + offset - 1) * x_gap,
                 column + offset,
                 offset,
@@ -1,4 +1,4 @@
-use crate::theme::{use_theme, Theme};
+use crate::{form::use_form_field, theme::mount_theme_vars};
 use leptos::*;
 use thaw_utils::{class_list, mount_style, ComponentRef, Model, OptionalProp};
 
@@ -15,8 +15,12 @@ pub fn TextArea(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
+    mount_theme_vars();
     mount_style("text-area", include_str!("./text-area.css"));
+    let invalid = use_form_field(invalid, move || value.get().into(), {
+        let initial_value = value.get_untracked();
+        move || value.set(initial_value.clone())
+    });
 
     let value_trigger = create_trigger();
     let on_input = move |ev| {
@@ -43,46 +47,6 @@ pub fn TextArea(
         }
     };
 
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let border_color_hover = theme.common.color_primary.clone();
-            css_vars.push_str(&format!("--thaw-border-color-hover: {border_color_hover};"));
-            css_vars.push_str(&format!("--thaw-box-shadow-color: {border_color_hover}33;"));
-            let border_radius = theme.common.border_radius.clone();
-            css_vars.push_str(&format!("--thaw-border-radius: {border_radius};"));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.input.background_color
-            ));
-            css_vars.push_str(&format!("--thaw-font-color: {};", theme.input.font_color));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                theme.input.border_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-color-error: {};",
-                theme.common.color_error
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-placeholder-color: {};",
-                theme.input.placeholder_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-disabled: {};",
-                theme.input.background_color_disabled
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-disabled: {};",
-                theme.input.font_color_disabled
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-box-shadow-color-invalid: {}33;",
-                theme.common.color_error
-            ));
-        });
-        css_vars
-    });
     let textarea_ref = create_node_ref::<html::Textarea>();
     textarea_ref.on_load(move |_| {
         comp_ref.load(TextAreaRef { textarea_ref });
@@ -109,7 +73,6 @@ pub fn TextArea(
                 || invalid.get()), class.map(| c | move || c.get())
             ]
 
-            style=move || css_vars.get()
         >
             <textarea
                 {..attrs}
@@ -1,4 +1,6 @@
-use crate::{Button, ButtonVariant, ComponentRef, Icon, Input, InputRef, InputSuffix};
+use crate::{
+    form::use_form_field, Button, ButtonVariant, ComponentRef, Icon, Input, InputRef, InputSuffix,
+};
 use leptos::*;
 use num_traits::Bounded;
 use std::ops::{Add, Sub};
@@ -24,6 +26,14 @@ where
     T: Add<Output = T> + Sub<Output = T> + PartialOrd + Bounded,
     T: Default + Clone + FromStr + ToString + 'static,
 {
+    let invalid = use_form_field(
+        invalid,
+        move || value.with(|value| value.to_string()).into(),
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
     let input_value = create_rw_signal(String::default());
     Effect::new_isomorphic(move |prev| {
         value.with(|value| {
@@ -79,9 +89,7 @@ where
         invalid.get() || value < min.get() || value > max.get()
     });
 
-    let parser = parser.map(|parser| {
-        Callback::new(move |v| parser.call(v).to_string())
-    });
+    let parser = parser.map(|parser| Callback::new(move |v| parser.call(v).to_string()));
     let formatter = formatter.map(|formatter| {
         Callback::new(move |v: String| formatter.call(v.parse::<T>().unwrap_or_default()))
     });
@@ -8,14 +8,18 @@ mod breadcrumb;
 mod button;
 mod calendar;
 mod card;
+mod cascader;
 mod checkbox;
 mod code;
 mod collapse;
 mod color_picker;
+mod config_provider;
 mod date_picker;
+mod dialog;
 mod divider;
 mod drawer;
 mod dropdown;
+mod form;
 mod global_style;
 mod grid;
 mod icon;
@@ -24,10 +28,12 @@ mod input;
 mod input_number;
 mod layout;
 mod loading_bar;
+mod locale;
 mod menu;
 mod message;
 pub mod mobile;
 mod modal;
+mod notification;
 mod pagination;
 mod popover;
 mod progress;
@@ -38,12 +44,16 @@ mod skeleton;
 mod slider;
 mod space;
 mod spinner;
+mod steps;
 mod switch;
 mod table;
 mod tabs;
 mod tag;
 mod theme;
 mod time_picker;
+mod transfer;
+mod tree;
+mod tree_select;
 mod typography;
 mod upload;
 
@@ -57,14 +67,18 @@ pub use breadcrumb::*;
 pub use button::*;
 pub use calendar::*;
 pub use card::*;
+pub use cascader::*;
 pub use checkbox::*;
 pub use code::*;
 pub use collapse::*;
 pub use color_picker::*;
+pub use config_provider::*;
 pub use date_picker::*;
+pub use dialog::*;
 pub use divider::*;
 pub use drawer::*;
 pub use dropdown::*;
+pub use form::*;
 pub use global_style::*;
 pub use grid::*;
 pub use icon::*;
@@ -73,9 +87,11 @@ pub use input::*;
 pub use input_number::*;
 pub use layout::*;
 pub use loading_bar::*;
+pub use locale::*;
 pub use menu::*;
 pub use message::*;
 pub use modal::*;
+pub use notification::*;
 pub use pagination::*;
 pub use popover::*;
 pub use progress::*;
@@ -86,6 +102,7 @@ pub use skeleton::*;
 pub use slider::*;
 pub use space::*;
 pub use spinner::*;
+pub use steps::*;
 pub use switch::*;
 pub use table::*;
 pub use tabs::*;
@@ -93,5 +110,8 @@ pub use tag::*;
 pub use thaw_utils::{create_component_ref, ComponentRef, SignalWatch};
 pub use theme::*;
 pub use time_picker::*;
+pub use transfer::*;
+pub use tree::*;
+pub use tree_select::*;
 pub use typography::*;
 pub use upload::*;
@@ -2,7 +2,7 @@ mod loading_bar_provider;
 
 pub use loading_bar_provider::{use_loading_bar, LoadingBarProvider};
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{mount_style, ComponentRef};
 
@@ -27,22 +27,8 @@ impl LoadingBarRef {
 
 #[component]
 pub(crate) fn LoadingBar(#[prop(optional)] comp_ref: ComponentRef<LoadingBarRef>) -> impl IntoView {
+    mount_theme_vars();
     mount_style("loading-bar", include_str!("./loading-bar.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.common.color_success
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-error: {};",
-                theme.common.color_error
-            ));
-        });
-        css_vars
-    });
     let loading_bar_ref = create_node_ref::<html::Div>();
     let loading = create_rw_signal(false);
 
@@ -50,7 +36,7 @@ pub(crate) fn LoadingBar(#[prop(optional)] comp_ref: ComponentRef<LoadingBarRef>
         loading.set(true);
         if let Some(loading_bar_ref) = loading_bar_ref.get_untracked() {
             let loading_bar_ref = loading_bar_ref
-                .style("background-color", "var(--thaw-background-color)")
+                .style("background-color", "var(--thaw-common-color-success)")
                 .style("transition", "none")
                 .style("max-width", "0");
             _ = loading_bar_ref.offset_width();
@@ -69,7 +55,7 @@ pub(crate) fn LoadingBar(#[prop(optional)] comp_ref: ComponentRef<LoadingBarRef>
     let finish = Callback::new(move |_| {
         if let Some(loading_bar_ref) = loading_bar_ref.get_untracked() {
             _ = loading_bar_ref
-                .style("background-color", "var(--thaw-background-color)")
+                .style("background-color", "var(--thaw-common-color-success)")
                 .style("transition", "max-width 0.5s linear")
                 .style("max-width", "100%");
             is_on_transitionend.set_value(true);
@@ -86,7 +72,7 @@ pub(crate) fn LoadingBar(#[prop(optional)] comp_ref: ComponentRef<LoadingBarRef>
                 _ = loading_bar_ref.offset_width();
             }
             _ = loading_bar_ref
-                .style("background-color", "var(--thaw-background-color-error)")
+                .style("background-color", "var(--thaw-common-color-error)")
                 .style("transition", "max-width 0.5s linear")
                 .style("max-width", "100%");
             is_on_transitionend.set_value(true);
@@ -106,7 +92,6 @@ pub(crate) fn LoadingBar(#[prop(optional)] comp_ref: ComponentRef<LoadingBarRef>
             <div
                 class="thaw-loading-bar"
                 ref=loading_bar_ref
-                style=move || css_vars.get()
                 on:transitionend=on_transitionend
             ></div>
         </div>
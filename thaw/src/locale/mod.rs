@@ -0,0 +1,224 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use leptos::*;
+use std::fmt::{self, Write};
+
+/// The built-in texts, calendar names and default formats of the components.
+#[derive(Clone, PartialEq)]
+pub struct Locale {
+    pub name: String,
+    pub first_day_of_week: Weekday,
+    pub months: [String; 12],
+    pub short_months: [String; 12],
+    /// Starting from Sunday.
+    pub weekdays: [String; 7],
+    /// Starting from Sunday.
+    pub min_weekdays: [String; 7],
+    /// `{month}` and `{year}` are replaced, e.g. `"{month} {year}"`.
+    pub month_year_format: String,
+    /// See `chrono::format::strftime`.
+    pub date_format: String,
+    pub time_format: String,
+    pub date_time_format: String,
+    pub today: String,
+    pub now: String,
+    pub ok: String,
+    pub cancel: String,
+    pub no_data: String,
+    /// Placeholder of the filter inputs.
+    pub search_placeholder: String,
+    pub required: String,
+    /// `{label}` is replaced by the label of the form item.
+    pub required_with_label: String,
+}
+
+impl Locale {
+    pub fn en_us() -> Self {
+        Self {
+            name: "en-US".into(),
+            first_day_of_week: Weekday::Sun,
+            months: strings([
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]),
+            short_months: strings([
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            weekdays: strings(["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]),
+            min_weekdays: strings(["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"]),
+            month_year_format: "{month} {year}".into(),
+            date_format: "%Y-%m-%d".into(),
+            time_format: "%H:%M:%S".into(),
+            date_time_format: "%Y-%m-%d %H:%M:%S".into(),
+            today: "Today".into(),
+            now: "Now".into(),
+            ok: "OK".into(),
+            cancel: "Cancel".into(),
+            no_data: "No Data".into(),
+            search_placeholder: "Search".into(),
+            required: "This field is required".into(),
+            required_with_label: "{label} is required".into(),
+        }
+    }
+
+    pub fn zh_cn() -> Self {
+        Self {
+            name: "zh-CN".into(),
+            first_day_of_week: Weekday::Mon,
+            months: strings([
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ]),
+            short_months: strings([
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ]),
+            weekdays: strings(["周日", "周一", "周二", "周三", "周四", "周五", "周六"]),
+            min_weekdays: strings(["日", "一", "二", "三", "四", "五", "六"]),
+            month_year_format: "{year}年{month}".into(),
+            date_format: "%Y-%m-%d".into(),
+            time_format: "%H:%M:%S".into(),
+            date_time_format: "%Y-%m-%d %H:%M:%S".into(),
+            today: "今天".into(),
+            now: "此刻".into(),
+            ok: "确定".into(),
+            cancel: "取消".into(),
+            no_data: "无数据".into(),
+            search_placeholder: "搜索".into(),
+            required: "此项为必填项".into(),
+            required_with_label: "{label}为必填项".into(),
+        }
+    }
+
+    pub fn de_de() -> Self {
+        Self {
+            name: "de-DE".into(),
+            first_day_of_week: Weekday::Mon,
+            months: strings([
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ]),
+            short_months: strings([
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ]),
+            weekdays: strings(["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]),
+            min_weekdays: strings(["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]),
+            month_year_format: "{month} {year}".into(),
+            date_format: "%d.%m.%Y".into(),
+            time_format: "%H:%M:%S".into(),
+            date_time_format: "%d.%m.%Y %H:%M:%S".into(),
+            today: "Heute".into(),
+            now: "Jetzt".into(),
+            ok: "OK".into(),
+            cancel: "Abbrechen".into(),
+            no_data: "Keine Daten".into(),
+            search_placeholder: "Suchen".into(),
+            required: "Dieses Feld ist erforderlich".into(),
+            required_with_label: "{label} ist erforderlich".into(),
+        }
+    }
+
+    pub fn month(&self, date: &NaiveDate) -> &str {
+        &self.months[date.month0() as usize]
+    }
+
+    pub fn format_month_year(&self, date: &NaiveDate) -> String {
+        self.month_year_format
+            .replace("{month}", self.month(date))
+            .replace("{year}", &date.year().to_string())
+    }
+
+    pub fn format_required(&self, label: &str) -> String {
+        self.required_with_label.replace("{label}", label)
+    }
+
+    /// The days of a week, starting from `first_day_of_week`.
+    pub fn week(&self) -> impl Iterator<Item = Weekday> {
+        std::iter::successors(Some(self.first_day_of_week), |day| Some(day.succ())).take(7)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+/// Whether `value`, e.g. `date.format(format)`, can be shown.
+/// `chrono` returns an error for an invalid format, on which `to_string` panics.
+pub(crate) fn can_format(value: impl fmt::Display) -> bool {
+    write!(String::new(), "{value}").is_ok()
+}
+
+fn strings<const N: usize>(names: [&str; N]) -> [String; N] {
+    names.map(String::from)
+}
+
+#[component]
+pub fn LocaleProvider(
+    #[prop(optional, into)] locale: Option<RwSignal<Locale>>,
+    children: Children,
+) -> impl IntoView {
+    let locale = if let Some(locale) = locale {
+        locale
+    } else {
+        create_rw_signal(Locale::en_us())
+    };
+
+    view! { <Provider value=locale children/> }
+}
+
+pub fn use_locale() -> ReadSignal<Locale> {
+    use_context::<RwSignal<Locale>>()
+        .unwrap_or_else(|| create_rw_signal(Locale::en_us()))
+        .split()
+        .0
+}
+
+#[cfg(test)]
+mod test {
+    use super::{can_format, Locale};
+    use chrono::{NaiveDate, NaiveTime, Weekday};
+
+    #[test]
+    fn locale_format() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(Locale::en_us().format_month_year(&date), "March 2024");
+        assert_eq!(Locale::zh_cn().format_month_year(&date), "2024年3月");
+        assert_eq!(
+            Locale::de_de().format_required("Name"),
+            "Name ist erforderlich"
+        );
+        let week = Locale::de_de().week().collect::<Vec<_>>();
+        assert_eq!(week.first(), Some(&Weekday::Mon));
+        assert_eq!(week.last(), Some(&Weekday::Sun));
+    }
+
+    #[test]
+    fn locale_can_format() {
+        let date = NaiveDate::default();
+        assert!(can_format(date.format(&Locale::en_us().date_format)));
+        assert!(!can_format(date.format("%Y-%Q")));
+        assert!(!can_format(date.format("%H:%M")));
+        assert!(can_format(NaiveTime::default().format("%H:%M")));
+    }
+}
@@ -1,5 +1,5 @@
 use super::MenuInjection;
-use crate::{theme::use_theme, Icon, Theme};
+use crate::{theme::mount_theme_vars, Icon};
 use leptos::*;
 use thaw_components::{CSSTransition, OptionComp};
 use thaw_utils::{class_list, mount_style, OptionalMaybeSignal, OptionalProp, StoredMaybeSignal};
@@ -12,8 +12,8 @@ pub fn MenuItem(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("menu-item", include_str!("./menu-item.css"));
-    let theme = use_theme(Theme::light);
 
     let submenu_ref = NodeRef::<html::Div>::new();
     let is_children = children.is_some();
@@ -31,7 +31,7 @@ pub fn MenuItem(
     let is_submenu_selected =
         Memo::new(move |_| menu.path.with(|path| key.with(|key| path.contains(key))));
 
-    let on_click = move |_| {
+    let on_click = move || {
         if is_children {
             is_open_children.set(!is_open_children.get_untracked());
         } else {
@@ -54,21 +54,6 @@ pub fn MenuItem(
         }
     };
 
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let font_color = theme.common.color_primary.clone();
-            css_vars.push_str(&format!("--thaw-font-color-active: {font_color};"));
-            css_vars.push_str(&format!("--thaw-font-color: {};", theme.menu.color));
-            css_vars.push_str(&format!("--thaw-background-color: {font_color}1a;"));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.menu.item_color_hover
-            ));
-        });
-        css_vars
-    });
-
     view! {
         <div class="thaw-menu-item">
             <div
@@ -79,14 +64,26 @@ pub fn MenuItem(
                     class.map(| c | move || c.get())
                 ]
 
-                on:click=on_click
-                style=move || css_vars.get()
+                on:click=move |_| on_click()
+                on:keydown=move |ev| {
+                    if matches!(ev.key().as_str(), " " | "Enter") {
+                        ev.prevent_default();
+                        on_click();
+                    }
+                }
+
+                role="menuitem"
+                tabindex=move || if is_selected.get() { "0" } else { "-1" }
+                aria-haspopup=is_children.then_some("true")
+                aria-expanded=move || {
+                    is_children.then(|| if is_open_children.get() { "true" } else { "false" })
+                }
             >
                 {
                     move || {
                         view! {
                             <OptionComp value=icon.get() let:icon>
-                                <Icon icon=icon style="font-size: 18px;margin-right: 8px"/>
+                                <Icon icon=icon style="font-size: 18px;margin-inline-end: 8px"/>
                             </OptionComp>
                         }
                     }
@@ -125,7 +122,6 @@ pub fn MenuItem(
                             style=move || display.get()
                             ref=submenu_ref
                             role="menu"
-                            aria-expanded=move || if is_open_children.get() { "true" } else { "false" }
                         >
                             {children()}
                         </div>
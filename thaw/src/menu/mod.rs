@@ -8,7 +8,7 @@ pub use theme::MenuTheme;
 
 use leptos::*;
 use std::collections::BTreeSet;
-use thaw_utils::{class_list, Model, OptionalProp};
+use thaw_utils::{class_list, use_roving_focus, Model, OptionalProp, RovingFocusOrientation};
 
 #[component]
 pub fn Menu(
@@ -18,10 +18,25 @@ pub fn Menu(
     children: Children,
 ) -> impl IntoView {
     let path = RwSignal::new(BTreeSet::<String>::new());
+    let menu_ref = NodeRef::<html::Div>::new();
+    let roving_focus = use_roving_focus(
+        menu_ref,
+        ".thaw-menu-item__content",
+        RovingFocusOrientation::Vertical,
+    );
 
     view! {
         <Provider value=MenuInjection { value, path, default_expanded_keys: StoredValue::new(default_expanded_keys) }>
-            <div class=class_list!["thaw-menu", class.map(| c | move || c.get())]>{children()}</div>
+            <div
+                class=class_list!["thaw-menu", class.map(| c | move || c.get())]
+                role="menu"
+                // Without a selected item the menu itself is focusable, the arrow keys move into it.
+                tabindex=move || value.with(String::is_empty).then_some("0")
+                ref=menu_ref
+                on:keydown=move |ev| roving_focus.on_keydown(ev)
+            >
+                {children()}
+            </div>
         </Provider>
     }
 }
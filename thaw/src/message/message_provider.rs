@@ -1,4 +1,5 @@
 use super::{Message, MessageVariant};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use std::time::Duration;
 use thaw_components::Teleport;
@@ -34,6 +35,7 @@ pub fn MessageProvider(
     #[prop(optional)] placement: MessagePlacement,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("message", include_str!("./message.css"));
 
     let message_list = create_rw_signal::<Vec<MessageType>>(vec![]);
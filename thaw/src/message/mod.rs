@@ -4,7 +4,7 @@ mod theme;
 pub use message_provider::*;
 pub use theme::MessageTheme;
 
-use crate::{theme::use_theme, Icon, Theme};
+use crate::Icon;
 use leptos::*;
 use thaw_components::{CSSTransition, If, Then};
 use uuid::Uuid;
@@ -25,11 +25,11 @@ impl MessageVariant {
             MessageVariant::Error => icondata_ai::AiCloseCircleFilled,
         }
     }
-    fn theme_color(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            MessageVariant::Success => theme.common.color_success.clone(),
-            MessageVariant::Warning => theme.common.color_warning.clone(),
-            MessageVariant::Error => theme.common.color_error.clone(),
+            MessageVariant::Success => "success",
+            MessageVariant::Warning => "warning",
+            MessageVariant::Error => "error",
         }
     }
 }
@@ -49,19 +49,6 @@ fn Message(message: MessageType, #[prop(into)] on_close: Callback<Uuid, ()>) ->
         );
     }
 
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {}",
-                theme.message.background_color
-            ))
-        });
-        css_vars
-    });
-    let style = theme.with_untracked(|theme| format!("color: {};", variant.theme_color(theme)));
-
     let on_before_leave = Callback::new(move |_| {
         let Some(node_el) = message_ref.get() else {
             return;
@@ -87,9 +74,9 @@ fn Message(message: MessageType, #[prop(into)] on_close: Callback<Uuid, ()>) ->
             let:_
         >
             <div class="thaw-message-wrapper" ref=message_ref>
-                <div class="thaw-message" style=move || css_vars.get()>
+                <div class=format!("thaw-message thaw-message--{}", variant.as_str())>
                     <div class="thaw-message__icon">
-                        <Icon icon=variant.icon() style/>
+                        <Icon icon=variant.icon()/>
                     </div>
                     <div class="thaw-message__content">{content}</div>
                     <If cond=closable>
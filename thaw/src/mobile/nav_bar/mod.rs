@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::NavBarTheme;
 
-use crate::{icon::*, use_theme, Theme};
+use crate::{icon::*, theme::mount_theme_vars};
 use leptos::*;
 use thaw_components::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
@@ -33,17 +33,8 @@ pub fn NavBar(
     #[prop(optional, into)] on_click_right: Option<Callback<ev::MouseEvent>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("nav-bar", include_str!("./nav-bar.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        theme.with(|theme| {
-            format!(
-                "--thaw-background-color: {};",
-                theme.nav_bar.background_color
-            )
-        })
-    });
-
     let on_click_left = move |ev| {
         if let Some(click_left) = on_click_left.as_ref() {
             click_left.call(ev);
@@ -59,7 +50,6 @@ pub fn NavBar(
     view! {
         <div
             class=class_list!["thaw-nav-bar", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
         >
 
             {if let Some(NavBarLeft { class, children }) = nav_bar_left {
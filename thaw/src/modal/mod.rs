@@ -1,43 +1,131 @@
+mod modal_manager;
+
+pub(crate) use modal_manager::ModalManagerInjection;
+
 use crate::{Card, CardFooter, CardHeader, CardHeaderExtra, Icon, Scrollbar, ScrollbarRef};
-use leptos::*;
+use leptos::{leptos_dom::helpers::WindowListenerHandle, *};
+use std::{future::Future, pin::Pin, rc::Rc};
 use thaw_components::{CSSTransition, FocusTrap, If, OptionComp, Teleport, Then};
-use thaw_utils::{class_list, mount_style, use_click_position, ComponentRef, Model, OptionalProp};
+use thaw_utils::{
+    class_list, mount_style, use_click_position, use_direction, use_lock_html_scroll, ComponentRef,
+    Model, OptionalProp,
+};
+use uuid::Uuid;
+
+/// The part of the title bar kept inside the viewport while the modal is dragged.
+const DRAG_VISIBLE_HEIGHT: f64 = 48.0;
 
 #[slot]
 pub struct ModalFooter {
     children: ChildrenFn,
 }
 
+/// Decides whether the modal may close, e.g. after asking to discard unsaved changes.
+///
+/// A closure returning `bool` converts into it, use [`ModalBeforeClose::new`] to wait for a future.
+#[derive(Clone)]
+pub struct ModalBeforeClose(Rc<dyn Fn() -> Pin<Box<dyn Future<Output = bool>>>>);
+
+impl ModalBeforeClose {
+    pub fn new<Fu>(f: impl Fn() -> Fu + 'static) -> Self
+    where
+        Fu: Future<Output = bool> + 'static,
+    {
+        Self(Rc::new(move || Box::pin(f())))
+    }
+
+    fn call(&self) -> Pin<Box<dyn Future<Output = bool>>> {
+        (self.0)()
+    }
+}
+
+impl<F> From<F> for ModalBeforeClose
+where
+    F: Fn() -> bool + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(move || std::future::ready(f()))
+    }
+}
+
 #[component]
 pub fn Modal(
     #[prop(into)] show: Model<bool>,
     #[prop(default = true.into(), into)] mask_closeable: MaybeSignal<bool>,
     #[prop(default = true, into)] close_on_esc: bool,
     #[prop(default = true, into)] closable: bool,
-    #[prop(default = 2000.into(), into)] z_index: MaybeSignal<i16>,
+    /// The z-index of the lowest modal, the modals opened later are stacked above it.
+    #[prop(default = 2000.into(), into)]
+    z_index: MaybeSignal<i16>,
     #[prop(default = MaybeSignal::Static("600px".to_string()), into)] width: MaybeSignal<String>,
     #[prop(optional, into)] title: MaybeSignal<String>,
     children: Children,
     #[prop(optional)] modal_footer: Option<ModalFooter>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// Called once the leave transition finished.
+    #[prop(optional, into)]
+    on_after_leave: Option<Callback<()>>,
+    /// Called before the close button, the mask or Esc closes the modal,
+    /// the modal stays open when it resolves to `false`.
+    #[prop(optional, into)]
+    before_close: Option<ModalBeforeClose>,
+    /// Whether the modal can be moved by dragging its title bar.
+    #[prop(optional, into)]
+    draggable: MaybeSignal<bool>,
+    /// Whether the modal fills the viewport.
+    #[prop(optional, into)]
+    fullscreen: MaybeSignal<bool>,
 ) -> impl IntoView {
     mount_style("modal", include_str!("./modal.css"));
 
+    let id = Uuid::new_v4();
+    let modal_manager = ModalManagerInjection::use_();
+    let level = create_memo(move |_| modal_manager.level(id));
+
     let displayed = RwSignal::new(show.get_untracked());
+    let drag_offset = RwSignal::new((0.0, 0.0));
+    if displayed.get_untracked() {
+        modal_manager.open(id);
+    }
     Effect::new(move |prev| {
         let is_show = show.get();
         if prev.is_some() && is_show {
             displayed.set(true);
+            drag_offset.set((0.0, 0.0));
+            modal_manager.open(id);
         }
     });
+    use_lock_html_scroll(displayed.into());
+    on_cleanup(move || modal_manager.close(id));
+
+    let before_close = StoredValue::new(before_close);
+    let is_closing = StoredValue::new(false);
+    let request_close = move || {
+        if is_closing.get_value() {
+            return;
+        }
+        let Some(before_close) = before_close.get_value() else {
+            show.set(false);
+            return;
+        };
+        is_closing.set_value(true);
+        let can_close = before_close.call();
+        spawn_local(async move {
+            let can_close = can_close.await;
+            is_closing.set_value(false);
+            if can_close {
+                show.set(false);
+            }
+        });
+    };
 
     let on_mask_click = move |_| {
         if mask_closeable.get_untracked() {
-            show.set(false);
+            request_close();
         }
     };
     let on_esc = Callback::new(move |_: ev::KeyboardEvent| {
-        show.set(false);
+        request_close();
     });
 
     let mask_ref = NodeRef::<html::Div>::new();
@@ -45,6 +133,7 @@ pub fn Modal(
     let modal_ref = NodeRef::<html::Div>::new();
 
     let click_position = use_click_position();
+    let dir = use_direction();
     let on_enter = Callback::new(move |_| {
         let Some(position) = click_position.get_untracked() else {
             return;
@@ -65,13 +154,73 @@ pub fn Modal(
         let _ = modal_el.attr("style", format!("transform-origin: {}px {}px", x, y));
     });
 
+    let drag_handles = StoredValue::new(Vec::<WindowListenerHandle>::new());
+    let stop_drag = move || {
+        drag_handles.update_value(|handles| {
+            for handle in handles.drain(..) {
+                handle.remove();
+            }
+        });
+    };
+    on_cleanup(stop_drag);
+    let on_title_mousedown = move |ev: ev::MouseEvent| {
+        if !draggable.get_untracked() || fullscreen.get_untracked() || ev.button() != 0 {
+            return;
+        }
+        let Some(modal_el) = modal_ref.get_untracked() else {
+            return;
+        };
+        ev.prevent_default();
+        stop_drag();
+        let rect = modal_el.get_bounding_client_rect();
+        let window = window();
+        let viewport_width = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default();
+        let viewport_height = window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default();
+        let start = (f64::from(ev.client_x()), f64::from(ev.client_y()));
+        let start_offset = drag_offset.get_untracked();
+
+        let on_mousemove = window_event_listener(ev::mousemove, move |ev| {
+            let dx = clamp_drag_delta(
+                f64::from(ev.client_x()) - start.0,
+                rect.left(),
+                rect.width(),
+                viewport_width,
+            );
+            let dy = clamp_drag_delta(
+                f64::from(ev.client_y()) - start.1,
+                rect.top(),
+                DRAG_VISIBLE_HEIGHT,
+                viewport_height,
+            );
+            drag_offset.set((start_offset.0 + dx, start_offset.1 + dy));
+        });
+        let on_mouseup = window_event_listener(ev::mouseup, move |_| stop_drag());
+        drag_handles.set_value(vec![on_mousemove, on_mouseup]);
+    };
+
     view! {
         <Teleport immediate=show.signal()>
             <FocusTrap disabled=!close_on_esc active=show.signal() on_esc>
                 <div
                     class="thaw-modal-container"
-                    style:z-index=move || z_index.get()
+                    dir=dir.map(|dir| move || dir.get().as_str())
+                    style:z-index=move || z_index.get().saturating_add(level.get() as i16)
                     style=("--thaw-width", move || width.get())
+                    style=(
+                        "--thaw-modal-offset",
+                        move || {
+                            let (x, y) = drag_offset.get();
+                            format!("{x}px {y}px")
+                        },
+                    )
                 >
                     <Scrollbar
                         content_style="min-height: 100%; display: flex;"
@@ -105,12 +254,21 @@ pub fn Modal(
                             show=show.signal()
                             name="fade-in-scale-up-transition"
                             on_enter
-                            on_after_leave=move |_| displayed.set(false)
+                            on_after_leave=move |_| {
+                                displayed.set(false);
+                                modal_manager.close(id);
+                                if let Some(on_after_leave) = on_after_leave {
+                                    on_after_leave.call(());
+                                }
+                            }
                             let:display
                         >
                             <div
                                 class=class_list![
-                                    "thaw-modal-body", class.map(| c | move || c.get())
+                                    "thaw-modal-body",
+                                    ("thaw-modal-body--draggable", move || draggable.get()),
+                                    ("thaw-modal-body--fullscreen", move || fullscreen.get()),
+                                    class.map(| c | move || c.get())
                                 ]
                                 ref=modal_ref
                                 role="dialog"
@@ -119,14 +277,19 @@ pub fn Modal(
                             >
                                 <Card>
                                     <CardHeader slot>
-                                        <span class="thaw-model-title">{move || title.get()}</span>
+                                        <span
+                                            class="thaw-model-title"
+                                            on:mousedown=on_title_mousedown
+                                        >
+                                            {move || title.get()}
+                                        </span>
                                     </CardHeader>
                                     <CardHeaderExtra slot>
                                         <If cond=closable>
                                             <Then slot>
                                                 <span
                                                     style="cursor: pointer;"
-                                                    on:click=move |_| show.set(false)
+                                                    on:click=move |_| request_close()
                                                 >
                                                     <Icon icon=icondata_ai::AiCloseOutlined/>
                                                 </span>
@@ -148,3 +311,24 @@ pub fn Modal(
         </Teleport>
     }
 }
+
+/// Limits the drag distance `delta` of an edge at `start`, so that `size`
+/// past that edge stays inside the viewport. The start edge wins when both
+/// do not fit.
+fn clamp_drag_delta(delta: f64, start: f64, size: f64, viewport: f64) -> f64 {
+    delta.min(viewport - size - start).max(-start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_drag_delta;
+
+    #[test]
+    fn drag_delta_is_clamped_to_viewport() {
+        assert_eq!(clamp_drag_delta(50.0, 100.0, 200.0, 1000.0), 50.0);
+        assert_eq!(clamp_drag_delta(-150.0, 100.0, 200.0, 1000.0), -100.0);
+        assert_eq!(clamp_drag_delta(900.0, 100.0, 200.0, 1000.0), 700.0);
+        // Wider than the viewport, the start edge stays visible.
+        assert_eq!(clamp_drag_delta(100.0, 0.0, 1200.0, 1000.0), 0.0);
+    }
+}
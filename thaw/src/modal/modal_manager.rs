@@ -0,0 +1,54 @@
+use leptos::*;
+use uuid::Uuid;
+
+/// Stacks the open modals, the last opened one is shown above the others.
+///
+/// Provided by the outermost `ConfigProvider`, `ThemeProvider` or `DialogProvider`,
+/// a modal outside of them only uses its own `z_index`.
+#[derive(Clone, Copy)]
+pub(crate) struct ModalManagerInjection {
+    stack: RwSignal<Vec<Uuid>>,
+}
+
+impl ModalManagerInjection {
+    pub fn new() -> Self {
+        Self {
+            stack: create_rw_signal(vec![]),
+        }
+    }
+
+    /// Provides a manager unless an outer provider already did, so that nested
+    /// providers share the stack.
+    pub fn provide() {
+        if use_context::<Self>().is_none() {
+            provide_context(Self::new());
+        }
+    }
+
+    pub fn use_() -> Self {
+        use_context().unwrap_or_else(Self::new)
+    }
+
+    /// Moves the modal to the top of the stack.
+    pub fn open(&self, id: Uuid) {
+        self.stack.update(|stack| {
+            stack.retain(|modal_id| modal_id != &id);
+            stack.push(id);
+        });
+    }
+
+    pub fn close(&self, id: Uuid) {
+        self.stack
+            .update(|stack| stack.retain(|modal_id| modal_id != &id));
+    }
+
+    /// The position of the modal in the stack, added to its `z_index`.
+    pub fn level(&self, id: Uuid) -> usize {
+        self.stack.with(|stack| {
+            stack
+                .iter()
+                .position(|modal_id| modal_id == &id)
+                .unwrap_or_default()
+        })
+    }
+}
@@ -0,0 +1,192 @@
+mod notification_provider;
+mod theme;
+
+pub use notification_provider::*;
+pub use theme::NotificationTheme;
+
+use crate::{Button, ButtonSize, ButtonVariant, Icon};
+use chrono::{DateTime, Utc};
+use leptos::{leptos_dom::helpers::TimeoutHandle, *};
+use std::time::Duration;
+use thaw_components::CSSTransition;
+use uuid::Uuid;
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum NotificationVariant {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotificationVariant {
+    fn icon(&self) -> icondata_core::Icon {
+        match self {
+            Self::Info => icondata_ai::AiInfoCircleFilled,
+            Self::Success => icondata_ai::AiCheckCircleFilled,
+            Self::Warning => icondata_ai::AiExclamationCircleFilled,
+            Self::Error => icondata_ai::AiCloseCircleFilled,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[component]
+fn Notification(
+    notification: NotificationItem,
+    handle: NotificationHandle,
+    #[prop(into)] on_close: Callback<Uuid, ()>,
+) -> impl IntoView {
+    let NotificationItem {
+        id,
+        options,
+        is_show,
+    } = notification;
+    let notification_ref = NodeRef::<html::Div>::new();
+
+    let timer = store_value(None::<(TimeoutHandle, DateTime<Utc>, Duration)>);
+    let paused = store_value(None::<Duration>);
+    let stop_timer = move || {
+        if let Some((handle, ..)) = timer.get_value() {
+            handle.clear();
+            timer.set_value(None);
+        }
+    };
+    let start_timer = move |duration: Duration| {
+        stop_timer();
+        paused.set_value(None);
+        if duration.is_zero() {
+            return;
+        }
+        if let Ok(handle) = set_timeout_with_handle(move || is_show.set(false), duration) {
+            timer.set_value(Some((handle, Utc::now(), duration)));
+        }
+    };
+    let duration = create_memo(move |_| options.with(|options| options.duration));
+    create_effect(move |_| start_timer(duration.get()));
+    on_cleanup(stop_timer);
+
+    let on_mouse_enter = move |_| {
+        let Some((_, started_at, duration)) = timer.get_value() else {
+            return;
+        };
+        stop_timer();
+        let elapsed = (Utc::now() - started_at).to_std().unwrap_or_default();
+        paused.set_value(Some(duration.saturating_sub(elapsed)));
+    };
+    let on_mouse_leave = move |_| {
+        if let Some(remaining) = paused.get_value() {
+            start_timer(remaining);
+        }
+    };
+
+    let on_before_leave = Callback::new(move |_| {
+        let Some(node_el) = notification_ref.get() else {
+            return;
+        };
+        use std::ops::Deref;
+        let any_el = node_el.into_any();
+        let el = any_el.deref();
+        let style = el.style();
+        let _ = style.set_property("max-height", &format!("{}px", el.offset_height()));
+    });
+    let on_after_leave = Callback::new(move |_| {
+        queue_microtask(move || on_close.call(id));
+    });
+
+    let variant = create_memo(move |_| options.with(|options| options.variant));
+    let closable = create_memo(move |_| options.with(|options| options.closable));
+
+    view! {
+        <CSSTransition
+            node_ref=notification_ref
+            name="fade-in-height-expand-transition"
+            show=is_show
+            appear=true
+            on_before_leave=on_before_leave
+            on_after_leave=on_after_leave
+            let:_
+        >
+            <div class="thaw-notification-wrapper" ref=notification_ref>
+                <div
+                    class=move || {
+                        format!("thaw-notification thaw-notification--{}", variant.get().as_str())
+                    }
+
+                    on:mouseenter=on_mouse_enter
+                    on:mouseleave=on_mouse_leave
+                >
+                    <div class="thaw-notification__icon">
+                        <Icon icon=Signal::derive(move || variant.get().icon())/>
+                    </div>
+                    <div class="thaw-notification__main">
+                        <div class="thaw-notification__title">
+                            {move || options.with(|options| options.title.clone())}
+                        </div>
+                        {move || {
+                            options
+                                .with(|options| options.description.clone())
+                                .map(|description| {
+                                    view! {
+                                        <div class="thaw-notification__description">
+                                            {description.run()}
+                                        </div>
+                                    }
+                                })
+                        }}
+                        {move || {
+                            let actions = options.with(|options| options.actions.clone());
+                            (!actions.is_empty())
+                                .then(|| {
+                                    view! {
+                                        <div class="thaw-notification__actions">
+                                            {actions
+                                                .into_iter()
+                                                .map(|action| {
+                                                    let on_click = action.on_click;
+                                                    view! {
+                                                        <Button
+                                                            size=ButtonSize::Small
+                                                            variant=ButtonVariant::Text
+                                                            on_click=move |_| on_click.call(handle)
+                                                        >
+                                                            {action.label}
+                                                        </Button>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                    }
+                                })
+                        }}
+
+                    </div>
+                    {move || {
+                        closable
+                            .get()
+                            .then(|| {
+                                view! {
+                                    <div
+                                        class="thaw-notification__close"
+                                        on:click=move |_| is_show.set(false)
+                                    >
+                                        <Icon icon=icondata_ai::AiCloseOutlined/>
+                                    </div>
+                                }
+                            })
+                    }}
+
+                </div>
+            </div>
+        </CSSTransition>
+    }
+}
@@ -0,0 +1,230 @@
+use super::{Notification, NotificationVariant};
+use crate::theme::mount_theme_vars;
+use leptos::*;
+use std::time::Duration;
+use thaw_components::Teleport;
+use thaw_utils::mount_style;
+use uuid::Uuid;
+
+#[derive(Default, Clone, Copy)]
+pub enum NotificationPlacement {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl NotificationPlacement {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::TopLeft => "top-left",
+            Self::TopRight => "top-right",
+            Self::BottomLeft => "bottom-left",
+            Self::BottomRight => "bottom-right",
+        }
+    }
+}
+
+#[component]
+pub fn NotificationProvider(
+    #[prop(optional)] placement: NotificationPlacement,
+    /// The maximum number of visible notifications, the ones created beyond it
+    /// wait until a visible one closes.
+    #[prop(optional)]
+    max: Option<usize>,
+    children: Children,
+) -> impl IntoView {
+    mount_theme_vars();
+    mount_style("notification", include_str!("./notification.css"));
+
+    let injection = NotificationInjection {
+        notification_list: create_rw_signal(vec![]),
+        max,
+        owner: Owner::current(),
+    };
+
+    let on_close = move |id| injection.remove(|notification| notification.id == id);
+
+    view! {
+        <Provider value=injection>
+            {children()} <Teleport>
+                <div class=format!(
+                    "thaw-notification-container thaw-notification-container--{}",
+                    placement.as_str(),
+                )>
+                    <For
+                        each=move || injection.visible_notifications()
+                        key=|notification| notification.id
+                        children=move |notification| {
+                            let handle = NotificationHandle {
+                                id: notification.id,
+                                injection,
+                            };
+                            view! { <Notification notification handle on_close/> }
+                        }
+                    />
+
+                </div>
+            </Teleport>
+        </Provider>
+    }
+}
+
+#[derive(Clone)]
+pub struct NotificationOptions {
+    pub title: String,
+    pub description: Option<ViewFn>,
+    pub variant: NotificationVariant,
+    pub actions: Vec<NotificationAction>,
+    /// Zero keeps the notification open until it is closed.
+    pub duration: Duration,
+    pub closable: bool,
+}
+
+impl Default for NotificationOptions {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            description: None,
+            variant: NotificationVariant::default(),
+            actions: vec![],
+            duration: Duration::from_secs(4),
+            closable: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NotificationAction {
+    pub label: String,
+    pub on_click: Callback<NotificationHandle>,
+}
+
+impl NotificationAction {
+    pub fn new(label: impl Into<String>, on_click: impl Fn(NotificationHandle) + 'static) -> Self {
+        Self {
+            label: label.into(),
+            on_click: Callback::new(on_click),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct NotificationItem {
+    pub id: Uuid,
+    pub options: RwSignal<NotificationOptions>,
+    pub is_show: RwSignal<bool>,
+}
+
+impl NotificationItem {
+    fn dispose(self) {
+        self.options.dispose();
+        self.is_show.dispose();
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct NotificationInjection {
+    notification_list: RwSignal<Vec<NotificationItem>>,
+    max: Option<usize>,
+    owner: Option<Owner>,
+}
+
+impl NotificationInjection {
+    pub fn create(&self, options: NotificationOptions) -> NotificationHandle {
+        let id = Uuid::new_v4();
+        // The signals belong to the provider rather than to the caller, which
+        // is usually an event handler.
+        let create_item = move || NotificationItem {
+            id,
+            options: create_rw_signal(options),
+            is_show: create_rw_signal(true),
+        };
+        let item = match self.owner {
+            Some(owner) => with_owner(owner, create_item),
+            None => create_item(),
+        };
+        self.notification_list
+            .update(move |notification_list| notification_list.push(item));
+
+        NotificationHandle {
+            id,
+            injection: *self,
+        }
+    }
+
+    /// Removes the first matching item and disposes its signals.
+    fn remove(&self, f: impl Fn(&NotificationItem) -> bool) {
+        let mut removed = None;
+        self.notification_list.update(|notification_list| {
+            if let Some(index) = notification_list.iter().position(f) {
+                removed = Some(notification_list.remove(index));
+            }
+        });
+        if let Some(item) = removed {
+            item.dispose();
+        }
+    }
+
+    fn visible_notifications(&self) -> Vec<NotificationItem> {
+        self.notification_list.with(|notification_list| {
+            let len = self.max.map_or(notification_list.len(), |max| {
+                max.min(notification_list.len())
+            });
+            notification_list[..len].to_vec()
+        })
+    }
+}
+
+/// Controls a notification after it was created.
+#[derive(Clone, Copy)]
+pub struct NotificationHandle {
+    id: Uuid,
+    injection: NotificationInjection,
+}
+
+impl NotificationHandle {
+    pub fn close(&self) {
+        let NotificationInjection {
+            notification_list,
+            max,
+            ..
+        } = self.injection;
+        let Some((index, is_show)) = notification_list.with_untracked(|notification_list| {
+            notification_list
+                .iter()
+                .position(|notification| notification.id == self.id)
+                .map(|index| (index, notification_list[index].is_show))
+        }) else {
+            return;
+        };
+        if max.is_some_and(|max| index >= max) {
+            // Still waiting to be shown, nothing to animate.
+            self.injection
+                .remove(|notification| notification.id == self.id);
+        } else {
+            is_show.set(false);
+        }
+    }
+
+    /// Updates the notification in place, a changed `duration` restarts its timer.
+    pub fn update(&self, f: impl FnOnce(&mut NotificationOptions)) {
+        let options = self
+            .injection
+            .notification_list
+            .with_untracked(|notification_list| {
+                notification_list
+                    .iter()
+                    .find(|notification| notification.id == self.id)
+                    .map(|notification| notification.options)
+            });
+        if let Some(options) = options {
+            options.update(f);
+        }
+    }
+}
+
+pub fn use_notification() -> NotificationInjection {
+    expect_context::<NotificationInjection>()
+}
@@ -0,0 +1,21 @@
+use crate::theme::ThemeMethod;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotificationTheme {
+    pub background_color: String,
+}
+
+impl ThemeMethod for NotificationTheme {
+    fn light() -> Self {
+        Self {
+            background_color: "#fff".into(),
+        }
+    }
+
+    fn dark() -> Self {
+        Self {
+            background_color: "#48484e".into(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{Button, ButtonSize, ButtonVariant};
 use leptos::*;
 use std::cmp::min;
-use thaw_utils::{class_list, mount_style, Model, OptionalProp, StoredMaybeSignal};
+use thaw_utils::{class_list, mount_style, use_direction, Model, OptionalProp, StoredMaybeSignal};
 
 fn range(start: usize, end: usize) -> Vec<PaginationItem> {
     let mut ret = vec![];
@@ -89,6 +89,22 @@ pub fn Pagination(
     let size: StoredMaybeSignal<_> = size.into();
     let no_next = Memo::new(move |_| page.get() == count.get());
     let no_previous = Memo::new(move |_| page.get() == 1);
+    let dir = use_direction();
+    let is_rtl = move || dir.is_some_and(|dir| dir.get().is_rtl());
+    let previous_icon = Signal::derive(move || {
+        Some(if is_rtl() {
+            icondata_ai::AiRightOutlined
+        } else {
+            icondata_ai::AiLeftOutlined
+        })
+    });
+    let next_icon = Signal::derive(move || {
+        Some(if is_rtl() {
+            icondata_ai::AiLeftOutlined
+        } else {
+            icondata_ai::AiRightOutlined
+        })
+    });
 
     let on_click_previous = Callback::<ev::MouseEvent>::new(move |_| {
         page.update(|val| *val -= 1);
@@ -112,7 +128,7 @@ pub fn Pagination(
                         size=size
                         on_click=on_click_previous
                         variant=ButtonVariant::Text
-                        icon=icondata_ai::AiLeftOutlined
+                        icon=previous_icon
                         disabled=no_previous
                         circle=true
                     />
@@ -169,7 +185,8 @@ pub fn Pagination(
                         size
                         on_click=on_click_next
                         variant=ButtonVariant::Text
-                        icon=icondata_ai::AiRightOutlined disabled=no_next
+                        icon=next_icon
+                        disabled=no_next
                         circle=true
                     />
                 </li>
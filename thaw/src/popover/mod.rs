@@ -2,12 +2,12 @@ mod theme;
 
 pub use theme::PopoverTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::{leptos_dom::helpers::TimeoutHandle, *};
 use std::time::Duration;
 use thaw_components::{Binder, CSSTransition, Follower, FollowerPlacement};
 use thaw_utils::{
-    add_event_listener, call_on_click_outside, class_list, mount_style, OptionalProp,
+    add_event_listener, call_on_click_outside, class_list, mount_style, Model, OptionalProp,
 };
 
 #[slot]
@@ -20,71 +20,88 @@ pub struct PopoverTrigger {
 #[component]
 pub fn Popover(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// Whether the popover is shown, it can also be shown and hidden by `trigger_type`.
+    #[prop(optional, into)]
+    show: Model<bool>,
     #[prop(optional)] trigger_type: PopoverTriggerType,
     popover_trigger: PopoverTrigger,
     #[prop(optional)] placement: PopoverPlacement,
     #[prop(optional)] tooltip: bool,
+    /// Milliseconds to wait before showing the popover on hover or focus.
+    #[prop(optional)]
+    show_delay: u64,
+    /// Milliseconds to wait before hiding the popover on hover or focus.
+    #[prop(default = 100)]
+    hide_delay: u64,
+    /// Whether to show the arrow pointing at the trigger.
+    #[prop(default = true)]
+    show_arrow: bool,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("popover", include_str!("./popover.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let background_color = if tooltip {
-                &theme.popover.tooltip_background_color
-            } else {
-                &theme.popover.background_color
-            };
-            css_vars.push_str(&format!("--thaw-background-color: {};", background_color));
-            let font_color = if tooltip {
-                "#fff"
-            } else {
-                &theme.common.font_color
-            };
-            css_vars.push_str(&format!("--thaw-font-color: {};", font_color));
-        });
-        css_vars
-    });
     let popover_ref = create_node_ref::<html::Div>();
     let target_ref = create_node_ref::<html::Div>();
-    let is_show_popover = create_rw_signal(false);
-    let show_popover_handle = store_value(None::<TimeoutHandle>);
+    let delay_handle = store_value(None::<TimeoutHandle>);
 
-    let on_mouse_enter = move |_| {
-        if trigger_type != PopoverTriggerType::Hover {
-            return;
-        }
-        show_popover_handle.update_value(|handle| {
+    let set_show_delayed = move |is_show: bool| {
+        delay_handle.update_value(|handle| {
             if let Some(handle) = handle.take() {
                 handle.clear();
             }
         });
-        is_show_popover.set(true);
-    };
-    let on_mouse_leave = move |_| {
-        if trigger_type != PopoverTriggerType::Hover {
+        if show.get_untracked() == is_show {
+            return;
+        }
+        let delay = if is_show { show_delay } else { hide_delay };
+        if delay == 0 {
+            show.set(is_show);
             return;
         }
-        show_popover_handle.update_value(|handle| {
+        let handle =
+            set_timeout_with_handle(move || show.set(is_show), Duration::from_millis(delay));
+        delay_handle.set_value(handle.ok());
+    };
+    on_cleanup(move || {
+        delay_handle.update_value(|handle| {
             if let Some(handle) = handle.take() {
                 handle.clear();
             }
-            *handle = set_timeout_with_handle(
-                move || {
-                    is_show_popover.set(false);
-                },
-                Duration::from_millis(100),
-            )
-            .ok();
         });
+    });
+
+    let on_mouse_enter = move |_| {
+        if trigger_type == PopoverTriggerType::Hover {
+            set_show_delayed(true);
+        }
+    };
+    let on_mouse_leave = move |_| {
+        if trigger_type == PopoverTriggerType::Hover {
+            set_show_delayed(false);
+        }
+    };
+    let on_focus_in = move |_| {
+        if trigger_type == PopoverTriggerType::Focus {
+            set_show_delayed(true);
+        }
+    };
+    let on_focus_out = move |_| {
+        if trigger_type == PopoverTriggerType::Focus {
+            set_show_delayed(false);
+        }
+    };
+    let on_context_menu = move |event: ev::MouseEvent| {
+        if trigger_type == PopoverTriggerType::ContextMenu {
+            event.prevent_default();
+            show.set(true);
+        }
     };
 
-    if trigger_type != PopoverTriggerType::Hover {
-        call_on_click_outside(
-            popover_ref,
-            Callback::new(move |_| is_show_popover.set(false)),
-        );
+    if matches!(
+        trigger_type,
+        PopoverTriggerType::Click | PopoverTriggerType::ContextMenu
+    ) {
+        call_on_click_outside(popover_ref, Callback::new(move |_| show.set(false)));
     }
     target_ref.on_load(move |target_el| {
         add_event_listener(target_el.into_any(), ev::click, move |event| {
@@ -92,7 +109,7 @@ pub fn Popover(
                 return;
             }
             event.stop_propagation();
-            is_show_popover.update(|show| *show = !*show);
+            show.update(|show| *show = !*show);
         });
     });
     let PopoverTrigger {
@@ -107,31 +124,40 @@ pub fn Popover(
                 ref=target_ref
                 on:mouseenter=on_mouse_enter
                 on:mouseleave=on_mouse_leave
+                on:focusin=on_focus_in
+                on:focusout=on_focus_out
+                on:contextmenu=on_context_menu
             >
                 {trigger_children()}
             </div>
-            <Follower slot show=is_show_popover placement>
+            <Follower slot show=show.signal() placement>
                 <CSSTransition
                     node_ref=popover_ref
                     name="popover-transition"
-                    appear=is_show_popover.get_untracked()
-                    show=is_show_popover
+                    appear=show.get_untracked()
+                    show=show.signal()
                     let:display
                 >
                     <div
-                        class=if tooltip { "thaw-popover thaw-popover--tooltip" } else { "thaw-popover" }
-                        style=move || {
-                            display.get().map(|d| d.to_string()).unwrap_or_else(|| css_vars.get())
-                        }
-
+                        class=class_list![
+                            "thaw-popover", ("thaw-popover--tooltip", move || tooltip),
+                            ("thaw-popover--arrow", move || show_arrow)
+                        ]
+                        style=move || display.get()
                         ref=popover_ref
+                        role=tooltip.then_some("tooltip")
                         on:mouseenter=on_mouse_enter
                         on:mouseleave=on_mouse_leave
                     >
                         <div class=class.map(|c| move || c.get())>{children()}</div>
-                        <div class="thaw-popover__angle-container">
-                            <div class="thaw-popover__angle"></div>
-                        </div>
+                        {show_arrow
+                            .then(|| {
+                                view! {
+                                    <div class="thaw-popover__angle-container">
+                                        <div class="thaw-popover__angle"></div>
+                                    </div>
+                                }
+                            })}
                     </div>
                 </CSSTransition>
             </Follower>
@@ -144,6 +170,12 @@ pub enum PopoverTriggerType {
     #[default]
     Hover,
     Click,
+    /// Shown while the trigger has the focus.
+    Focus,
+    /// Shown by a right click on the trigger, hidden by a click outside.
+    ContextMenu,
+    /// Only shown and hidden through `show`.
+    Manual,
 }
 
 impl Copy for PopoverTriggerType {}
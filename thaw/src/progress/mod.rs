@@ -4,7 +4,7 @@ mod theme;
 pub use progress_circle::ProgressCircle;
 pub use theme::ProgressTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::mount_style;
 
@@ -36,12 +36,12 @@ pub enum ProgressColor {
 }
 
 impl ProgressColor {
-    fn theme_background_color(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            Self::Primary => theme.common.color_primary.clone(),
-            Self::Success => theme.common.color_success.clone(),
-            Self::Warning => theme.common.color_warning.clone(),
-            Self::Error => theme.common.color_error.clone(),
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
         }
     }
 }
@@ -53,22 +53,8 @@ pub fn Progress(
     #[prop(into, default = MaybeSignal::Static(true))] show_indicator: MaybeSignal<bool>,
     #[prop(into, optional)] indicator_placement: MaybeSignal<ProgressIndicatorPlacement>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("progress", include_str!("./progress.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.progress.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-inner-background-color: {};",
-                color.get().theme_background_color(theme)
-            ));
-        });
-        css_vars
-    });
     let style = move || {
         let percentage = percentage.get();
         let percentage = if percentage < 0.0 {
@@ -93,7 +79,7 @@ pub fn Progress(
     };
 
     view! {
-        <div class="thaw-progress" style=move || css_vars.get()>
+        <div class=move || format!("thaw-progress thaw-progress--{}", color.get().as_str())>
             <div class=class>
                 <div class="thaw-progress__progress-inner" style=style>
                     <Show when=move || {
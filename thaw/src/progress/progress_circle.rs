@@ -1,5 +1,5 @@
 use super::ProgressColor;
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -11,8 +11,8 @@ pub fn ProgressCircle(
     #[prop(into, default = "120px".into())] size: MaybeSignal<String>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("progress-circle", include_str!("./progress-circle.css"));
-    let theme = use_theme(Theme::light);
 
     let stroke_width = 7;
     let view_box_width = 100;
@@ -27,8 +27,6 @@ pub fn ProgressCircle(
 
     let len = std::f64::consts::PI * 2.0 * f64::from(radius);
     let rail_stroke_dasharray = format!("{len}px {}px", view_box_width * 8);
-    let rail_stroke_color =
-        Memo::new(move |_| theme.with(|theme| theme.progress.background_color.clone()));
 
     let fill_path = rail_path.clone();
     let fill_stroke_dasharray = Memo::new(move |_| {
@@ -46,17 +44,17 @@ pub fn ProgressCircle(
             view_box_width * 8
         )
     });
-    let fill_stroke_color =
-        Memo::new(move |_| theme.with(|theme| color.get().theme_background_color(theme)));
 
     view! {
         <div
-            class=class_list!["thaw-progress-circle", class.map(| c | move || c.get())]
+            class=class_list![
+                "thaw-progress-circle", move || format!("thaw-progress-circle--{}", color.get()
+                .as_str()), class.map(| c | move || c.get())
+            ]
             role="progressbar"
             aria-valuemax="100"
             aria-valuemin="0"
             aria-valuenow=move || percentage.get()
-            style=("--thaw-fill-color", move || fill_stroke_color.get())
             style=("--thaw-size", move || size.get())
         >
 
@@ -67,7 +65,7 @@ pub fn ProgressCircle(
                         stroke-width=stroke_width
                         stroke-linecap="round"
                         fill="none"
-                        style:stroke=move || rail_stroke_color.get()
+                        style:stroke="var(--thaw-progress-background-color)"
                         style:stroke-dasharray=rail_stroke_dasharray
                     ></path>
                 </g>
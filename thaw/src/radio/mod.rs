@@ -2,9 +2,11 @@ mod radio_group;
 mod radio_item;
 
 pub use radio_group::RadioGroup;
+
+use radio_group::RadioGroupInjection;
 pub use radio_item::RadioItem;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, Model, OptionalProp};
 
@@ -14,18 +16,19 @@ pub fn Radio(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
+    mount_theme_vars();
     mount_style("radio", include_str!("./radio.css"));
-
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let bg_color = theme.common.color_primary.clone();
-            css_vars.push_str(&format!("--thaw-background-color-checked: {bg_color};"));
-        });
-
-        css_vars
-    });
+    // Only the checked radio of a group is reached by `Tab`, the arrow keys move between them.
+    let group_value = use_context::<RadioGroupInjection>().map(|group| group.0);
+    let tabindex = move || {
+        let is_tabbable =
+            value.get() || group_value.is_none_or(|group_value| group_value.with(Option::is_none));
+        if is_tabbable {
+            "0"
+        } else {
+            "-1"
+        }
+    };
 
     view! {
         <div
@@ -34,10 +37,25 @@ pub fn Radio(
                 c.get())
             ]
 
-            style=move || css_vars.get()
             on:click=move |_| value.set(!value.get_untracked())
+            on:keydown=move |ev| {
+                if matches!(ev.key().as_str(), " " | "Enter") {
+                    ev.prevent_default();
+                    value.set(!value.get_untracked());
+                }
+            }
+
+            role="radio"
+            tabindex=tabindex
+            aria-checked=move || if value.get() { "true" } else { "false" }
         >
-            <input class="thaw-radio__input" type="radio" prop:value=move || value.get()/>
+            <input
+                class="thaw-radio__input"
+                type="radio"
+                tabindex="-1"
+                aria-hidden="true"
+                prop:value=move || value.get()
+            />
             <div class="thaw-radio__dot"></div>
             <div class="thaw-radio__label">{children.map(|children| children())}</div>
         </div>
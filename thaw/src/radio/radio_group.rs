@@ -1,12 +1,28 @@
 use leptos::*;
-use thaw_utils::Model;
+use thaw_utils::{mount_style, use_roving_focus, Model, RovingFocusOrientation};
 
 #[component]
 pub fn RadioGroup(
     #[prop(optional, into)] value: Model<Option<String>>,
     children: Children,
 ) -> impl IntoView {
-    view! { <Provider value=RadioGroupInjection(value) children/> }
+    mount_style("radio", include_str!("./radio.css"));
+    let group_ref = create_node_ref::<html::Div>();
+    let roving_focus =
+        use_roving_focus(group_ref, ".thaw-radio", RovingFocusOrientation::Both).click_on_focus();
+
+    view! {
+        <Provider value=RadioGroupInjection(value)>
+            <div
+                class="thaw-radio-group"
+                role="radiogroup"
+                ref=group_ref
+                on:keydown=move |ev| roving_focus.on_keydown(ev)
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
 }
 
 #[derive(Clone)]
@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::ScrollbarTheme;
 
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::{leptos_dom::helpers::WindowListenerHandle, *};
 use thaw_utils::{class_list, mount_style, ComponentRef, OptionalProp};
 
@@ -14,25 +14,11 @@ pub fn Scrollbar(
     #[prop(optional, into)] content_style: OptionalProp<MaybeSignal<String>>,
     #[prop(default = 8)] size: u8,
     #[prop(optional)] comp_ref: Option<ComponentRef<ScrollbarRef>>,
+    #[prop(optional, into)] on_scroll: Option<Callback<ev::Event>>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("scrollbar", include_str!("./scrollbar.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = Memo::new(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-scrollbar-background-color: {};",
-                theme.scrollbar.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-scrollbar-background-color-hover: {};",
-                theme.scrollbar.background_color_hover
-            ));
-            css_vars.push_str(&format!("--thaw-scrollbar-size: {}px;", size));
-        });
-        css_vars
-    });
 
     let container_ref = NodeRef::<html::Div>::new();
     let content_ref = NodeRef::<html::Div>::new();
@@ -167,8 +153,11 @@ pub fn Scrollbar(
         });
     };
 
-    let on_scroll = move |_| {
+    let on_scroll = move |ev| {
         sync_scroll_state();
+        if let Some(on_scroll) = on_scroll.as_ref() {
+            on_scroll.call(ev);
+        }
     };
 
     let x_trumb_mousemove_handle = StoredValue::new(None::<WindowListenerHandle>);
@@ -304,7 +293,11 @@ pub fn Scrollbar(
         <div
             class=class_list!["thaw-scrollbar", class.map(| c | move || c.get())]
             style=move || {
-                format!("{}{}", css_vars.get(), style.as_ref().map(|s| s.get()).unwrap_or_default())
+                format!(
+                    "--thaw-scrollbar-size: {}px;{}",
+                    size,
+                    style.as_ref().map(|s| s.get()).unwrap_or_default(),
+                )
             }
 
             on:mouseenter=on_mouseenter
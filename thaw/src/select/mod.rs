@@ -1,24 +1,33 @@
 mod multi;
 mod raw;
+mod search;
 mod theme;
 
 pub use multi::*;
+pub(crate) use raw::{
+    RawSelect, SelectIcon, SelectMenu, VIRTUAL_MENU_HEIGHT, VIRTUAL_MENU_ITEM_HEIGHT,
+};
+pub use search::OptionSearch;
+pub(crate) use search::SearchedOptions;
 pub use theme::SelectTheme;
 
 use leptos::*;
-use std::{hash::Hash, rc::Rc};
+use std::{hash::Hash, rc::Rc, time::Duration};
 use thaw_utils::{Model, OptionalProp};
 
-use crate::{
-    select::raw::{RawSelect, SelectIcon},
-    Icon,
-};
+use crate::{form::use_form_field, FormFieldValue, Icon};
 
 #[slot]
 pub struct SelectLabel {
     children: Children,
 }
 
+/// Shown in the menu when there are no options.
+#[slot]
+pub struct SelectEmpty {
+    children: ChildrenFn,
+}
+
 #[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct SelectOption<T> {
     pub label: String,
@@ -40,36 +49,74 @@ pub fn Select<T>(
     #[prop(optional, into)] options: MaybeSignal<Vec<SelectOption<T>>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional)] virtual_scroll: bool,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    /// Whether options can be filtered by typing.
+    #[prop(optional, into)]
+    filterable: MaybeSignal<bool>,
+    /// Decides if an option matches the typed text,
+    /// by default the labels are matched case-insensitively.
+    #[prop(optional, into)]
+    filter: Option<Callback<(String, SelectOption<T>), bool>>,
+    /// Loads the options matching the typed text from a remote source, they are not
+    /// filtered locally in this case. Only the result of the latest search is shown,
+    /// `options` are shown while nothing is typed.
+    #[prop(optional, into)]
+    on_search: Option<OptionSearch<SelectOption<T>>>,
+    /// Milliseconds `on_search` waits for the typing to stop.
+    #[prop(default = 300)]
+    search_debounce: u64,
+    /// Shows a spinner in the menu.
+    #[prop(optional, into)]
+    loading: MaybeSignal<bool>,
+    #[prop(optional)] select_empty: Option<SelectEmpty>,
 ) -> impl IntoView
 where
     T: Eq + Hash + Clone + 'static,
 {
+    let searched_options = SearchedOptions::new(on_search, Duration::from_millis(search_debounce));
+    let is_remote = searched_options.is_remote();
+    let options = searched_options.options(options);
+    let loading = searched_options.loading(loading);
+    // With `on_search` the options may be replaced by results without the selected
+    // value, the last selected option keeps its label.
+    let selected_option = create_memo(move |prev: Option<&Option<SelectOption<T>>>| {
+        with!(|value, options| {
+            let value = value.as_ref()?;
+            options
+                .iter()
+                .find(|opt| &opt.value == value)
+                .or_else(|| prev?.as_ref().filter(|opt| &opt.value == value))
+                .cloned()
+        })
+    });
+    let selected_label = Signal::derive(move || {
+        selected_option.with(|option| option.as_ref().map(|option| option.label.clone()))
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            selected_label
+                .get()
+                .map_or(FormFieldValue::Empty, FormFieldValue::Text)
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
     let is_menu_visible = create_rw_signal(false);
     let show_menu = move |_| is_menu_visible.set(true);
     let hide_menu = move |_| is_menu_visible.set(false);
     let is_selected = move |v: &T| with!(|value| value.as_ref() == Some(v));
-    let on_select: Callback<(ev::MouseEvent, SelectOption<T>)> =
-        Callback::new(move |(_, option): (ev::MouseEvent, SelectOption<T>)| {
-            let item_value = option.value;
-            value.set(Some(item_value));
-            hide_menu(());
-        });
-    let select_label = select_label.unwrap_or_else(|| {
-        let options = options.clone();
-        let value_label = Signal::derive(move || {
-            with!(|value, options| {
-                match value {
-                    Some(value) => options
-                        .iter()
-                        .find(|opt| &opt.value == value)
-                        .map_or(String::new(), |v| v.label.clone()),
-                    None => String::new(),
-                }
-            })
-        });
-        SelectLabel {
-            children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
-        }
+    let on_select: Callback<SelectOption<T>> = Callback::new(move |option: SelectOption<T>| {
+        value.set(Some(option.value));
+        hide_menu(());
+    });
+    let value_label = Signal::derive(move || selected_label.get().unwrap_or_default());
+    let filter_placeholder = value_label;
+    let select_label = select_label.unwrap_or_else(|| SelectLabel {
+        children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
     });
     let select_icon = SelectIcon {
         children: Rc::new(move || {
@@ -90,6 +137,14 @@ where
             show_menu
             hide_menu
             is_selected=is_selected
+            virtual_scroll
+            invalid
+            filterable
+            filter
+            on_search=is_remote.then(|| Callback::new(move |text| searched_options.search(text)))
+            loading
+            select_empty
+            filter_placeholder
         />
     }
 }
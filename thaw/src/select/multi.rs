@@ -3,8 +3,10 @@ use std::{hash::Hash, rc::Rc, time::Duration};
 use thaw_utils::{Model, OptionalProp};
 
 use crate::{
+    form::use_form_field,
     select::raw::{RawSelect, SelectIcon},
-    Icon, SelectLabel, SelectOption, Tag, TagVariant,
+    select::SearchedOptions,
+    FormFieldValue, Icon, OptionSearch, SelectEmpty, SelectLabel, SelectOption, Tag, TagVariant,
 };
 
 #[derive(Clone, Default, PartialEq, Eq, Hash)]
@@ -45,99 +47,117 @@ pub fn MultiSelect<T>(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional, into)] clearable: MaybeSignal<bool>,
     #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional)] virtual_scroll: bool,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    /// Whether options can be filtered by typing.
+    #[prop(optional, into)]
+    filterable: MaybeSignal<bool>,
+    /// Decides if an option matches the typed text,
+    /// by default the labels are matched case-insensitively.
+    #[prop(optional, into)]
+    filter: Option<Callback<(String, SelectOption<T>), bool>>,
+    /// Loads the options matching the typed text from a remote source, they are not
+    /// filtered locally in this case. Only the result of the latest search is shown,
+    /// `options` are shown while nothing is typed.
+    #[prop(optional, into)]
+    on_search: Option<OptionSearch<MultiSelectOption<T>>>,
+    /// Milliseconds `on_search` waits for the typing to stop.
+    #[prop(default = 300)]
+    search_debounce: u64,
+    /// Shows a spinner in the menu.
+    #[prop(optional, into)]
+    loading: MaybeSignal<bool>,
+    #[prop(optional)] select_empty: Option<SelectEmpty>,
 ) -> impl IntoView
 where
     T: Eq + Hash + Clone + 'static,
 {
-    let select_options: Signal<Vec<_>> = Signal::derive({
-        let options = options.clone();
-        move || options.get().into_iter().map(SelectOption::from).collect()
+    let searched_options = SearchedOptions::new(on_search, Duration::from_millis(search_debounce));
+    let is_remote = searched_options.is_remote();
+    let options = searched_options.options(options);
+    let loading = searched_options.loading(loading);
+    // With `on_search` the options may be replaced by results without the selected
+    // values, the selected options keep their labels and variants.
+    let selected_options = create_memo(move |prev: Option<&Vec<MultiSelectOption<T>>>| {
+        with!(|value, options| {
+            value
+                .iter()
+                .filter_map(|value| {
+                    options
+                        .iter()
+                        .chain(prev.into_iter().flatten())
+                        .find(|opt| &opt.value == value)
+                        .cloned()
+                })
+                .collect::<Vec<_>>()
+        })
     });
-    let class: OptionalProp<_> = match class.into_option() {
-        Some(MaybeSignal::Dynamic(class)) => {
-            Some(MaybeSignal::Dynamic(Signal::derive(move || {
-                with!(|class| format!("thaw-select--multiple {class}"))
-            })))
-            .into()
-        }
-        Some(MaybeSignal::Static(class)) => Some(MaybeSignal::Static(format!(
-            "thaw-select--multiple {class}"
-        )))
-        .into(),
-        None => Some(MaybeSignal::Static("thaw-select--multiple".to_string())).into(),
-    };
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            selected_options.with(|selected_options| {
+                FormFieldValue::List(
+                    selected_options
+                        .iter()
+                        .map(|opt| opt.label.clone())
+                        .collect(),
+                )
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+    let select_options: Signal<Vec<_>> =
+        Signal::derive(move || options.get().into_iter().map(SelectOption::from).collect());
+    let class = multiple_class(class);
     let is_menu_visible = create_rw_signal(false);
     let show_menu = move |_| is_menu_visible.set(true);
     let hide_menu = move |_| is_menu_visible.set(false);
     let is_selected = move |v: &T| with!(|value| value.contains(v));
-    let on_select: Callback<(ev::MouseEvent, SelectOption<T>)> =
-        Callback::new(move |(_, option): (ev::MouseEvent, SelectOption<T>)| {
-            let item_value = option.value;
-            update!(|value| {
-                let index = value
-                    .iter()
-                    .enumerate()
-                    .find_map(|(i, v)| (v == &item_value).then_some(i));
-                match index {
-                    Some(i) => {
-                        value.remove(i);
-                    }
-                    None => {
-                        value.push(item_value);
-                    }
+    let on_select: Callback<SelectOption<T>> = Callback::new(move |option: SelectOption<T>| {
+        let item_value = option.value;
+        update!(|value| {
+            let index = value
+                .iter()
+                .enumerate()
+                .find_map(|(i, v)| (v == &item_value).then_some(i));
+            match index {
+                Some(i) => {
+                    value.remove(i);
                 }
-            });
+                None => {
+                    value.push(item_value);
+                }
+            }
         });
+    });
     let select_label = select_label.unwrap_or_else(|| {
-        let options = options.clone();
-        let signal_value = value;
-        let value_label = Signal::derive(move || {
-            with!(|value, options| {
+        let tags = Signal::derive(move || {
+            with!(|value, selected_options| {
                 value
                     .iter()
                     .map(|value| {
-                        let (label, variant) = options
+                        let (label, variant) = selected_options
                             .iter()
-                            .find(move |v| &v.value == value)
+                            .find(|opt| &opt.value == value)
                             .map_or((String::new(), TagVariant::Default), |v| {
                                 (v.label.clone(), v.variant)
                             });
-                        let value = value.clone();
-                        let on_close = Callback::new(move |ev: ev::MouseEvent| {
-                            ev.stop_propagation();
-                            let value = value.clone();
-                            // We remove the item on the next tick to ensure the menu on click handler works correctly
-                            set_timeout(
-                                move || {
-                                    update!(|signal_value| {
-                                        let index = signal_value
-                                            .iter()
-                                            .enumerate()
-                                            .find_map(|(i, v)| (v == &value).then_some(i));
-                                        if let Some(i) = index {
-                                            signal_value.remove(i);
-                                        }
-                                    });
-                                },
-                                Duration::ZERO,
-                            )
-                        });
-                        view! {
-                            <Tag
-                                variant
-                                closable=true
-                                on_close
-                            >
-                                {label}
-                            </Tag>
-                        }
+                        (value.clone(), label, variant)
                     })
-                    .collect_view()
+                    .collect()
             })
         });
-        SelectLabel {
-            children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
-        }
+        let on_remove = Callback::new(move |item_value: T| {
+            value.update(|value| {
+                if let Some(i) = value.iter().position(|v| v == &item_value) {
+                    value.remove(i);
+                }
+            });
+        });
+        tags_select_label(tags, on_remove)
     });
     let is_hovered = RwSignal::new(false);
     let show_clear_icon = Signal::derive(move || {
@@ -146,31 +166,8 @@ where
     });
     let on_hover_enter = Callback::new(move |_| is_hovered.set(true));
     let on_hover_exit = Callback::new(move |_| is_hovered.set(false));
-    let select_icon = SelectIcon {
-        children: Rc::new(move || {
-            Fragment::new(vec![view! {
-                {move || if show_clear_icon.get() {
-                    view! {
-                        <Icon
-                            class="thaw-select-dropdown-icon thaw-select-dropdown-icon--clear"
-                            icon=icondata_ai::AiCloseCircleFilled
-                            on_click=Callback::new(move |_| {
-                                set_timeout(
-                                    move || value.set(vec![]),
-                                    Duration::ZERO,
-                                )
-                            })
-                        />
-                    }
-                } else {
-                    view! {
-                        <Icon class="thaw-select-dropdown-icon" icon=icondata_ai::AiDownOutlined/>
-                    }
-                }}
-            }
-            .into_view()])
-        }),
-    };
+    let select_icon =
+        multiple_select_icon(show_clear_icon, Callback::new(move |_| value.set(vec![])));
 
     // Trigger the following menu to resync when the value is updated
     let _ = watch(
@@ -194,6 +191,97 @@ where
             on_hover_enter
             on_hover_exit
             is_selected=is_selected
+            virtual_scroll
+            invalid
+            filterable
+            filter
+            on_search=is_remote.then(|| Callback::new(move |text| searched_options.search(text)))
+            loading
+            select_empty
         />
     }
 }
+
+/// Adds the class of the multiple pickers to the user's classes.
+pub(crate) fn multiple_class(
+    class: OptionalProp<MaybeSignal<String>>,
+) -> OptionalProp<MaybeSignal<String>> {
+    match class.into_option() {
+        Some(MaybeSignal::Dynamic(class)) => {
+            Some(MaybeSignal::Dynamic(Signal::derive(move || {
+                with!(|class| format!("thaw-select--multiple {class}"))
+            })))
+            .into()
+        }
+        Some(MaybeSignal::Static(class)) => Some(MaybeSignal::Static(format!(
+            "thaw-select--multiple {class}"
+        )))
+        .into(),
+        None => Some(MaybeSignal::Static("thaw-select--multiple".to_string())).into(),
+    }
+}
+
+/// A closable tag for every selected item, `on_remove` is called with the
+/// value of a closed one.
+pub(crate) fn tags_select_label<T: Clone + 'static>(
+    tags: Signal<Vec<(T, String, TagVariant)>>,
+    on_remove: Callback<T>,
+) -> SelectLabel {
+    let value_label = Signal::derive(move || {
+        tags.get()
+            .into_iter()
+            .map(|(value, label, variant)| {
+                let on_close = Callback::new(move |ev: ev::MouseEvent| {
+                    ev.stop_propagation();
+                    let value = value.clone();
+                    // We remove the item on the next tick to ensure the menu on click handler works correctly
+                    set_timeout(move || on_remove.call(value), Duration::ZERO)
+                });
+                view! {
+                    <Tag
+                        variant
+                        closable=true
+                        on_close
+                    >
+                        {label}
+                    </Tag>
+                }
+            })
+            .collect_view()
+    });
+    SelectLabel {
+        children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
+    }
+}
+
+/// The dropdown icon, replaced by a clear icon while `show_clear_icon` is `true`.
+pub(crate) fn multiple_select_icon(
+    show_clear_icon: Signal<bool>,
+    on_clear: Callback<()>,
+) -> SelectIcon {
+    SelectIcon {
+        children: Rc::new(move || {
+            Fragment::new(vec![view! {
+                {move || if show_clear_icon.get() {
+                    view! {
+                        <Icon
+                            class="thaw-select-dropdown-icon thaw-select-dropdown-icon--clear"
+                            icon=icondata_ai::AiCloseCircleFilled
+                            on_click=Callback::new(move |_| {
+                                set_timeout(
+                                    move || on_clear.call(()),
+                                    Duration::ZERO,
+                                )
+                            })
+                        />
+                    }
+                } else {
+                    view! {
+                        <Icon class="thaw-select-dropdown-icon" icon=icondata_ai::AiDownOutlined/>
+                    }
+                }}
+            }
+            .into_view()])
+        }),
+    }
+}
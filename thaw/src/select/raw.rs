@@ -1,38 +1,210 @@
 use std::{hash::Hash, time::Duration};
 
 use leptos::*;
-use thaw_components::{Binder, CSSTransition, Follower, FollowerPlacement, FollowerWidth};
+use thaw_components::{
+    Binder, CSSTransition, Follower, FollowerPlacement, FollowerWidth, VirtualList,
+    VirtualListScroll,
+};
 use thaw_utils::{class_list, mount_style, OptionalProp};
+use uuid::Uuid;
 
-use crate::{theme::use_theme, SelectLabel, SelectOption, Theme};
+use crate::{
+    theme::mount_theme_vars, use_locale, SelectEmpty, SelectLabel, SelectOption, Spinner,
+    SpinnerSize,
+};
+
+/// Height of a menu item when `virtual_scroll` is enabled, see `.thaw-select-menu--virtual`
+/// and `.thaw-auto-complete__menu--virtual`.
+pub(crate) const VIRTUAL_MENU_ITEM_HEIGHT: f64 = 34.0;
+/// The largest `max-height` of `.thaw-select-menu` and `.thaw-auto-complete__menu`,
+/// they shrink when there is less room.
+pub(crate) const VIRTUAL_MENU_HEIGHT: f64 = 200.0;
 
 #[slot]
 pub(crate) struct SelectIcon {
     children: ChildrenFn,
 }
 
+/// Replaces the options in the menu, used by the pickers of hierarchical options.
+#[slot]
+pub(crate) struct SelectMenu {
+    #[prop(optional)]
+    class: &'static str,
+    children: ChildrenFn,
+}
+
 #[component]
-pub(super) fn RawSelect<T, F>(
+pub(crate) fn RawSelect<T, F>(
     #[prop(optional, into)] options: MaybeSignal<Vec<SelectOption<T>>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     select_label: SelectLabel,
     select_icon: SelectIcon,
     #[prop(optional, into)] is_menu_visible: Signal<bool>,
-    #[prop(into)] on_select: Callback<(ev::MouseEvent, SelectOption<T>)>,
+    #[prop(into)] on_select: Callback<SelectOption<T>>,
     #[prop(into)] show_menu: Callback<()>,
     #[prop(into)] hide_menu: Callback<()>,
     #[prop(optional, into)] on_hover_enter: Option<Callback<()>>,
     #[prop(optional, into)] on_hover_exit: Option<Callback<()>>,
+    #[prop(optional)] virtual_scroll: bool,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+    #[prop(optional, into)] filterable: MaybeSignal<bool>,
+    #[prop(into)] filter: Option<Callback<(String, SelectOption<T>), bool>>,
+    /// Shown in the filter input while nothing has been typed.
+    #[prop(optional, into)]
+    filter_placeholder: Signal<String>,
+    /// Options are no longer filtered locally but searched through this callback,
+    /// it is called with an empty text when the filter is cleared.
+    #[prop(into)]
+    on_search: Option<Callback<String>>,
+    #[prop(optional, into)] loading: MaybeSignal<bool>,
+    #[prop(into)] select_empty: Option<SelectEmpty>,
+    #[prop(optional)] select_menu: Option<SelectMenu>,
     is_selected: F,
 ) -> impl IntoView
 where
     T: Eq + Hash + Clone + 'static,
     F: Fn(&T) -> bool + Copy + 'static,
 {
+    mount_theme_vars();
     mount_style("select", include_str!("./select.css"));
+    let locale = use_locale();
 
     let trigger_ref = create_node_ref::<html::Div>();
     let menu_ref = create_node_ref::<html::Div>();
+    let label_ref = create_node_ref::<html::Div>();
+    let filter_input_ref = create_node_ref::<html::Input>();
+    let menu_id = StoredValue::new(format!("thaw-select-menu-{}", Uuid::new_v4()));
+
+    let is_remote = on_search.is_some();
+    let filterable = Signal::derive(move || is_remote || filterable.get());
+    let filter_text = RwSignal::new(String::new());
+    let clear_filter = move || {
+        filter_text.set(String::new());
+        if let Some(on_search) = on_search {
+            on_search.call(String::new());
+        }
+    };
+    let filtered_options = create_memo(move |_| {
+        let pattern = filter_text.get();
+        if is_remote || !filterable.get() || pattern.is_empty() {
+            return options.get();
+        }
+        options.with(|options| {
+            options
+                .iter()
+                .filter(|option| match filter {
+                    Some(filter) => filter.call((pattern.clone(), (*option).clone())),
+                    None => option
+                        .label
+                        .to_lowercase()
+                        .contains(&pattern.to_lowercase()),
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let active_index = RwSignal::new(None::<usize>);
+    // The active option is reset when the menu is opened or the filter changes,
+    // the first selected option is preferred.
+    create_effect(move |prev: Option<(bool, String)>| {
+        let is_visible = is_menu_visible.get();
+        let pattern = filter_text.get();
+        if is_visible && prev.as_ref() != Some(&(true, pattern.clone())) {
+            let index = filtered_options.with_untracked(|options| {
+                options
+                    .iter()
+                    .position(|option| is_selected(&option.value))
+                    .or_else(|| (!options.is_empty()).then_some(0))
+            });
+            active_index.set(index);
+        } else if !is_visible && !pattern.is_empty() {
+            clear_filter();
+        }
+        (is_visible, pattern)
+    });
+
+    let aria_expanded = move || {
+        if is_menu_visible.get() {
+            "true"
+        } else {
+            "false"
+        }
+    };
+    let aria_activedescendant = move || {
+        is_menu_visible
+            .get()
+            .then(|| active_index.get())
+            .flatten()
+            .map(|index| format!("{}-{index}", menu_id.get_value()))
+    };
+
+    let select_option = move |option: SelectOption<T>| {
+        if !filter_text.with_untracked(String::is_empty) {
+            clear_filter();
+        }
+        on_select.call(option);
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let key = ev.key();
+        if !is_menu_visible.get_untracked() {
+            let is_filter_input = filterable.get_untracked();
+            if matches!(key.as_str(), "ArrowDown" | "ArrowUp" | "Enter")
+                || (key == " " && !is_filter_input)
+            {
+                ev.prevent_default();
+                show_menu.call(());
+            }
+            return;
+        }
+        let len = filtered_options.with_untracked(|options| options.len());
+        match key.as_str() {
+            "ArrowDown" => {
+                ev.prevent_default();
+                active_index.update(|index| {
+                    *index = match *index {
+                        _ if len == 0 => None,
+                        Some(index) if index + 1 < len => Some(index + 1),
+                        _ => Some(0),
+                    }
+                });
+            }
+            "ArrowUp" => {
+                ev.prevent_default();
+                active_index.update(|index| {
+                    *index = match *index {
+                        _ if len == 0 => None,
+                        Some(index) if index > 0 && index < len => Some(index - 1),
+                        _ => Some(len - 1),
+                    }
+                });
+            }
+            "Home" => {
+                ev.prevent_default();
+                active_index.set((len > 0).then_some(0));
+            }
+            "End" => {
+                ev.prevent_default();
+                active_index.set(len.checked_sub(1));
+            }
+            "Enter" => {
+                ev.prevent_default();
+                let option = active_index.get_untracked().and_then(|index| {
+                    filtered_options.with_untracked(|options| options.get(index).cloned())
+                });
+                if let Some(option) = option {
+                    select_option(option);
+                }
+            }
+            "Escape" => {
+                ev.prevent_default();
+                hide_menu.call(());
+            }
+            "Tab" => hide_menu.call(()),
+            _ => {}
+        }
+    };
 
     #[cfg(any(feature = "csr", feature = "hydrate"))]
     {
@@ -42,8 +214,11 @@ where
             let el: Option<web_sys::Node> = el.into_js_result().map_or(None, |el| Some(el.into()));
             let is_descendent_of_select = trigger_ref.get().unwrap().contains(el.as_ref());
             let is_descendent_of_menu = menu_ref.get().unwrap().contains(el.as_ref());
+            let is_trigger = el.is_some_and(|el| {
+                el == ****trigger_ref.get().unwrap() || el == ****label_ref.get().unwrap()
+            });
             if (!is_descendent_of_select && !is_descendent_of_menu)
-                || (is_menu_visible.get() && el.unwrap() == ****trigger_ref.get().unwrap())
+                || (is_menu_visible.get() && is_trigger)
             {
                 hide_menu.call(());
             }
@@ -55,61 +230,147 @@ where
         let _ = hide_menu;
     }
 
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let border_color_hover = theme.common.color_primary.clone();
-            css_vars.push_str(&format!("--thaw-border-color-hover: {border_color_hover};"));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.select.background_color
-            ));
-            css_vars.push_str(&format!("--thaw-font-color: {};", theme.select.font_color));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                theme.select.border_color
-            ));
+    let menu_scroll = VirtualListScroll::new(menu_ref, VIRTUAL_MENU_ITEM_HEIGHT);
+    let on_menu_scroll = move |_| {
+        if virtual_scroll {
+            menu_scroll.on_scroll();
+        }
+    };
+    if virtual_scroll {
+        menu_scroll.reveal(move || {
+            if is_menu_visible.get() {
+                active_index.get()
+            } else {
+                None
+            }
         });
+    }
 
-        css_vars
+    let render_option = move |(index, item): (usize, SelectOption<T>)| {
+        let item = store_value(item);
+        let menu_item_ref = create_node_ref::<html::Div>();
+        if !virtual_scroll {
+            create_effect(move |_| {
+                if Some(index) != active_index.get() || !is_menu_visible.get() {
+                    return;
+                }
+                if let (Some(menu_item_el), Some(menu_el)) = (menu_item_ref.get(), menu_ref.get()) {
+                    let menu_rect = menu_el.get_bounding_client_rect();
+                    let item_rect = menu_item_el.get_bounding_client_rect();
+                    if item_rect.y() < menu_rect.y() {
+                        menu_item_el.scroll_into_view_with_bool(true);
+                    } else if item_rect.y() + item_rect.height()
+                        > menu_rect.y() + menu_rect.height()
+                    {
+                        menu_item_el.scroll_into_view_with_bool(false);
+                    }
+                }
+            });
+        }
+        let is_item_selected = move || item.with_value(|item_value| is_selected(&item_value.value));
+        view! {
+            <div
+                class="thaw-select-menu__item"
+                class=(
+                    "thaw-select-menu__item-selected",
+                    is_item_selected,
+                )
+                class=("thaw-select-menu__item--active", move || Some(index) == active_index.get())
+                on:click=move |_| select_option(item.get_value())
+                on:mousedown=|ev| ev.prevent_default()
+                on:mouseenter=move |_| active_index.set(Some(index))
+                ref=menu_item_ref
+                id=move || format!("{}-{index}", menu_id.get_value())
+                role="option"
+                aria-selected=move || if is_item_selected() { "true" } else { "false" }
+            >
+                {item.get_value().label}
+            </div>
+        }
+    };
+    let enumerated_options = Signal::derive(move || {
+        filtered_options
+            .get()
+            .into_iter()
+            .enumerate()
+            .collect::<Vec<_>>()
     });
 
-    let menu_css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.select.menu_background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-hover: {};",
-                theme.select.menu_background_color_hover
-            ));
-            css_vars.push_str(&format!("--thaw-font-color: {};", theme.select.font_color));
-            css_vars.push_str(&format!(
-                "--thaw-font-color-selected: {};",
-                theme.common.color_primary
-            ));
-        });
-        css_vars
-    });
+    let menu_class = select_menu.as_ref().map_or_else(
+        || String::from("thaw-select-menu"),
+        |select_menu| format!("thaw-select-menu {}", select_menu.class),
+    );
+    let menu_children = select_menu.map(|select_menu| select_menu.children);
+    let is_options_menu = menu_children.is_none();
 
     view! {
         <Binder target_ref=trigger_ref>
             <div
-                class=class_list!["thaw-select", class.map(|c| move || c.get())]
+                class=class_list![
+                    "thaw-select", ("thaw-select--invalid", move || invalid.get()),
+                    ("thaw-select--filterable", move || filterable.get()), ("thaw-select--open", move
+                    || is_menu_visible.get()), class.map(| c | move || c.get())
+                ]
                 ref=trigger_ref
+                tabindex=move || (!filterable.get()).then_some(0)
                 on:click=move |_| {
+                    if filterable.get_untracked() {
+                        if let Some(filter_input_el) = filter_input_ref.get_untracked() {
+                            _ = filter_input_el.focus();
+                        }
+                    }
                     if !is_menu_visible.get_untracked() {
                         set_timeout(move || show_menu.call(()), Duration::ZERO);
                     }
                 }
+                on:keydown=on_keydown
                 on:mouseenter=move |_| if let Some(cb) = on_hover_enter { cb.call(()) }
                 on:mouseleave=move |_| if let Some(cb) = on_hover_exit { cb.call(()) }
-                style=move || css_vars.get()
+                // The filter input is the combobox when the options are filterable.
+                role=move || (!filterable.get()).then_some("combobox")
+                aria-haspopup=move || (!filterable.get()).then_some("listbox")
+                aria-expanded=move || (!filterable.get()).then(aria_expanded)
+                aria-controls=move || (!filterable.get()).then(|| menu_id.get_value())
+                aria-activedescendant=move || {
+                    (!filterable.get()).then(aria_activedescendant).flatten()
+                }
+                aria-invalid=move || invalid.get().then_some("true")
             >
-                {(select_label.children)()}
+                <div class="thaw-select__label" ref=label_ref>
+                    {(select_label.children)()}
+                </div>
+                {move || {
+                    filterable
+                        .get()
+                        .then(|| {
+                            view! {
+                                <input
+                                    class="thaw-select__filter"
+                                    prop:value=move || filter_text.get()
+                                    placeholder=move || {
+                                        is_menu_visible.get().then(|| filter_placeholder.get())
+                                    }
+                                    on:input=move |ev| {
+                                        let text = event_target_value(&ev);
+                                        if let Some(on_search) = on_search {
+                                            on_search.call(text.clone());
+                                        }
+                                        filter_text.set(text);
+                                        if !is_menu_visible.get_untracked() {
+                                            show_menu.call(());
+                                        }
+                                    }
+                                    ref=filter_input_ref
+                                    role="combobox"
+                                    aria-autocomplete="list"
+                                    aria-haspopup="listbox"
+                                    aria-expanded=aria_expanded
+                                    aria-controls=menu_id.get_value()
+                                    aria-activedescendant=aria_activedescendant
+                                />
+                            }
+                        })
+                }}
                 {select_icon.children}
             </div>
             <Follower
@@ -126,34 +387,57 @@ where
                     let:display
                 >
                     <div
-                        class="thaw-select-menu"
-                        style=move || {
-                            display
-                                .get()
-                                .map(|d| d.to_string())
-                                .unwrap_or_else(|| menu_css_vars.get())
-                        }
+                        class=menu_class
+                        class=("thaw-select-menu--virtual", virtual_scroll)
+                        style=move || display.get()
                         ref=menu_ref
+                        on:scroll=on_menu_scroll
+                        id=menu_id.get_value()
+                        role=is_options_menu.then_some("listbox")
                     >
-                        <For
-                            each=move || options.get()
-                            key=move |item| item.value.clone()
-                            children=move |item| {
-                                let item = store_value(item);
-                                view! {
-                                    <div
-                                        class="thaw-select-menu__item"
-                                        class=(
-                                            "thaw-select-menu__item-selected",
-                                            move || item.with_value(|item_value| is_selected(&item_value.value)),
-                                        )
-                                        on:click=move |ev| on_select.call((ev, item.get_value()))
-                                    >
-                                        {item.get_value().label}
+                        {if let Some(menu_children) = menu_children {
+                            menu_children().into_view()
+                        } else if virtual_scroll {
+                            view! {
+                                <VirtualList
+                                    each=enumerated_options
+                                    key=|(index, item)| (*index, item.value.clone())
+                                    item_size=VIRTUAL_MENU_ITEM_HEIGHT
+                                    scroll_offset=menu_scroll.scroll_offset()
+                                    viewport_size=Signal::derive(|| VIRTUAL_MENU_HEIGHT)
+                                    children=render_option
+                                />
+                            }
+                                .into_view()
+                        } else {
+                            view! {
+                                <For
+                                    each=move || enumerated_options.get()
+                                    key=|(index, item)| (*index, item.value.clone())
+                                    children=render_option
+                                />
+                            }
+                                .into_view()
+                        }}
+                        {move || {
+                            if loading.get() {
+                                return view! {
+                                    <div class="thaw-select-menu__loading">
+                                        <Spinner size=SpinnerSize::Tiny/>
                                     </div>
                                 }
+                                    .into_view();
+                            }
+                            let is_empty = filtered_options.with(|options| options.is_empty());
+                            if !is_empty || !(filterable.get() || select_empty.is_some()) {
+                                return ().into_view();
                             }
-                        />
+                            let children = select_empty
+                                .as_ref()
+                                .map_or_else(|| (move || locale.with(|locale| locale.no_data.clone())).into_view(), |empty| (empty.children)().into_view());
+                            view! { <div class="thaw-select-menu__empty">{children}</div> }
+                                .into_view()
+                        }}
                     </div>
                 </CSSTransition>
             </Follower>
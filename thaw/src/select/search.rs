@@ -0,0 +1,139 @@
+use leptos::{leptos_dom::helpers::TimeoutHandle, *};
+use std::{future::Future, pin::Pin, rc::Rc, time::Duration};
+
+type SearchFn<O> = dyn Fn(String) -> Pin<Box<dyn Future<Output = Vec<O>>>>;
+
+/// Loads the options matching the typed text, e.g. from a server.
+///
+/// An async function or closure taking the text converts into it.
+pub struct OptionSearch<O>(Rc<SearchFn<O>>);
+
+impl<O> Clone for OptionSearch<O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<O, F, Fu> From<F> for OptionSearch<O>
+where
+    F: Fn(String) -> Fu + 'static,
+    Fu: Future<Output = Vec<O>> + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(move |text| Box::pin(f(text))))
+    }
+}
+
+/// The options found by an [`OptionSearch`] once the typing stopped.
+///
+/// Every search starts a new generation, results and loading states of older
+/// generations are dropped. The `options` of the component are shown while
+/// nothing is typed.
+pub(crate) struct SearchedOptions<O: 'static> {
+    search: StoredValue<Option<OptionSearch<O>>>,
+    debounce: Duration,
+    results: RwSignal<Option<Vec<O>>>,
+    loading: RwSignal<bool>,
+    generation: StoredValue<usize>,
+    timeout_handle: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl<O> Clone for SearchedOptions<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O> Copy for SearchedOptions<O> {}
+
+impl<O: Clone + 'static> SearchedOptions<O> {
+    pub fn new(search: Option<OptionSearch<O>>, debounce: Duration) -> Self {
+        let searched_options = Self {
+            search: StoredValue::new(search),
+            debounce,
+            results: RwSignal::new(None),
+            loading: RwSignal::new(false),
+            generation: StoredValue::new(0),
+            timeout_handle: StoredValue::new(None),
+        };
+        on_cleanup(move || searched_options.cancel());
+        searched_options
+    }
+
+    pub fn is_remote(&self) -> bool {
+        self.search.with_value(Option::is_some)
+    }
+
+    /// Searches `text` after the debounce, an empty text goes back to the `options`.
+    pub fn search(&self, text: String) {
+        if text.is_empty() {
+            self.reset();
+            return;
+        }
+        self.cancel();
+        let Self {
+            search,
+            results,
+            loading,
+            generation,
+            timeout_handle,
+            ..
+        } = *self;
+        let Ok(handle) = set_timeout_with_handle(
+            move || {
+                timeout_handle.set_value(None);
+                let Some(search) = search.get_value() else {
+                    return;
+                };
+                let search_generation = generation.get_value();
+                loading.set(true);
+                let found = (search.0)(text);
+                spawn_local(async move {
+                    let found = found.await;
+                    if generation.try_get_value() != Some(search_generation) {
+                        return;
+                    }
+                    results.try_set(Some(found));
+                    loading.try_set(false);
+                });
+            },
+            self.debounce,
+        ) else {
+            return;
+        };
+        self.timeout_handle.set_value(Some(handle));
+    }
+
+    /// Drops the pending and the running search and goes back to the `options`.
+    pub fn reset(&self) {
+        self.cancel();
+        if self.results.with_untracked(Option::is_some) {
+            self.results.set(None);
+        }
+    }
+
+    fn cancel(&self) {
+        self.timeout_handle.try_update_value(|handle| {
+            if let Some(handle) = handle.take() {
+                handle.clear();
+            }
+        });
+        self.generation
+            .try_update_value(|generation| *generation += 1);
+        if self.loading.try_get_untracked() == Some(true) {
+            self.loading.set(false);
+        }
+    }
+
+    /// The found options, or `options` when nothing was searched.
+    pub fn options(&self, options: MaybeSignal<Vec<O>>) -> Signal<Vec<O>> {
+        let results = self.results;
+        Signal::derive(move || results.get().unwrap_or_else(|| options.get()))
+    }
+
+    /// Whether a search is running or `loading` is set.
+    pub fn loading(&self, loading: MaybeSignal<bool>) -> Signal<bool> {
+        let is_searching = self.loading;
+        Signal::derive(move || is_searching.get() || loading.get())
+    }
+}
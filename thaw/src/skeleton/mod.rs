@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::SkeletionTheme;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::mount_style;
 
@@ -13,37 +13,26 @@ pub fn Skeleton(
     #[prop(optional, into)] width: Option<MaybeSignal<String>>,
     #[prop(optional, into)] height: Option<MaybeSignal<String>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("skeleton", include_str!("./skeleton.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
+    let style = create_memo(move |_| {
+        let mut style = String::new();
         if text.get() {
-            css_vars.push_str("display: inline-block;");
+            style.push_str("display: inline-block;");
         }
 
         if let Some(width) = width.as_ref() {
-            css_vars.push_str(&format!("width: {};", width.get()));
+            style.push_str(&format!("width: {};", width.get()));
         }
         if let Some(height) = height.as_ref() {
-            css_vars.push_str(&format!("height: {};", height.get()));
+            style.push_str(&format!("height: {};", height.get()));
         }
 
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color-start: {};",
-                theme.skeletion.background_color_start
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-end: {};",
-                theme.skeletion.background_color_end
-            ));
-        });
-
-        css_vars
+        style
     });
     (0..repeat.get())
         .map(|_| {
-            view! { <div class="thaw-skeleton" style=move || css_vars.get()></div> }
+            view! { <div class="thaw-skeleton" style=move || style.get()></div> }
         })
         .collect_view()
 }
@@ -4,10 +4,10 @@ mod theme;
 pub use slider_label::SliderLabel;
 pub use theme::SliderTheme;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_components::OptionComp;
-use thaw_utils::{class_list, mount_style, Model, OptionalProp};
+use thaw_utils::{class_list, mount_style, use_direction, Model, OptionalProp};
 use web_sys::DomRect;
 
 #[component]
@@ -18,24 +18,8 @@ pub fn Slider(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("slider", include_str!("./slider.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        css_vars.push_str(&format!("--thaw-slider-max: {};", max.get()));
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                &theme.slider.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-fill: {};",
-                &theme.common.color_primary
-            ));
-        });
-
-        css_vars
-    });
 
     let percentage = create_memo(move |_| {
         if value.get() < 0.0 || max.get() <= 0.0 {
@@ -68,6 +52,8 @@ pub fn Slider(
         value.set(val);
     };
 
+    let dir = use_direction();
+    let is_rtl = move || dir.is_some_and(|dir| dir.get().is_rtl());
     let rail_ref = create_node_ref::<html::Div>();
     let (mouse_move_value, set_mouse_move_value) = create_signal::<Option<f64>>(None);
     let (is_mouse_move, set_mouse_move) = create_signal(false);
@@ -76,12 +62,18 @@ pub fn Slider(
     };
 
     let check_value_and_update = move |ev_x: f64, rect: DomRect| {
-        if ev_x <= rect.x() {
+        // Distance from the start of the rail.
+        let offset = if is_rtl() {
+            rect.x() + rect.width() - ev_x
+        } else {
+            ev_x - rect.x()
+        };
+        if offset <= 0.0 {
             set_mouse_move_value.set(Some(0.0));
-        } else if ev_x >= rect.x() + rect.width() {
+        } else if offset >= rect.width() {
             set_mouse_move_value.set(Some(max.get()));
         } else {
-            set_mouse_move_value.set(Some(((ev_x - rect.x()) / rect.width()) * max.get()));
+            set_mouse_move_value.set(Some((offset / rect.width()) * max.get()));
         }
         if let Some(value) = mouse_move_value.get_untracked() {
             do_update_value(value);
@@ -115,8 +107,11 @@ pub fn Slider(
 
     view! {
         <div
-            class=class_list!["thaw-slider", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
+            class=class_list![
+                "thaw-slider", ("thaw-slider--rtl", is_rtl), class.map(| c | move || c.get())
+            ]
+
+            style=move || format!("--thaw-slider-max: {};", max.get())
             on:click=on_mouse_click
         >
             <div class="thaw-slider-rail" ref=rail_ref>
@@ -131,7 +126,7 @@ pub fn Slider(
             <div
                 on:mousedown=on_mouse_down
                 class="thaw-slider-handle"
-                style=move || { format!("left: {}%", percentage.get()) }
+                style=move || { format!("inset-inline-start: {}%", percentage.get()) }
             ></div>
 
         </div>
@@ -9,7 +9,10 @@ pub fn SliderLabel(#[prop(into)] value: MaybeSignal<f64>, children: Children) ->
         <div
             class:thaw-slider-label=true
             style=move || {
-                format!("left: calc(calc({} / var(--thaw-slider-max)) * 100%)", value.get())
+                format!(
+                    "inset-inline-start: calc(calc({} / var(--thaw-slider-max)) * 100%)",
+                    value.get(),
+                )
             }
         >
 
@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::SpinnerTheme;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -16,12 +16,12 @@ pub enum SpinnerSize {
 }
 
 impl SpinnerSize {
-    fn theme_height(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            SpinnerSize::Tiny => theme.common.height_tiny.clone(),
-            SpinnerSize::Small => theme.common.height_small.clone(),
-            SpinnerSize::Medium => theme.common.height_medium.clone(),
-            SpinnerSize::Large => theme.common.height_large.clone(),
+            SpinnerSize::Tiny => "tiny",
+            SpinnerSize::Small => "small",
+            SpinnerSize::Medium => "medium",
+            SpinnerSize::Large => "large",
         }
     }
 }
@@ -31,28 +31,14 @@ pub fn Spinner(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     #[prop(optional, into)] size: MaybeSignal<SpinnerSize>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("spinner", include_str!("./spinner.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-height: {};",
-                size.get().theme_height(theme)
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                &theme.spinner.background_color
-            ));
-            css_vars.push_str(&format!("--thaw-color: {};", &theme.common.color_primary));
-        });
-        css_vars
-    });
-
     view! {
         <div
-            class=class_list!["thaw-spinner", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
+            class=class_list![
+                "thaw-spinner", move || format!("thaw-spinner--{}", size.get().as_str()), class
+                .map(| c | move || c.get())
+            ]
         ></div>
     }
 }
@@ -0,0 +1,112 @@
+mod step_item;
+mod theme;
+
+pub use step_item::StepItem;
+pub use theme::StepsTheme;
+
+use crate::theme::mount_theme_vars;
+use leptos::*;
+use thaw_utils::{class_list, mount_style, Model, OptionalProp};
+use uuid::Uuid;
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum StepStatus {
+    Wait,
+    #[default]
+    Process,
+    Finish,
+    Error,
+}
+
+impl StepStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Wait => "wait",
+            Self::Process => "process",
+            Self::Finish => "finish",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[component]
+pub fn Steps(
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// The index of the current step, starting from zero.
+    #[prop(optional, into)]
+    current: Model<usize>,
+    /// Status of the current step, the steps before it are finished
+    /// and the ones after it are waiting.
+    #[prop(optional, into)]
+    status: MaybeSignal<StepStatus>,
+    #[prop(optional, into)] vertical: MaybeSignal<bool>,
+    /// Clicking a step, or pressing Enter or Space on it, makes it the current one.
+    #[prop(optional, into)]
+    clickable: MaybeSignal<bool>,
+    children: Children,
+) -> impl IntoView {
+    mount_theme_vars();
+    mount_style("steps", include_str!("./steps.css"));
+
+    let injection = StepsInjection {
+        current,
+        status,
+        clickable,
+        items: create_rw_signal(vec![]),
+    };
+
+    view! {
+        <Provider value=injection>
+            <div
+                class=class_list![
+                    "thaw-steps", ("thaw-steps--vertical", move || vertical.get()),
+                    class.map(| c | move || c.get())
+                ]
+                role="list"
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct StepsInjection {
+    current: Model<usize>,
+    status: MaybeSignal<StepStatus>,
+    clickable: MaybeSignal<bool>,
+    /// The registered items in document order.
+    items: RwSignal<Vec<(Uuid, NodeRef<html::Div>)>>,
+}
+
+impl StepsInjection {
+    /// Moves the mounted item `id` to its position in the document, an item
+    /// inserted between others is registered after them.
+    fn place_item(&self, id: Uuid, node: &web_sys::Node) {
+        if !node.is_connected() {
+            return;
+        }
+        self.items.try_update(|items| {
+            let Some(index) = items.iter().position(|(item_id, _)| item_id == &id) else {
+                return;
+            };
+            let item = items.remove(index);
+            let index = items
+                .iter()
+                .position(|(_, item_ref)| {
+                    item_ref.get_untracked().is_some_and(|item_el| {
+                        item_el.is_connected()
+                            && node.compare_document_position(&item_el)
+                                & web_sys::Node::DOCUMENT_POSITION_FOLLOWING
+                                != 0
+                    })
+                })
+                .unwrap_or(items.len());
+            items.insert(index, item);
+        });
+    }
+}
+
+pub(crate) fn use_steps() -> StepsInjection {
+    expect_context()
+}
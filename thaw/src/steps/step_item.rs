@@ -0,0 +1,110 @@
+use super::{use_steps, StepStatus};
+use crate::Icon;
+use leptos::*;
+use thaw_utils::{class_list, OptionalMaybeSignal, OptionalProp};
+use uuid::Uuid;
+
+#[component]
+pub fn StepItem(
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(into)] title: MaybeSignal<String>,
+    #[prop(optional, into)] description: OptionalProp<MaybeSignal<String>>,
+    /// Shown instead of the step number.
+    #[prop(optional, into)]
+    icon: OptionalMaybeSignal<icondata_core::Icon>,
+    /// Overrides the status derived from the current step of `Steps`.
+    #[prop(optional, into)]
+    status: Option<MaybeSignal<StepStatus>>,
+) -> impl IntoView {
+    let steps = use_steps();
+    let id = Uuid::new_v4();
+    let item_ref = create_node_ref::<html::Div>();
+    steps.items.update(|items| items.push((id, item_ref)));
+    on_cleanup(move || {
+        steps
+            .items
+            .update(|items| items.retain(|(item_id, _)| item_id != &id));
+    });
+    // The element is inserted into the document after it is loaded.
+    item_ref.on_load(move |item_el| queue_microtask(move || steps.place_item(id, &item_el)));
+
+    let index = create_memo(move |_| {
+        steps
+            .items
+            .with(|items| items.iter().position(|(item_id, _)| item_id == &id))
+            .unwrap_or_default()
+    });
+    let is_current = create_memo(move |_| steps.current.get() == index.get());
+    let status = create_memo(move |_| {
+        if let Some(status) = status {
+            return status.get();
+        }
+        let (index, current) = (index.get(), steps.current.get());
+        if index < current {
+            StepStatus::Finish
+        } else if index == current {
+            steps.status.get()
+        } else {
+            StepStatus::Wait
+        }
+    });
+
+    let select = move || {
+        if steps.clickable.get_untracked() {
+            steps.current.set(index.get_untracked());
+        }
+    };
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if matches!(ev.key().as_str(), " " | "Enter") {
+            ev.prevent_default();
+            select();
+        }
+    };
+
+    view! {
+        <div
+            class=class_list![
+                "thaw-step-item", move || format!("thaw-step-item--{}", status.get().as_str()),
+                ("thaw-step-item--clickable", move || steps.clickable.get()),
+                class.map(| c | move || c.get())
+            ]
+            role="listitem"
+            aria-current=move || is_current.get().then_some("step")
+            on:click=move |_| select()
+            ref=item_ref
+        >
+            <div class="thaw-step-item__tail"></div>
+            <div class="thaw-step-item__indicator">
+                {move || match (icon.get(), status.get()) {
+                    (Some(icon), _) => view! { <Icon icon/> }.into_view(),
+                    (None, StepStatus::Finish) => {
+                        view! { <Icon icon=icondata_ai::AiCheckOutlined/> }.into_view()
+                    }
+                    (None, StepStatus::Error) => {
+                        view! { <Icon icon=icondata_ai::AiCloseOutlined/> }.into_view()
+                    }
+                    (None, _) => (index.get() + 1).into_view(),
+                }}
+
+            </div>
+            <div
+                class="thaw-step-item__content"
+                role=move || steps.clickable.get().then_some("button")
+                tabindex=move || steps.clickable.get().then_some(0)
+                on:keydown=on_keydown
+            >
+                <div class="thaw-step-item__title">{move || title.get()}</div>
+                {description
+                    .into_option()
+                    .map(|description| {
+                        view! {
+                            <div class="thaw-step-item__description">
+                                {move || description.get()}
+                            </div>
+                        }
+                    })}
+
+            </div>
+        </div>
+    }
+}
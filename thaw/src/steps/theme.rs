@@ -0,0 +1,24 @@
+use crate::theme::ThemeMethod;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StepsTheme {
+    pub wait_color: String,
+    pub line_color: String,
+}
+
+impl ThemeMethod for StepsTheme {
+    fn light() -> Self {
+        Self {
+            wait_color: "#a8a8a8".into(),
+            line_color: "#e5e5e5".into(),
+        }
+    }
+
+    fn dark() -> Self {
+        Self {
+            wait_color: "#6b6b70".into(),
+            line_color: "#48484e".into(),
+        }
+    }
+}
@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::SwitchTheme;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, Model, OptionalProp};
 
@@ -12,29 +12,21 @@ pub fn Switch(
     #[prop(optional, into)] on_change: Option<Callback<bool>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("switch", include_str!("./switch.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.switch.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-active: {};",
-                theme.common.color_primary
-            ));
-        });
-        css_vars
-    });
-    let on_click = move |_| {
+    let toggle = move || {
         let new_value = !value.get_untracked();
         value.set(new_value);
         if let Some(on_change) = on_change {
             on_change.call(new_value);
         }
     };
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if matches!(ev.key().as_str(), " " | "Enter") {
+            ev.prevent_default();
+            toggle();
+        }
+    };
 
     view! {
         <div
@@ -43,9 +35,10 @@ pub fn Switch(
                 c.get())
             ]
 
-            style=move || css_vars.get()
-            on:click=on_click
+            on:click=move |_| toggle()
+            on:keydown=on_keydown
             role="switch"
+            tabindex="0"
             aria-checked=move || if value.get() { "true" } else { "false" }
         >
             <div class="thaw-switch__button"></div>
@@ -0,0 +1,434 @@
+use super::Table;
+use crate::{theme::mount_theme_vars, use_locale, Checkbox, Icon, Scrollbar};
+use leptos::*;
+use std::{cmp::Ordering, collections::HashSet, hash::Hash, rc::Rc};
+use thaw_components::{get_virtual_range, VirtualRange};
+use thaw_utils::{class_list, mount_style, Model, OptionalProp, SignalWatch, StoredMaybeSignal};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TableColumnAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl TableColumnAlign {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Center => "center",
+            Self::Right => "right",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    Ascend,
+    Descend,
+}
+
+impl SortOrder {
+    fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascend => ordering,
+            Self::Descend => ordering.reverse(),
+        }
+    }
+}
+
+type TableColumnSorter<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+pub struct TableColumn<T: 'static> {
+    pub title: String,
+    pub key: String,
+    pub render: Rc<dyn Fn(&T) -> View>,
+    pub width: Option<String>,
+    pub align: TableColumnAlign,
+    pub sorter: Option<TableColumnSorter<T>>,
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            key: self.key.clone(),
+            render: self.render.clone(),
+            width: self.width.clone(),
+            align: self.align,
+            sorter: self.sorter.clone(),
+        }
+    }
+}
+
+impl<T> TableColumn<T> {
+    pub fn new<IV: IntoView>(
+        title: impl Into<String>,
+        key: impl Into<String>,
+        render: impl Fn(&T) -> IV + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            key: key.into(),
+            render: Rc::new(move |row| render(row).into_view()),
+            width: None,
+            align: TableColumnAlign::default(),
+            sorter: None,
+        }
+    }
+
+    pub fn with_width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn with_align(mut self, align: TableColumnAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_sorter(mut self, sorter: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sorter = Some(Rc::new(sorter));
+        self
+    }
+
+    fn cell_style(&self) -> String {
+        let mut style = format!("text-align: {};", self.align.as_str());
+        if let Some(width) = self.width.as_ref() {
+            style.push_str(&format!("width: {width};"));
+        }
+        style
+    }
+}
+
+#[slot]
+pub struct DataTableEmpty {
+    children: ChildrenFn,
+}
+
+#[component]
+pub fn DataTable<T, K, F>(
+    columns: Vec<TableColumn<T>>,
+    #[prop(into)] data: MaybeSignal<Vec<T>>,
+    row_key: F,
+    #[prop(optional, into)] sort: Model<Option<(String, SortOrder)>>,
+    #[prop(optional)] selectable: bool,
+    #[prop(optional, into)] checked_row_keys: Model<HashSet<K>>,
+    #[prop(optional, into)] style: MaybeSignal<String>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(default=true.into(), into)] single_row: MaybeSignal<bool>,
+    #[prop(optional, into)] single_column: MaybeSignal<bool>,
+    #[prop(optional)] virtual_scroll: bool,
+    #[prop(default = 400.0)] max_height: f64,
+    #[prop(default = 47.0)] row_height: f64,
+    #[prop(optional)] data_table_empty: Option<DataTableEmpty>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + Clone + 'static,
+    F: Fn(&T) -> K + Copy + 'static,
+{
+    mount_theme_vars();
+    mount_style("data-table", include_str!("./data-table.css"));
+    let locale = use_locale();
+    let data = StoredMaybeSignal::from(data);
+    let columns = StoredValue::new(columns);
+    // Sorting indices instead of rows keeps the sorted order cached while scrolling.
+    let sorted_indices = Memo::new(move |_| {
+        data.with(|data| {
+            let mut indices: Vec<usize> = (0..data.len()).collect();
+            sort.with(|sort| {
+                let Some((key, order)) = sort else {
+                    return;
+                };
+                let sorter = columns.with_value(|columns| {
+                    columns
+                        .iter()
+                        .find(|column| &column.key == key)
+                        .and_then(|column| column.sorter.clone())
+                });
+                if let Some(sorter) = sorter {
+                    indices.sort_by(|a, b| order.apply(sorter(&data[*a], &data[*b])));
+                }
+            });
+            indices
+        })
+    });
+    let scroll_top = RwSignal::new(0.0);
+    let virtual_range = Memo::new(move |_| {
+        let len = sorted_indices.with(|indices| indices.len());
+        if virtual_scroll {
+            get_virtual_range(len, row_height, scroll_top.get(), max_height, 4)
+        } else {
+            VirtualRange {
+                start: 0,
+                end: len,
+                ..Default::default()
+            }
+        }
+    });
+    let rows = move || {
+        let VirtualRange { start, end, .. } = virtual_range.get();
+        data.with(|data| {
+            sorted_indices.with(|indices| {
+                indices[start..end]
+                    .iter()
+                    .map(|index| data[*index].clone())
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    let on_sort = move |key: String| {
+        sort.update(|sort| {
+            *sort = match sort.take() {
+                Some((prev_key, SortOrder::Ascend)) if prev_key == key => {
+                    Some((key, SortOrder::Descend))
+                }
+                Some((prev_key, SortOrder::Descend)) if prev_key == key => None,
+                _ => Some((key, SortOrder::Ascend)),
+            };
+        });
+    };
+
+    let is_all_checked = Memo::new(move |_| {
+        data.with(|data| {
+            !data.is_empty()
+                && checked_row_keys.with(|keys| data.iter().all(|row| keys.contains(&row_key(row))))
+        })
+    });
+    let all_checked = RwSignal::new(false);
+    let _ = all_checked.watch(move |checked| {
+        let checked = *checked;
+        data.with_untracked(|data| {
+            checked_row_keys.update(|keys| {
+                for row in data.iter() {
+                    if checked {
+                        keys.insert(row_key(row));
+                    } else {
+                        keys.remove(&row_key(row));
+                    }
+                }
+            });
+        });
+    });
+
+    let column_count = columns.with_value(|columns| columns.len()) + usize::from(selectable);
+    let data_table_empty = StoredValue::new(data_table_empty);
+
+    view! {
+        <div
+            class=class_list!["thaw-data-table", class.map(| c | move || c.get())]
+            style=move || style.get()
+        >
+            <DataTableScroll virtual_scroll max_height on_scroll=move |top| scroll_top.set(top)>
+                <Table single_row single_column>
+                    <thead>
+                        <tr>
+                            {selectable
+                                .then(|| {
+                                    view! {
+                                        <th class="thaw-data-table__selection">
+                                            <Checkbox value=(
+                                                Signal::from(is_all_checked),
+                                                all_checked.write_only(),
+                                            )/>
+                                        </th>
+                                    }
+                                })}
+                            {columns
+                                .get_value()
+                                .into_iter()
+                                .map(|column| {
+                                    let style = column.cell_style();
+                                    let TableColumn { title, key, sorter, .. } = column;
+                                    if sorter.is_some() {
+                                        let sort_order = Memo::new({
+                                            let key = key.clone();
+                                            move |_| {
+                                                sort.with(|sort| {
+                                                    sort.as_ref()
+                                                        .and_then(|(sort_key, order)| {
+                                                            (sort_key == &key).then_some(*order)
+                                                        })
+                                                })
+                                            }
+                                        });
+                                        view! {
+                                            <th
+                                                class="thaw-data-table__th--sortable"
+                                                style=style
+                                                on:click=move |_| on_sort(key.clone())
+                                            >
+                                                {title}
+                                                <span
+                                                    class="thaw-data-table__sorter"
+                                                    class=(
+                                                        "thaw-data-table__sorter--ascend",
+                                                        move || sort_order.get() == Some(SortOrder::Ascend),
+                                                    )
+                                                    class=(
+                                                        "thaw-data-table__sorter--descend",
+                                                        move || sort_order.get() == Some(SortOrder::Descend),
+                                                    )
+                                                >
+                                                    <Icon icon=icondata_ai::AiCaretUpFilled/>
+                                                    <Icon icon=icondata_ai::AiCaretDownFilled/>
+                                                </span>
+                                            </th>
+                                        }
+                                    } else {
+                                        view! { <th style=style>{title}</th> }
+                                    }
+                                })
+                                .collect_view()}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        <Show
+                            when=move || sorted_indices.with(|indices| !indices.is_empty())
+                            fallback=move || {
+                                view! {
+                                    <tr>
+                                        <td class="thaw-data-table__empty" colspan=column_count>
+                                            {data_table_empty
+                                                .with_value(|empty| {
+                                                    empty.as_ref().map(|empty| (empty.children)())
+                                                })
+                                                .map(IntoView::into_view)
+                                                .unwrap_or_else(|| (move || locale.with(|locale| locale.no_data.clone())).into_view())}
+                                        </td>
+                                    </tr>
+                                }
+                            }
+                        >
+                            {move || {
+                                let offset_before = virtual_range.get().offset_before;
+                                (offset_before > 0.0)
+                                    .then(|| {
+                                        view! { <DataTableSpacer column_count height=offset_before/> }
+                                    })
+                            }}
+                            <For
+                                each=rows
+                                key=move |row| row_key(row)
+                                children=move |row| {
+                                    view! {
+                                        <DataTableRow
+                                            row
+                                            columns
+                                            row_key
+                                            selectable
+                                            checked_row_keys
+                                            row_height=virtual_scroll.then_some(row_height)
+                                        />
+                                    }
+                                }
+                            />
+                            {move || {
+                                let offset_after = virtual_range.get().offset_after;
+                                (offset_after > 0.0)
+                                    .then(|| {
+                                        view! { <DataTableSpacer column_count height=offset_after/> }
+                                    })
+                            }}
+                        </Show>
+                    </tbody>
+                </Table>
+            </DataTableScroll>
+        </div>
+    }
+}
+
+/// Only tables with `virtual_scroll` need their own scroll container.
+#[component]
+fn DataTableScroll(
+    virtual_scroll: bool,
+    max_height: f64,
+    #[prop(into)] on_scroll: Callback<f64>,
+    children: Children,
+) -> impl IntoView {
+    if virtual_scroll {
+        view! {
+            <Scrollbar
+                class="thaw-data-table__scrollbar"
+                style=format!("max-height: {max_height}px;")
+                content_style="width: 100%;"
+                on_scroll=move |ev: ev::Event| {
+                    let el = event_target::<web_sys::Element>(&ev);
+                    on_scroll.call(f64::from(el.scroll_top()));
+                }
+            >
+                {children()}
+            </Scrollbar>
+        }
+    } else {
+        children().into_view()
+    }
+}
+
+#[component]
+fn DataTableSpacer(column_count: usize, height: f64) -> impl IntoView {
+    view! {
+        <tr class="thaw-data-table__spacer">
+            <td colspan=column_count style=format!("height: {height}px;")></td>
+        </tr>
+    }
+}
+
+#[component]
+fn DataTableRow<T, K, F>(
+    row: T,
+    columns: StoredValue<Vec<TableColumn<T>>>,
+    row_key: F,
+    selectable: bool,
+    checked_row_keys: Model<HashSet<K>>,
+    row_height: Option<f64>,
+) -> impl IntoView
+where
+    T: 'static,
+    K: Eq + Hash + Clone + 'static,
+    F: Fn(&T) -> K + Copy + 'static,
+{
+    let key = StoredValue::new(row_key(&row));
+    let is_checked =
+        Memo::new(move |_| checked_row_keys.with(|keys| key.with_value(|key| keys.contains(key))));
+    let checked = RwSignal::new(false);
+    let _ = checked.watch(move |checked| {
+        checked_row_keys.update(|keys| {
+            if *checked {
+                keys.insert(key.get_value());
+            } else {
+                key.with_value(|key| {
+                    keys.remove(key);
+                });
+            }
+        });
+    });
+
+    view! {
+        <tr
+            class=("thaw-data-table__tr--checked", move || selectable && is_checked.get())
+            style=row_height.map(|height| format!("height: {height}px;"))
+        >
+            {selectable
+                .then(|| {
+                    view! {
+                        <td class="thaw-data-table__selection">
+                            <Checkbox value=(Signal::from(is_checked), checked.write_only())/>
+                        </td>
+                    }
+                })}
+            {columns
+                .with_value(|columns| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            view! { <td style=column.cell_style()>{(column.render)(&row)}</td> }
+                        })
+                        .collect_view()
+                })}
+        </tr>
+    }
+}
@@ -1,8 +1,10 @@
+mod data_table;
 mod theme;
 
+pub use data_table::*;
 pub use theme::TableTheme;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -14,32 +16,8 @@ pub fn Table(
     #[prop(optional, into)] single_column: MaybeSignal<bool>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("table", include_str!("./table.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.table.background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color-striped: {};",
-                theme.table.background_color_striped
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                theme.table.border_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-radius: {};",
-                theme.common.border_radius
-            ));
-        });
-
-        css_vars
-    });
-
     view! {
         <table
             class=class_list![
@@ -48,7 +26,7 @@ pub fn Table(
                 c.get())
             ]
 
-            style=move || format!("{}{}", css_vars.get(), style.get())
+            style=move || style.get()
         >
             {children()}
         </table>
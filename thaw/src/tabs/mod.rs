@@ -2,9 +2,11 @@ mod tab;
 
 pub use tab::*;
 
-use crate::{theme::use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
-use thaw_utils::{class_list, mount_style, Model, OptionalProp};
+use thaw_utils::{
+    class_list, mount_style, use_roving_focus, Model, OptionalProp, RovingFocusOrientation,
+};
 
 #[component]
 pub fn Tabs(
@@ -12,6 +14,7 @@ pub fn Tabs(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("tabs", include_str!("./tabs.css"));
     let tab_options_vec = create_rw_signal(vec![]);
 
@@ -33,18 +36,6 @@ fn TabsInner(
     children: Children,
 ) -> impl IntoView {
     mount_style("tabs", include_str!("./tabs.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let color_primary = theme.common.color_primary.clone();
-            css_vars.push_str(&format!(
-                "--thaw-label-active-background-color: {color_primary};"
-            ));
-        });
-        css_vars
-    });
-
     let label_line = create_rw_signal::<Option<TabsLabelLine>>(None);
     let label_line_style = create_memo(move |_| {
         let mut style = String::new();
@@ -54,15 +45,36 @@ fn TabsInner(
         style
     });
     let label_list_ref = create_node_ref::<html::Div>();
+    let roving_focus = use_roving_focus(
+        label_list_ref,
+        ".thaw-tabs__label",
+        RovingFocusOrientation::Horizontal,
+    )
+    .click_on_focus();
+    // The active tab is reached by `Tab`, or the first one when no tab is active.
+    let tabbable_key = create_memo(move |_| {
+        let active_key = value.get();
+        tab_options_vec.with(|tab_options_vec| {
+            if tab_options_vec.iter().any(|tab| tab.key == active_key) {
+                Some(active_key)
+            } else {
+                tab_options_vec.first().map(|tab| tab.key.clone())
+            }
+        })
+    });
 
     let children = children();
 
     view! {
         <div
             class=class_list!["thaw-tabs", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
         >
-            <div class="thaw-tabs__label-list" ref=label_list_ref role="tablist">
+            <div
+                class="thaw-tabs__label-list"
+                ref=label_list_ref
+                role="tablist"
+                on:keydown=move |ev| roving_focus.on_keydown(ev)
+            >
                 <For
                     each=move || tab_options_vec.get()
                     key=move |v| v.key.clone()
@@ -116,6 +128,17 @@ fn TabsInner(
                                     aria-selected=move || {
                                         if is_active.get() { "true" } else { "false" }
                                     }
+
+                                    tabindex={
+                                        let key = key.clone();
+                                        move || {
+                                            if tabbable_key.with(|tabbable_key| tabbable_key.as_ref() == Some(&key)) {
+                                                "0"
+                                            } else {
+                                                "-1"
+                                            }
+                                        }
+                                    }
                                 >
 
                                     {children}
@@ -137,6 +160,17 @@ fn TabsInner(
                                     aria-selected=move || {
                                         if is_active.get() { "true" } else { "false" }
                                     }
+
+                                    tabindex={
+                                        let key = key.clone();
+                                        move || {
+                                            if tabbable_key.with(|tabbable_key| tabbable_key.as_ref() == Some(&key)) {
+                                                "0"
+                                            } else {
+                                                "-1"
+                                            }
+                                        }
+                                    }
                                 >
 
                                     {if label.is_empty() { key } else { label }}
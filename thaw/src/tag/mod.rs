@@ -2,7 +2,7 @@ mod theme;
 
 pub use theme::TagTheme;
 
-use crate::{theme::use_theme, Icon, Theme};
+use crate::{theme::mount_theme_vars, Icon};
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -16,28 +16,12 @@ pub enum TagVariant {
 }
 
 impl TagVariant {
-    fn theme_font_color(&self, theme: &Theme) -> String {
+    fn as_str(&self) -> &'static str {
         match self {
-            TagVariant::Default => theme.tag.default_font_color.clone(),
-            TagVariant::Success => theme.common.color_success.clone(),
-            TagVariant::Warning => theme.common.color_warning.clone(),
-            TagVariant::Error => theme.common.color_error.clone(),
-        }
-    }
-    fn theme_background_color(&self, theme: &Theme) -> String {
-        match self {
-            TagVariant::Default => theme.tag.default_background_color.clone(),
-            TagVariant::Success => theme.tag.success_background_color.clone(),
-            TagVariant::Warning => theme.tag.warning_background_color.clone(),
-            TagVariant::Error => theme.tag.error_background_color.clone(),
-        }
-    }
-    fn theme_border_color(&self, theme: &Theme) -> String {
-        match self {
-            TagVariant::Default => theme.tag.default_border_color.clone(),
-            TagVariant::Success => theme.tag.success_border_color.clone(),
-            TagVariant::Warning => theme.tag.warning_border_color.clone(),
-            TagVariant::Error => theme.tag.error_border_color.clone(),
+            TagVariant::Default => "default",
+            TagVariant::Success => "success",
+            TagVariant::Warning => "warning",
+            TagVariant::Error => "error",
         }
     }
 }
@@ -50,28 +34,8 @@ pub fn Tag(
     #[prop(optional, into)] on_close: Option<Callback<ev::MouseEvent>>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("tag", include_str!("./tag.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            let variant = variant.get();
-            css_vars.push_str(&format!(
-                "--thaw-font-color: {};",
-                variant.theme_font_color(theme)
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                variant.theme_background_color(theme)
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-border-color: {};",
-                variant.theme_border_color(theme)
-            ));
-        });
-        css_vars
-    });
-
     let on_close = move |event| {
         let Some(callback) = on_close.as_ref() else {
             return;
@@ -81,8 +45,10 @@ pub fn Tag(
 
     view! {
         <div
-            class=class_list!["thaw-tag", class.map(| c | move || c.get())]
-            style=move || css_vars.get()
+            class=class_list![
+                "thaw-tag", move || format!("thaw-tag--{}", variant.get().as_str()), class.map(| c |
+                move || c.get())
+            ]
         >
             <span class="thaw-tag__content">{children()}</span>
 
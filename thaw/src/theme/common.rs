@@ -1,6 +1,7 @@
 use super::ThemeMethod;
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommonTheme {
     pub font_family: String,
     pub font_color: String,
@@ -11,15 +12,19 @@ pub struct CommonTheme {
     pub color_primary: String,
     pub color_primary_hover: String,
     pub color_primary_active: String,
+    pub color_primary_disabled: String,
     pub color_success: String,
     pub color_success_hover: String,
     pub color_success_active: String,
+    pub color_success_disabled: String,
     pub color_warning: String,
     pub color_warning_hover: String,
     pub color_warning_active: String,
+    pub color_warning_disabled: String,
     pub color_error: String,
     pub color_error_hover: String,
     pub color_error_active: String,
+    pub color_error_disabled: String,
 
     pub font_size: String,
     pub font_size_tiny: String,
@@ -56,15 +61,19 @@ impl CommonTheme {
             color_primary: "".into(),
             color_primary_hover: "".into(),
             color_primary_active: "".into(),
+            color_primary_disabled: "".into(),
             color_success: "".into(),
             color_success_hover: "".into(),
             color_success_active: "".into(),
+            color_success_disabled: "".into(),
             color_warning: "".into(),
             color_warning_hover: "".into(),
             color_warning_active: "".into(),
+            color_warning_disabled: "".into(),
             color_error: "".into(),
             color_error_hover: "".into(),
             color_error_active: "".into(),
+            color_error_disabled: "".into(),
 
             font_size: "14px".into(),
             font_size_tiny: "12px".into(),
@@ -101,15 +110,19 @@ impl ThemeMethod for CommonTheme {
             color_primary: "#0078ff".into(),
             color_primary_hover: "#2994ff".into(),
             color_primary_active: "#005ed9".into(),
+            color_primary_disabled: "#80bcff".into(),
             color_success: "#18a058".into(),
             color_success_hover: "#36ad6a".into(),
             color_success_active: "#0c7a43".into(),
+            color_success_disabled: "#8cd0ac".into(),
             color_warning: "#f0a020".into(),
             color_warning_hover: "#fcb040".into(),
             color_warning_active: "#c97c10".into(),
+            color_warning_disabled: "#f8d090".into(),
             color_error: "#d03050".into(),
             color_error_hover: "#de576d".into(),
             color_error_active: "#ab1f3f".into(),
+            color_error_disabled: "#e898a8".into(),
             border_color: "#e5e8eb".into(),
             ..CommonTheme::common()
         }
@@ -122,17 +135,21 @@ impl ThemeMethod for CommonTheme {
             color_primary: "#0078ff".into(),
             color_primary_hover: "#2994ff".into(),
             color_primary_active: "#005ed9".into(),
+            color_primary_disabled: "#0078ff80".into(),
             // color_success: "#63e2b7".into(),
             // color_success_hover: "#7fe7c4".into(),
             color_success: "#18a058".into(),
             color_success_hover: "#36ad6a".into(),
             color_success_active: "#5acea7".into(),
+            color_success_disabled: "#18a05880".into(),
             color_warning: "#f0a020".into(),
             color_warning_hover: "#fcb040".into(),
             color_warning_active: "#e6c260".into(),
+            color_warning_disabled: "#f0a02080".into(),
             color_error: "#d03050".into(),
             color_error_hover: "#de576d".into(),
             color_error_active: "#e57272".into(),
+            color_error_disabled: "#d0305080".into(),
             border_color: "#1f2537".into(),
             ..CommonTheme::common()
         }
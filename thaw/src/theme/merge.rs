@@ -0,0 +1,57 @@
+use super::Theme;
+use serde_json::Value;
+
+impl Theme {
+    /// Layers a partial theme over this one, for example a JSON document
+    /// that only contains the overridden fields.
+    ///
+    /// ```ignore
+    /// let partial = serde_json::from_str(r##"{ "common": { "color_primary": "#722ed1" } }"##)?;
+    /// let theme = Theme::light().merge(partial)?;
+    /// ```
+    pub fn merge(self, partial: Value) -> serde_json::Result<Self> {
+        let mut value = serde_json::to_value(self)?;
+        merge_value(&mut value, partial);
+        serde_json::from_value(value)
+    }
+}
+
+fn merge_value(base: &mut Value, partial: Value) {
+    match (base, partial) {
+        (Value::Object(base), Value::Object(partial)) => {
+            for (key, value) in partial {
+                match base.get_mut(&key) {
+                    Some(base) => merge_value(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, partial) => *base = partial,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+    use serde_json::json;
+
+    #[test]
+    fn merge() {
+        let theme = Theme::dark()
+            .merge(json!({
+                "common": { "color_primary": "#722ed1" },
+                "button": { "border_color_outlined": "#000" }
+            }))
+            .unwrap();
+        assert_eq!(theme.name, "dark");
+        assert_eq!(theme.common.color_primary, "#722ed1");
+        assert_eq!(theme.common.color_scheme, "dark");
+        assert_eq!(theme.button.border_color_outlined, "#000");
+
+        assert!(Theme::light()
+            .merge(json!({ "common": { "color_primary": 1 } }))
+            .is_err());
+    }
+}
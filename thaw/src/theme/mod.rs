@@ -1,15 +1,24 @@
 mod common;
+#[cfg(feature = "serde")]
+mod merge;
+mod mode;
+mod seed;
 
 use self::common::CommonTheme;
+pub use self::mode::{use_theme_mode, ThemeMode};
+pub use self::seed::ThemeSeed;
 use crate::{
     mobile::{NavBarTheme, TabbarTheme},
+    modal::ModalManagerInjection,
     AlertTheme, AnchorTheme, AutoCompleteTheme, AvatarTheme, BackTopTheme, BreadcrumbTheme,
     ButtonTheme, CalendarTheme, CollapseTheme, ColorPickerTheme, DatePickerTheme, DropdownTheme,
-    InputTheme, MenuTheme, MessageTheme, PopoverTheme, ProgressTheme, ScrollbarTheme, SelectTheme,
-    SkeletionTheme, SliderTheme, SpinnerTheme, SwitchTheme, TableTheme, TagTheme, TimePickerTheme,
-    TypographyTheme, UploadTheme,
+    InputTheme, MenuTheme, MessageTheme, NotificationTheme, PopoverTheme, ProgressTheme,
+    ScrollbarTheme, SelectTheme, SkeletionTheme, SliderTheme, SpinnerTheme, StepsTheme,
+    SwitchTheme, TableTheme, TagTheme, TimePickerTheme, TypographyTheme, UploadTheme,
 };
 use leptos::*;
+use std::sync::OnceLock;
+use thaw_utils::{mount_style, ThemeVarsInjection};
 
 pub trait ThemeMethod {
     fn light() -> Self;
@@ -17,6 +26,7 @@ pub trait ThemeMethod {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Theme {
     pub name: String,
     pub common: CommonTheme,
@@ -29,6 +39,7 @@ pub struct Theme {
     pub tag: TagTheme,
     pub avatar: AvatarTheme,
     pub message: MessageTheme,
+    pub notification: NotificationTheme,
     pub select: SelectTheme,
     pub slider: SliderTheme,
     pub switch: SwitchTheme,
@@ -50,6 +61,7 @@ pub struct Theme {
     pub scrollbar: ScrollbarTheme,
     pub back_top: BackTopTheme,
     pub anchor: AnchorTheme,
+    pub steps: StepsTheme,
 }
 
 impl Theme {
@@ -66,6 +78,7 @@ impl Theme {
             tag: TagTheme::light(),
             avatar: AvatarTheme::light(),
             message: MessageTheme::light(),
+            notification: NotificationTheme::light(),
             select: SelectTheme::light(),
             slider: SliderTheme::light(),
             switch: SwitchTheme::light(),
@@ -87,6 +100,7 @@ impl Theme {
             scrollbar: ScrollbarTheme::light(),
             back_top: BackTopTheme::light(),
             anchor: AnchorTheme::light(),
+            steps: StepsTheme::light(),
         }
     }
     pub fn dark() -> Self {
@@ -102,6 +116,7 @@ impl Theme {
             tag: TagTheme::dark(),
             avatar: AvatarTheme::dark(),
             message: MessageTheme::dark(),
+            notification: NotificationTheme::dark(),
             select: SelectTheme::dark(),
             slider: SliderTheme::dark(),
             switch: SwitchTheme::dark(),
@@ -123,10 +138,116 @@ impl Theme {
             scrollbar: ScrollbarTheme::dark(),
             back_top: BackTopTheme::dark(),
             anchor: AnchorTheme::dark(),
+            steps: StepsTheme::dark(),
         }
     }
 }
 
+macro_rules! push_css_vars {
+    ($css_vars:ident, $theme:ident, $($group:ident: [$($field:ident),* $(,)?]),* $(,)?) => {
+        $($(
+            push_css_var(
+                &mut $css_vars,
+                stringify!($group),
+                stringify!($field),
+                &$theme.$group.$field,
+            );
+        )*)*
+    };
+}
+
+fn push_css_var(css_vars: &mut String, group: &str, field: &str, value: &str) {
+    css_vars.push_str("--thaw-");
+    css_vars.push_str(&group.replace('_', "-"));
+    css_vars.push('-');
+    css_vars.push_str(&field.replace('_', "-"));
+    css_vars.push_str(": ");
+    css_vars.push_str(value);
+    css_vars.push(';');
+}
+
+impl Theme {
+    /// The design tokens of the theme as CSS custom properties,
+    /// `theme.<group>.<field>` becomes `--thaw-<group>-<field>` in kebab case.
+    pub fn css_vars(&self) -> String {
+        let theme = self;
+        let mut css_vars = String::new();
+        push_css_vars!(css_vars, theme,
+            common: [
+                font_family, font_color, background_color, border_color, color_scheme,
+                color_primary, color_primary_hover, color_primary_active, color_primary_disabled,
+                color_success, color_success_hover, color_success_active, color_success_disabled,
+                color_warning, color_warning_hover, color_warning_active, color_warning_disabled,
+                color_error, color_error_hover, color_error_active, color_error_disabled,
+                font_size, font_size_tiny, font_size_small, font_size_medium, font_size_large,
+                font_size_huge,
+                height_tiny, height_small, height_medium, height_large,
+                line_height, line_height_small, line_height_medium, line_height_large,
+                line_height_huge,
+                border_radius, border_radius_small, border_radius_medium, border_radius_large,
+            ],
+            button: [
+                padding_tiny, padding_small, padding_medium, padding_large,
+                border_color_outlined, color_text_hover, color_text_active, color_text_disabled,
+                color_background_disabled, color_border_disabled,
+            ],
+            input: [
+                font_color, placeholder_color, border_color, background_color,
+                font_color_disabled, background_color_disabled,
+            ],
+            menu: [color, item_color_hover, group_color],
+            table: [background_color, background_color_striped, border_color],
+            alert: [
+                success_background_color, success_border_color,
+                warning_background_color, warning_border_color,
+                error_background_color, error_border_color,
+            ],
+            skeletion: [background_color_start, background_color_end],
+            tag: [
+                default_font_color, default_background_color, default_border_color,
+                success_background_color, success_border_color,
+                warning_background_color, warning_border_color,
+                error_background_color, error_border_color,
+            ],
+            avatar: [background_color],
+            message: [background_color],
+            notification: [background_color],
+            select: [
+                font_color, border_color, background_color,
+                menu_background_color, menu_background_color_hover,
+            ],
+            slider: [background_color],
+            switch: [background_color],
+            spinner: [background_color],
+            upload: [dragger_background_color, dragger_border_color],
+            nav_bar: [background_color],
+            tabbar: [background_color],
+            auto_complete: [menu_background_color, menu_background_color_hover],
+            color_picker: [popover_background_color],
+            breadcrumb: [item_font_color, item_font_color_hover, item_background_color_hover],
+            progress: [background_color],
+            typograph: [code_background_color],
+            calendar: [border_color, other_month_font_color, background_color_hover],
+            time_picker: [
+                panel_background_color, panel_time_item_background_color_hover,
+                panel_border_color,
+            ],
+            date_picker: [
+                panel_background_color, panel_date_item_background_color_hover,
+                panel_border_color, panel_other_month_font_color,
+            ],
+            popover: [background_color, tooltip_background_color],
+            dropdown: [background_color, item_color_hover, font_color_disabled],
+            collapse: [border_color],
+            scrollbar: [background_color, background_color_hover],
+            back_top: [background_color],
+            anchor: [rail_background_color],
+            steps: [wait_color, line_color],
+        );
+        css_vars
+    }
+}
+
 impl ThemeMethod for Theme {
     fn light() -> Self {
         Theme::light()
@@ -136,18 +257,74 @@ impl ThemeMethod for Theme {
     }
 }
 
+/// Provides the theme and emits its design tokens.
+///
+/// The outermost provider declares the tokens on `:root`, a nested one scopes
+/// them to its subtree and to the popups opened inside of it.
+///
+/// With a `mode` the theme is rebuilt by `mode_theme` whenever the mode changes,
+/// a `storage_key` restores and persists the mode in `localStorage` and in a
+/// cookie the server reads. Without a `mode_theme` the theme switches between
+/// `Theme::light()` and `Theme::dark()`, unless a `theme` was passed, which
+/// is then left as it is.
 #[component]
 pub fn ThemeProvider(
     #[prop(optional, into)] theme: Option<RwSignal<Theme>>,
+    #[prop(optional, into)] mode: Option<RwSignal<ThemeMode>>,
+    /// Builds the theme of the mode, called with `true` for the dark mode,
+    /// e.g. with `Theme::from_seed_dark` or `Theme::merge`.
+    #[prop(optional, into)]
+    mode_theme: Option<Callback<bool, Theme>>,
+    #[prop(optional, into)] storage_key: Option<String>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
+    let mode_theme = match (&theme, mode_theme) {
+        (_, Some(mode_theme)) => Some(mode_theme),
+        (None, None) => Some(Callback::new(mode::default_theme_of)),
+        (Some(_), None) => None,
+    };
     let theme = if let Some(theme) = theme {
         theme
     } else {
         create_rw_signal(Theme::light())
     };
+    if let Some(mode) = mode {
+        mode::sync_theme_mode(theme, mode, mode_theme, storage_key);
+        provide_context(mode);
+    }
+    let is_nested = use_context::<RwSignal<Theme>>().is_some();
+    ModalManagerInjection::provide();
+
+    view! {
+        <Provider value=theme>
+            {if is_nested {
+                let css_vars = Signal::derive(move || theme.with(Theme::css_vars));
+                provide_context(ThemeVarsInjection(css_vars));
+                view! {
+                    <div class="thaw-theme-provider" style=move || css_vars.get()>
+                        {children()}
+                    </div>
+                }
+                    .into_view()
+            } else {
+                view! {
+                    <style>{move || theme.with(|theme| format!(":root {{{}}}", theme.css_vars()))}</style>
+                    {children()}
+                }
+                    .into_view()
+            }}
+
+        </Provider>
+    }
+}
 
-    view! { <Provider value=theme children/> }
+/// Declares the tokens of the light theme on `:root`, so the components are
+/// styled without a `ThemeProvider` too.
+pub(crate) fn mount_theme_vars() {
+    static LIGHT_CSS_VARS: OnceLock<String> = OnceLock::new();
+    let css = LIGHT_CSS_VARS.get_or_init(|| format!(":root {{{}}}", Theme::light().css_vars()));
+    mount_style("theme", css);
 }
 
 pub fn use_theme(default: impl Fn() -> Theme) -> ReadSignal<Theme> {
@@ -168,4 +345,12 @@ mod tests {
     fn _t_use_theme() {
         use_theme(Theme::dark);
     }
+
+    #[test]
+    fn css_vars() {
+        let css_vars = Theme::light().css_vars();
+        assert!(css_vars.starts_with("--thaw-common-font-family: "));
+        assert!(css_vars.contains("--thaw-common-color-primary: #0078ff;"));
+        assert!(css_vars.contains("--thaw-date-picker-panel-other-month-font-color: "));
+    }
 }
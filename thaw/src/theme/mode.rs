@@ -0,0 +1,207 @@
+use super::Theme;
+use leptos::*;
+
+/// Which of the light and dark themes a `ThemeProvider` uses.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeMode {
+    #[default]
+    Light,
+    Dark,
+    /// Follows the `prefers-color-scheme` of the user.
+    System,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::System => "system",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            "system" => Some(Self::System),
+            _ => None,
+        }
+    }
+}
+
+/// The mode of the closest `ThemeProvider` that was given one.
+pub fn use_theme_mode() -> Option<RwSignal<ThemeMode>> {
+    use_context::<RwSignal<ThemeMode>>()
+}
+
+/// Keeps `theme` in line with `mode` when `theme_of` is given, restoring and
+/// persisting the mode under `storage_key` when given.
+pub(super) fn sync_theme_mode(
+    theme: RwSignal<Theme>,
+    mode: RwSignal<ThemeMode>,
+    theme_of: Option<Callback<bool, Theme>>,
+    storage_key: Option<String>,
+) {
+    if let Some(stored) = storage_key
+        .as_deref()
+        .and_then(read_stored_mode)
+        .and_then(|name| ThemeMode::from_name(&name))
+    {
+        mode.set_untracked(stored);
+    }
+    let prefers_dark = use_prefers_dark();
+    let is_dark = create_memo(move |_| match mode.get() {
+        ThemeMode::Light => false,
+        ThemeMode::Dark => true,
+        ThemeMode::System => prefers_dark.get(),
+    });
+    if let Some(theme_of) = theme_of {
+        theme.set_untracked(theme_of.call(is_dark.get_untracked()));
+        create_effect(move |prev: Option<()>| {
+            let is_dark = is_dark.get();
+            if prev.is_some() {
+                theme.set(theme_of.call(is_dark));
+            }
+        });
+    }
+
+    if let Some(key) = storage_key {
+        create_effect(move |_| store_mode(&key, mode.get()));
+    }
+}
+
+/// The theme of a mode when `ThemeProvider` was given neither a `theme` nor a `mode_theme`.
+pub(super) fn default_theme_of(is_dark: bool) -> Theme {
+    if is_dark {
+        Theme::dark()
+    } else {
+        Theme::light()
+    }
+}
+
+fn use_prefers_dark() -> Signal<bool> {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        use thaw_utils::add_event_listener_with_bool;
+        use web_sys::EventTarget;
+
+        let Ok(Some(media)) = window().match_media("(prefers-color-scheme: dark)") else {
+            return Signal::derive(|| false);
+        };
+        let prefers_dark = create_rw_signal(media.matches());
+        let target: EventTarget = media.clone().into();
+        let handle = add_event_listener_with_bool(
+            target,
+            ev::Custom::<web_sys::Event>::new("change"),
+            move |_| prefers_dark.set(media.matches()),
+            false,
+        );
+        on_cleanup(move || handle.remove());
+        prefers_dark.into()
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    {
+        // Only sent by browsers when the server asked for it with
+        // `Accept-CH: Sec-CH-Prefers-Color-Scheme`.
+        let prefers_dark = request_header("sec-ch-prefers-color-scheme")
+            .is_some_and(|scheme| scheme.trim_matches('"') == "dark");
+        Signal::derive(move || prefers_dark)
+    }
+}
+
+fn read_stored_mode(key: &str) -> Option<String> {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        let stored = window()
+            .local_storage()
+            .ok()
+            .flatten()
+            .and_then(|storage| storage.get_item(key).ok().flatten());
+        if stored.is_some() {
+            return stored;
+        }
+    }
+    read_cookie(key)
+}
+
+fn store_mode(key: &str, mode: ThemeMode) {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        use wasm_bindgen::JsCast;
+
+        if let Ok(Some(storage)) = window().local_storage() {
+            _ = storage.set_item(key, mode.as_str());
+        }
+        let document = document().unchecked_into::<web_sys::HtmlDocument>();
+        _ = document.set_cookie(&format!(
+            "{key}={}; path=/; max-age=31536000; SameSite=Lax",
+            mode.as_str()
+        ));
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    {
+        _ = (key, mode);
+    }
+}
+
+fn read_cookie(key: &str) -> Option<String> {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    let cookies = {
+        use wasm_bindgen::JsCast;
+
+        document()
+            .unchecked_into::<web_sys::HtmlDocument>()
+            .cookie()
+            .ok()
+    };
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    let cookies = request_header("cookie");
+
+    cookies.and_then(|cookies| cookie_value(&cookies, key).map(String::from))
+}
+
+/// A header of the request being rendered, read from the `http::request::Parts`
+/// the server integration provides as context.
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn request_header(name: &str) -> Option<String> {
+    #[cfg(feature = "ssr")]
+    {
+        let parts = use_context::<http::request::Parts>()?;
+        let value = parts.headers.get(name)?.to_str().ok()?;
+        Some(value.to_string())
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        _ = name;
+        None
+    }
+}
+
+fn cookie_value<'a>(cookies: &'a str, key: &str) -> Option<&'a str> {
+    cookies.split(';').find_map(|cookie| {
+        let (name, value) = cookie.split_once('=')?;
+        (name.trim() == key).then(|| value.trim())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cookie_value, ThemeMode};
+
+    #[test]
+    fn theme_mode_name() {
+        for mode in [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System] {
+            assert_eq!(ThemeMode::from_name(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ThemeMode::from_name("sepia"), None);
+    }
+
+    #[test]
+    fn cookie() {
+        let cookies = "a=1; thaw-theme-mode=dark;b=2";
+        assert_eq!(cookie_value(cookies, "thaw-theme-mode"), Some("dark"));
+        assert_eq!(cookie_value(cookies, "b"), Some("2"));
+        assert_eq!(cookie_value(cookies, "theme-mode"), None);
+    }
+}
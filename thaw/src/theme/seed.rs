@@ -0,0 +1,211 @@
+use super::Theme;
+use palette::{Darken, FromColor, Hsl, Lighten, Mix, Srgb};
+use std::str::FromStr;
+
+/// The colors a whole theme is derived from by [`Theme::from_seed_light`]
+/// and [`Theme::from_seed_dark`].
+///
+/// Colors are hex codes (`#rrggbb` or `#rgb`).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThemeSeed {
+    pub primary: String,
+    pub success: String,
+    pub warning: String,
+    pub error: String,
+}
+
+impl Default for ThemeSeed {
+    fn default() -> Self {
+        Self {
+            primary: "#0078ff".into(),
+            success: "#18a058".into(),
+            warning: "#f0a020".into(),
+            error: "#d03050".into(),
+        }
+    }
+}
+
+impl Theme {
+    /// Builds a light theme whose hover, active, disabled and tinted colors
+    /// are all derived from the seed colors.
+    ///
+    /// A seed color that can't be parsed is used as is for all of its shades.
+    pub fn from_seed_light(seed: &ThemeSeed) -> Self {
+        Self::from_seed(seed, false)
+    }
+
+    /// The dark variant of [`Theme::from_seed_light`].
+    pub fn from_seed_dark(seed: &ThemeSeed) -> Self {
+        Self::from_seed(seed, true)
+    }
+
+    fn from_seed(seed: &ThemeSeed, dark: bool) -> Self {
+        let mut theme = if dark { Theme::dark() } else { Theme::light() };
+        let primary = SeedColor::new(&seed.primary);
+        let success = SeedColor::new(&seed.success);
+        let warning = SeedColor::new(&seed.warning);
+        let error = SeedColor::new(&seed.error);
+
+        let common = &mut theme.common;
+        common.color_primary_hover = primary.hover();
+        common.color_primary_active = primary.active();
+        common.color_primary_disabled = primary.disabled(dark);
+        common.color_primary = primary.hex();
+        common.color_success_hover = success.hover();
+        common.color_success_active = success.active();
+        common.color_success_disabled = success.disabled(dark);
+        common.color_success = success.hex();
+        common.color_warning_hover = warning.hover();
+        common.color_warning_active = warning.active();
+        common.color_warning_disabled = warning.disabled(dark);
+        common.color_warning = warning.hex();
+        common.color_error_hover = error.hover();
+        common.color_error_active = error.active();
+        common.color_error_disabled = error.disabled(dark);
+        common.color_error = error.hex();
+
+        let alert = &mut theme.alert;
+        alert.success_background_color = success.background(dark, 0x40);
+        alert.success_border_color = success.border(dark, 0x59);
+        alert.warning_background_color = warning.background(dark, 0x40);
+        alert.warning_border_color = warning.border(dark, 0x59);
+        alert.error_background_color = error.background(dark, 0x40);
+        alert.error_border_color = error.border(dark, 0x59);
+
+        let tag = &mut theme.tag;
+        tag.success_background_color = success.background(dark, 0);
+        tag.success_border_color = success.border(dark, 0x4d);
+        tag.warning_background_color = warning.background(dark, 0);
+        tag.warning_border_color = warning.border(dark, 0x4d);
+        tag.error_background_color = error.background(dark, 0);
+        tag.error_border_color = error.border(dark, 0x4d);
+
+        // The hover and active backgrounds of items are tinted with the primary color.
+        theme.button.color_text_hover = primary.tint(dark, 0.06, 0x1a);
+        theme.button.color_text_active = primary.tint(dark, 0.1, 0x26);
+        let item_hover = primary.tint(dark, 0.05, 0x17);
+        theme.menu.item_color_hover = item_hover.clone();
+        theme.select.menu_background_color_hover = item_hover.clone();
+        theme.auto_complete.menu_background_color_hover = item_hover.clone();
+        theme.dropdown.item_color_hover = item_hover.clone();
+        theme.calendar.background_color_hover = item_hover.clone();
+        theme.breadcrumb.item_background_color_hover = item_hover.clone();
+        theme.date_picker.panel_date_item_background_color_hover = item_hover.clone();
+        theme.time_picker.panel_time_item_background_color_hover = item_hover;
+
+        theme
+    }
+}
+
+struct SeedColor {
+    raw: String,
+    rgb: Option<Srgb>,
+}
+
+impl SeedColor {
+    fn new(raw: &str) -> Self {
+        let rgb = Srgb::<u8>::from_str(raw.trim())
+            .ok()
+            .map(|rgb| rgb.into_format());
+        Self {
+            raw: raw.to_string(),
+            rgb,
+        }
+    }
+
+    fn hex(&self) -> String {
+        match self.rgb {
+            Some(rgb) => to_hex(rgb),
+            None => self.raw.clone(),
+        }
+    }
+
+    fn hover(&self) -> String {
+        self.map(|rgb| Srgb::from_color(Hsl::from_color(rgb).lighten_fixed(0.08)))
+    }
+
+    fn active(&self) -> String {
+        self.map(|rgb| Srgb::from_color(Hsl::from_color(rgb).darken_fixed(0.08)))
+    }
+
+    fn disabled(&self, dark: bool) -> String {
+        self.tint(dark, 0.5, 0x80)
+    }
+
+    /// Light themes use a faint tint of the color, dark themes the color
+    /// itself with `alpha`.
+    fn background(&self, dark: bool, alpha: u8) -> String {
+        self.tint(dark, 0.08, alpha)
+    }
+
+    fn border(&self, dark: bool, alpha: u8) -> String {
+        self.tint(dark, 0.25, alpha)
+    }
+
+    fn tint(&self, dark: bool, factor: f32, alpha: u8) -> String {
+        match self.rgb {
+            Some(rgb) if dark => format!("{}{:02x}", to_hex(rgb), alpha),
+            Some(rgb) => to_hex(Srgb::new(1.0, 1.0, 1.0).mix(rgb, factor)),
+            None => self.raw.clone(),
+        }
+    }
+
+    fn map(&self, f: impl FnOnce(Srgb) -> Srgb) -> String {
+        match self.rgb {
+            Some(rgb) => to_hex(f(rgb)),
+            None => self.raw.clone(),
+        }
+    }
+}
+
+fn to_hex(rgb: Srgb) -> String {
+    format!("#{:x}", rgb.into_format::<u8>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Theme, ThemeSeed};
+
+    #[test]
+    fn from_seed() {
+        let seed = ThemeSeed::default();
+        let theme = Theme::from_seed_light(&seed);
+        assert_eq!(theme.name, "light");
+        assert_eq!(theme.common.color_primary, "#0078ff");
+        assert_eq!(theme.common.color_primary_hover, "#298eff");
+        assert_eq!(theme.common.color_primary_active, "#0065d6");
+        assert_eq!(theme.common.color_primary_disabled, "#80bcff");
+        let base = Theme::light().common;
+        for (derived, base) in [
+            (
+                &theme.common.color_success_disabled,
+                base.color_success_disabled,
+            ),
+            (
+                &theme.common.color_warning_disabled,
+                base.color_warning_disabled,
+            ),
+            (
+                &theme.common.color_error_disabled,
+                base.color_error_disabled,
+            ),
+        ] {
+            assert_eq!(derived, &base);
+        }
+        assert_eq!(theme.alert.success_background_color, "#edf7f2");
+        assert_eq!(theme.alert.success_border_color, "#c5e7d5");
+        assert_eq!(theme.select.menu_background_color_hover, "#f2f8ff");
+
+        let theme = Theme::from_seed_dark(&ThemeSeed {
+            primary: "rebeccapurple".into(),
+            ..seed
+        });
+        assert_eq!(theme.name, "dark");
+        assert_eq!(theme.common.color_primary_hover, "rebeccapurple");
+        assert_eq!(theme.common.color_success_disabled, "#18a05880");
+        assert_eq!(theme.tag.success_background_color, "#18a05800");
+        assert_eq!(theme.tag.success_border_color, "#18a0584d");
+        assert_eq!(theme.button.color_text_hover, "rebeccapurple");
+    }
+}
@@ -3,8 +3,8 @@ mod theme;
 pub use theme::TimePickerTheme;
 
 use crate::{
-    use_theme, Button, ButtonSize, ButtonVariant, Icon, Input, InputSuffix, Scrollbar,
-    ScrollbarRef, SignalWatch, Theme,
+    locale::can_format, theme::mount_theme_vars, use_locale, Button, ButtonSize, ButtonVariant,
+    Icon, Input, InputSuffix, Scrollbar, ScrollbarRef, SignalWatch,
 };
 use chrono::{Local, NaiveTime, Timelike};
 use leptos::*;
@@ -15,35 +15,79 @@ use thaw_utils::{mount_style, ComponentRef, Model, OptionalProp};
 pub fn TimePicker(
     #[prop(optional, into)] value: Model<Option<NaiveTime>>,
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// Used to show and to parse the time, see `chrono::format::strftime`.
+    /// Defaults to the time format of the locale, which is also used for an invalid format.
+    #[prop(optional, into)]
+    format: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] min: MaybeSignal<Option<NaiveTime>>,
+    #[prop(optional, into)] max: MaybeSignal<Option<NaiveTime>>,
+    #[prop(default = 1)] hour_step: u32,
+    #[prop(default = 1)] minute_step: u32,
+    #[prop(default = 1)] second_step: u32,
+    /// Returning `true` makes the hour unselectable.
+    #[prop(optional, into)]
+    is_hour_disabled: Option<Callback<u32, bool>>,
+    /// Returning `true` makes the minute unselectable.
+    #[prop(optional, into)]
+    is_minute_disabled: Option<Callback<u32, bool>>,
+    /// Returning `true` makes the second unselectable.
+    #[prop(optional, into)]
+    is_second_disabled: Option<Callback<u32, bool>>,
     #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("time-picker", include_str!("./time-picker.css"));
     let time_picker_ref = create_node_ref::<html::Div>();
-    let panel_ref = ComponentRef::<PanelRef>::default();
+    let panel_ref = ComponentRef::<TimePanelColumnsRef>::default();
     let is_show_panel = create_rw_signal(false);
-    let show_time_format = "%H:%M:%S";
+    let locale = use_locale();
+    // An invalid format falls back to the one of the locale instead of panicking.
+    let format = create_memo(move |_| {
+        format
+            .as_ref()
+            .map(|format| format.get())
+            .filter(|format| can_format(NaiveTime::default().format(format)))
+            .unwrap_or_else(|| locale.with(|locale| locale.time_format.clone()))
+    });
+    let limits = TimeLimits {
+        min: Signal::derive(move || min.get()),
+        max: Signal::derive(move || max.get()),
+        is_hour_disabled,
+        is_minute_disabled,
+        is_second_disabled,
+    };
+    let steps = TimeSteps {
+        hour: hour_step,
+        minute: minute_step,
+        second: second_step,
+    };
     let show_time_text = create_rw_signal(String::new());
     let update_show_time_text = move || {
         value.with_untracked(move |time| {
             let text = time.as_ref().map_or(String::new(), |time| {
-                time.format(show_time_format).to_string()
+                format.with_untracked(|format| time.format(format).to_string())
             });
             show_time_text.set(text);
         });
     };
     update_show_time_text();
+    create_effect(move |_| {
+        format.track();
+        update_show_time_text();
+    });
     let panel_selected_time = create_rw_signal(None::<NaiveTime>);
     _ = panel_selected_time.watch(move |time| {
         let text = time.as_ref().map_or(String::new(), |time| {
-            time.format(show_time_format).to_string()
+            format.with_untracked(|format| time.format(format).to_string())
         });
         show_time_text.set(text);
     });
 
     let on_input_blur = Callback::new(move |_| {
-        if let Ok(time) =
-            NaiveTime::parse_from_str(&show_time_text.get_untracked(), show_time_format)
-        {
+        let time = format.with_untracked(|format| {
+            NaiveTime::parse_from_str(&show_time_text.get_untracked(), format).ok()
+        });
+        if let Some(time) = time.filter(|time| !limits.is_time_disabled(*time)) {
             if value.get_untracked() != Some(time) {
                 value.set(Some(time));
                 update_show_time_text();
@@ -88,6 +132,8 @@ pub fn TimePicker(
                     time_picker_ref
                     is_show_panel
                     comp_ref=panel_ref
+                    limits
+                    steps
                 />
             </Follower>
         </Binder>
@@ -100,31 +146,11 @@ fn Panel(
     time_picker_ref: NodeRef<html::Div>,
     close_panel: Callback<Option<NaiveTime>>,
     #[prop(into)] is_show_panel: MaybeSignal<bool>,
-    comp_ref: ComponentRef<PanelRef>,
+    comp_ref: ComponentRef<TimePanelColumnsRef>,
+    limits: TimeLimits,
+    steps: TimeSteps,
 ) -> impl IntoView {
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-item-font-color: {};",
-                theme.common.color_primary
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {};",
-                theme.time_picker.panel_background_color
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-item-background-color-hover: {};",
-                theme.time_picker.panel_time_item_background_color_hover
-            ));
-            css_vars.push_str(&format!(
-                "--thaw-item-border-color: {};",
-                theme.time_picker.panel_border_color
-            ));
-        });
-        css_vars
-    });
+    let locale = use_locale();
     let now = Callback::new(move |_| {
         close_panel.call(Some(now_time()));
     });
@@ -165,15 +191,6 @@ fn Panel(
         _ = panel_ref;
     }
 
-    let hour_ref = ComponentRef::<ScrollbarRef>::new();
-    let minute_ref = ComponentRef::<ScrollbarRef>::new();
-    let second_ref = ComponentRef::<ScrollbarRef>::new();
-    comp_ref.load(PanelRef {
-        hour_ref,
-        minute_ref,
-        second_ref,
-    });
-
     view! {
         <CSSTransition
             node_ref=panel_ref
@@ -184,113 +201,27 @@ fn Panel(
         >
             <div
                 class="thaw-time-picker-panel"
-                style=move || display.get().map(|d| d.to_string()).unwrap_or_else(|| css_vars.get())
+                style=move || display.get()
                 ref=panel_ref
             >
-                <div class="thaw-time-picker-panel__time">
-                    <div class="thaw-time-picker-panel__time-hour">
-                        <Scrollbar size=6 comp_ref=hour_ref>
-                            {(0..24)
-                                .map(|hour| {
-                                    let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
-                                    let on_click = move |_| {
-                                        selected_time
-                                            .update(move |time| {
-                                                *time = if let Some(time) = time {
-                                                    time.with_hour(hour)
-                                                } else {
-                                                    NaiveTime::from_hms_opt(hour, 0, 0)
-                                                }
-                                            });
-                                        comp_ref.get_untracked().unwrap().scroll_into_view();
-                                    };
-                                    let is_selected = Memo::new(move |_| {
-                                        selected_time.get().map_or(false, |v| v.hour() == hour)
-                                    });
-                                    view! {
-                                        <PanelTimeItem
-                                            value=hour
-                                            on:click=on_click
-                                            is_selected
-                                            comp_ref
-                                        />
-                                    }
-                                })
-                                .collect_view()}
-                            <div class="thaw-time-picker-panel__time-padding"></div>
-                        </Scrollbar>
-                    </div>
-                    <div class="thaw-time-picker-panel__time-minute">
-                        <Scrollbar size=6 comp_ref=minute_ref>
-                            {(0..60)
-                                .map(|minute| {
-                                    let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
-                                    let on_click = move |_| {
-                                        selected_time
-                                            .update(move |time| {
-                                                *time = if let Some(time) = time {
-                                                    time.with_minute(minute)
-                                                } else {
-                                                    NaiveTime::from_hms_opt(now_time().hour(), minute, 0)
-                                                }
-                                            });
-                                        comp_ref.get_untracked().unwrap().scroll_into_view();
-                                    };
-                                    let is_selected = Memo::new(move |_| {
-                                        selected_time.get().map_or(false, |v| v.minute() == minute)
-                                    });
-                                    view! {
-                                        <PanelTimeItem
-                                            value=minute
-                                            on:click=on_click
-                                            is_selected
-                                            comp_ref
-                                        />
-                                    }
-                                })
-                                .collect_view()}
-                            <div class="thaw-time-picker-panel__time-padding"></div>
-                        </Scrollbar>
-                    </div>
-                    <div class="thaw-time-picker-panel__time-second">
-                        <Scrollbar size=6 comp_ref=second_ref>
-                            {(0..60)
-                                .map(|second| {
-                                    let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
-                                    let on_click = move |_| {
-                                        selected_time
-                                            .update(move |time| {
-                                                *time = if let Some(time) = time {
-                                                    time.with_second(second)
-                                                } else {
-                                                    now_time().with_second(second)
-                                                }
-                                            });
-                                        comp_ref.get_untracked().unwrap().scroll_into_view();
-                                    };
-                                    let is_selected = Memo::new(move |_| {
-                                        selected_time.get().map_or(false, |v| v.second() == second)
-                                    });
-                                    view! {
-                                        <PanelTimeItem
-                                            value=second
-                                            on:click=on_click
-                                            is_selected
-                                            comp_ref
-                                        />
-                                    }
-                                })
-                                .collect_view()}
-                            <div class="thaw-time-picker-panel__time-padding"></div>
-                        </Scrollbar>
-                    </div>
-                </div>
+                <TimePanelColumns selected_time comp_ref limits steps/>
                 <div class="thaw-time-picker-panel__footer">
-                    <Button variant=ButtonVariant::Outlined size=ButtonSize::Tiny on_click=now>
-                        "Now"
+                    <Button
+                        variant=ButtonVariant::Outlined
+                        size=ButtonSize::Tiny
+                        disabled=Signal::derive(move || limits.is_time_disabled(now_time()))
+                        on_click=now
+                    >
+                        {move || locale.with(|locale| locale.now.clone())}
                     </Button>
-                    <Button size=ButtonSize::Tiny on_click=ok>
-                        "OK"
+                    <Button
+                        size=ButtonSize::Tiny
+                        disabled=Signal::derive(move || {
+                            selected_time.get().is_some_and(|time| limits.is_time_disabled(time))
+                        })
+                        on_click=ok
+                    >
+                        {move || locale.with(|locale| locale.ok.clone())}
                     </Button>
                 </div>
             </div>
@@ -298,14 +229,159 @@ fn Panel(
     }
 }
 
+/// The hour, minute and second columns of the time panel.
+#[component]
+pub(crate) fn TimePanelColumns(
+    selected_time: RwSignal<Option<NaiveTime>>,
+    comp_ref: ComponentRef<TimePanelColumnsRef>,
+    #[prop(optional)] limits: TimeLimits,
+    #[prop(optional)] steps: TimeSteps,
+) -> impl IntoView {
+    let hour_ref = ComponentRef::<ScrollbarRef>::new();
+    let minute_ref = ComponentRef::<ScrollbarRef>::new();
+    let second_ref = ComponentRef::<ScrollbarRef>::new();
+    comp_ref.load(TimePanelColumnsRef {
+        hour_ref,
+        minute_ref,
+        second_ref,
+    });
+
+    view! {
+        <div class="thaw-time-picker-panel__time">
+            <div class="thaw-time-picker-panel__time-hour">
+                <Scrollbar size=6 comp_ref=hour_ref>
+                    {(0..24)
+                        .step_by(steps.hour.max(1) as usize)
+                        .map(|hour| {
+                            let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
+                            let is_disabled = Memo::new(move |_| limits.is_hour_disabled(hour));
+                            let on_click = move |_| {
+                                if is_disabled.get_untracked() {
+                                    return;
+                                }
+                                selected_time
+                                    .update(move |time| {
+                                        *time = if let Some(time) = time {
+                                            time.with_hour(hour)
+                                        } else {
+                                            NaiveTime::from_hms_opt(hour, 0, 0)
+                                        }
+                                    });
+                                comp_ref.get_untracked().unwrap().scroll_into_view();
+                            };
+                            let is_selected = Memo::new(move |_| {
+                                selected_time.get().map_or(false, |v| v.hour() == hour)
+                            });
+                            view! {
+                                <PanelTimeItem
+                                    value=hour
+                                    on:click=on_click
+                                    is_selected
+                                    is_disabled
+                                    comp_ref
+                                />
+                            }
+                        })
+                        .collect_view()}
+                    <div class="thaw-time-picker-panel__time-padding"></div>
+                </Scrollbar>
+            </div>
+            <div class="thaw-time-picker-panel__time-minute">
+                <Scrollbar size=6 comp_ref=minute_ref>
+                    {(0..60)
+                        .step_by(steps.minute.max(1) as usize)
+                        .map(|minute| {
+                            let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
+                            let is_disabled = Memo::new(move |_| {
+                                let hour = selected_time.get().map(|time| time.hour());
+                                limits.is_minute_disabled(hour, minute)
+                            });
+                            let on_click = move |_| {
+                                if is_disabled.get_untracked() {
+                                    return;
+                                }
+                                selected_time
+                                    .update(move |time| {
+                                        *time = if let Some(time) = time {
+                                            time.with_minute(minute)
+                                        } else {
+                                            NaiveTime::from_hms_opt(now_time().hour(), minute, 0)
+                                        }
+                                    });
+                                comp_ref.get_untracked().unwrap().scroll_into_view();
+                            };
+                            let is_selected = Memo::new(move |_| {
+                                selected_time.get().map_or(false, |v| v.minute() == minute)
+                            });
+                            view! {
+                                <PanelTimeItem
+                                    value=minute
+                                    on:click=on_click
+                                    is_selected
+                                    is_disabled
+                                    comp_ref
+                                />
+                            }
+                        })
+                        .collect_view()}
+                    <div class="thaw-time-picker-panel__time-padding"></div>
+                </Scrollbar>
+            </div>
+            <div class="thaw-time-picker-panel__time-second">
+                <Scrollbar size=6 comp_ref=second_ref>
+                    {(0..60)
+                        .step_by(steps.second.max(1) as usize)
+                        .map(|second| {
+                            let comp_ref = ComponentRef::<PanelTimeItemRef>::default();
+                            let is_disabled = Memo::new(move |_| {
+                                let hour_minute = selected_time
+                                    .get()
+                                    .map(|time| (time.hour(), time.minute()));
+                                limits.is_second_disabled(hour_minute, second)
+                            });
+                            let on_click = move |_| {
+                                if is_disabled.get_untracked() {
+                                    return;
+                                }
+                                selected_time
+                                    .update(move |time| {
+                                        *time = if let Some(time) = time {
+                                            time.with_second(second)
+                                        } else {
+                                            now_time().with_second(second)
+                                        }
+                                    });
+                                comp_ref.get_untracked().unwrap().scroll_into_view();
+                            };
+                            let is_selected = Memo::new(move |_| {
+                                selected_time.get().map_or(false, |v| v.second() == second)
+                            });
+                            view! {
+                                <PanelTimeItem
+                                    value=second
+                                    on:click=on_click
+                                    is_selected
+                                    is_disabled
+                                    comp_ref
+                                />
+                            }
+                        })
+                        .collect_view()}
+                    <div class="thaw-time-picker-panel__time-padding"></div>
+                </Scrollbar>
+            </div>
+        </div>
+    }
+}
+
 #[derive(Clone)]
-struct PanelRef {
+pub(crate) struct TimePanelColumnsRef {
     hour_ref: ComponentRef<ScrollbarRef>,
     minute_ref: ComponentRef<ScrollbarRef>,
     second_ref: ComponentRef<ScrollbarRef>,
 }
 
-impl PanelRef {
+impl TimePanelColumnsRef {
     fn scroll_top(scrollbar_ref: ScrollbarRef) {
         let Some(contetn_ref) = scrollbar_ref.content_ref.get_untracked() else {
             return;
@@ -323,7 +399,7 @@ impl PanelRef {
         }
     }
 
-    fn scroll_into_view(&self) {
+    pub(crate) fn scroll_into_view(&self) {
         if let Some(hour) = self.hour_ref.get_untracked() {
             Self::scroll_top(hour);
         }
@@ -340,6 +416,7 @@ impl PanelRef {
 fn PanelTimeItem(
     value: u32,
     is_selected: Memo<bool>,
+    is_disabled: Memo<bool>,
     comp_ref: ComponentRef<PanelTimeItemRef>,
 ) -> impl IntoView {
     let item_ref = create_node_ref();
@@ -351,6 +428,7 @@ fn PanelTimeItem(
         <div
             class="thaw-time-picker-panel__time-item"
             class=("thaw-time-picker-panel__time-item--slected", move || is_selected.get())
+            class=("thaw-time-picker-panel__time-item--disabled", move || is_disabled.get())
             ref=item_ref
         >
 
@@ -373,6 +451,111 @@ impl PanelTimeItemRef {
     }
 }
 
-fn now_time() -> NaiveTime {
+/// The times that can't be picked in the panel.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct TimeLimits {
+    pub min: Signal<Option<NaiveTime>>,
+    pub max: Signal<Option<NaiveTime>>,
+    pub is_hour_disabled: Option<Callback<u32, bool>>,
+    pub is_minute_disabled: Option<Callback<u32, bool>>,
+    pub is_second_disabled: Option<Callback<u32, bool>>,
+}
+
+impl TimeLimits {
+    /// Whether no time from `first` to `last` is between `min` and `max`.
+    fn is_out_of_bounds(&self, first: Option<NaiveTime>, last: Option<NaiveTime>) -> bool {
+        let (Some(first), Some(last)) = (first, last) else {
+            return false;
+        };
+        self.min.get().is_some_and(|min| last < min)
+            || self.max.get().is_some_and(|max| first > max)
+    }
+
+    pub fn is_hour_disabled(&self, hour: u32) -> bool {
+        self.is_hour_disabled
+            .is_some_and(|is_disabled| is_disabled.call(hour))
+            || self.is_out_of_bounds(
+                NaiveTime::from_hms_opt(hour, 0, 0),
+                NaiveTime::from_hms_opt(hour, 59, 59),
+            )
+    }
+
+    /// The bounds are only checked once the hour is known.
+    pub fn is_minute_disabled(&self, hour: Option<u32>, minute: u32) -> bool {
+        self.is_minute_disabled
+            .is_some_and(|is_disabled| is_disabled.call(minute))
+            || hour.is_some_and(|hour| {
+                self.is_out_of_bounds(
+                    NaiveTime::from_hms_opt(hour, minute, 0),
+                    NaiveTime::from_hms_opt(hour, minute, 59),
+                )
+            })
+    }
+
+    /// The bounds are only checked once the hour and the minute are known.
+    pub fn is_second_disabled(&self, hour_minute: Option<(u32, u32)>, second: u32) -> bool {
+        self.is_second_disabled
+            .is_some_and(|is_disabled| is_disabled.call(second))
+            || hour_minute.is_some_and(|(hour, minute)| {
+                let time = NaiveTime::from_hms_opt(hour, minute, second);
+                self.is_out_of_bounds(time, time)
+            })
+    }
+
+    pub fn is_time_disabled(&self, time: NaiveTime) -> bool {
+        self.is_hour_disabled(time.hour())
+            || self.is_minute_disabled(Some(time.hour()), time.minute())
+            || self.is_second_disabled(Some((time.hour(), time.minute())), time.second())
+    }
+}
+
+/// The distance between two items of each column of the panel.
+#[derive(Clone, Copy)]
+pub(crate) struct TimeSteps {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl Default for TimeSteps {
+    fn default() -> Self {
+        Self {
+            hour: 1,
+            minute: 1,
+            second: 1,
+        }
+    }
+}
+
+pub(crate) fn now_time() -> NaiveTime {
     Local::now().time()
 }
+
+#[cfg(test)]
+mod test {
+    use super::TimeLimits;
+    use chrono::NaiveTime;
+    use leptos::*;
+
+    #[test]
+    fn time_limits() {
+        let runtime = create_runtime();
+        let limits = TimeLimits {
+            min: Signal::derive(|| NaiveTime::from_hms_opt(9, 30, 0)),
+            max: Signal::derive(|| NaiveTime::from_hms_opt(18, 0, 0)),
+            is_hour_disabled: Some(Callback::new(|hour| hour == 12)),
+            ..Default::default()
+        };
+        assert!(limits.is_hour_disabled(8));
+        assert!(!limits.is_hour_disabled(9));
+        assert!(limits.is_hour_disabled(12));
+        assert!(!limits.is_hour_disabled(18));
+        assert!(limits.is_minute_disabled(Some(9), 29));
+        assert!(!limits.is_minute_disabled(None, 29));
+        assert!(limits.is_second_disabled(Some((18, 0)), 1));
+        assert!(!limits.is_time_disabled(NaiveTime::from_hms_opt(9, 30, 0).unwrap()));
+        assert!(limits.is_time_disabled(NaiveTime::from_hms_opt(12, 10, 0).unwrap()));
+        assert!(limits.is_time_disabled(NaiveTime::from_hms_opt(18, 0, 1).unwrap()));
+        runtime.dispose();
+    }
+}
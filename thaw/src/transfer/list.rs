@@ -0,0 +1,170 @@
+use crate::{
+    use_locale, Checkbox, CheckboxGroup, CheckboxItem, Icon, Input, InputPrefix, SelectOption,
+};
+use leptos::*;
+use std::{collections::HashSet, hash::Hash};
+use thaw_components::{VirtualList, VirtualListScroll};
+
+/// Height of an item, see `.thaw-transfer-list__item`.
+const ITEM_HEIGHT: f64 = 32.0;
+/// Same as the `height` of `.thaw-transfer-list__body`.
+const BODY_HEIGHT: f64 = 240.0;
+
+#[component]
+pub(super) fn TransferList<T>(
+    title: MaybeSignal<String>,
+    /// The options of the list with their index in the options of the transfer.
+    items: Signal<Vec<(usize, SelectOption<T>)>>,
+    checked: RwSignal<HashSet<String>>,
+    filterable: MaybeSignal<bool>,
+    render_item: Option<Callback<SelectOption<T>, View>>,
+    virtual_scroll: bool,
+) -> impl IntoView
+where
+    T: Eq + Hash + Clone + 'static,
+{
+    let locale = use_locale();
+    let filter_text = create_rw_signal(String::new());
+    let filtered_items = create_memo(move |_| {
+        let pattern = filter_text.with(|text| text.to_lowercase());
+        items.with(|items| {
+            items
+                .iter()
+                .filter(|(_, option)| {
+                    pattern.is_empty() || option.label.to_lowercase().contains(&pattern)
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+    let checked_count = create_memo(move |_| {
+        checked.with(|checked| {
+            items.with(|items| {
+                items
+                    .iter()
+                    .filter(|(index, _)| checked.contains(&index.to_string()))
+                    .count()
+            })
+        })
+    });
+
+    // The header checkbox checks or unchecks the shown items.
+    let is_all_checked = create_memo(move |_| {
+        checked.with(|checked| {
+            filtered_items.with(|items| {
+                !items.is_empty()
+                    && items
+                        .iter()
+                        .all(|(index, _)| checked.contains(&index.to_string()))
+            })
+        })
+    });
+    let is_some_checked = Signal::derive(move || checked_count.get() > 0);
+    let (all_toggled, on_all_toggle) = create_signal(false);
+    create_effect(move |prev: Option<()>| {
+        all_toggled.track();
+        if prev.is_none() {
+            return;
+        }
+        let keys = filtered_items.with_untracked(|items| {
+            items
+                .iter()
+                .map(|(index, _)| index.to_string())
+                .collect::<Vec<_>>()
+        });
+        let is_all_checked = is_all_checked.get_untracked();
+        checked.update(|checked| {
+            for key in keys {
+                if is_all_checked {
+                    checked.remove(&key);
+                } else {
+                    checked.insert(key);
+                }
+            }
+        });
+    });
+
+    let body_ref = create_node_ref::<html::Div>();
+    let body_scroll = VirtualListScroll::new(body_ref, ITEM_HEIGHT);
+    let on_scroll = move |_| {
+        if virtual_scroll {
+            body_scroll.on_scroll();
+        }
+    };
+    let filter_placeholder =
+        Signal::derive(move || locale.with(|locale| locale.search_placeholder.clone()));
+    let render = move |(index, option): (usize, SelectOption<T>)| {
+        let key = index.to_string();
+        let item = match render_item {
+            Some(render_item) => {
+                view! { <CheckboxItem key>{render_item.call(option)}</CheckboxItem> }
+            }
+            None => view! { <CheckboxItem key label=option.label/> },
+        };
+        view! { <div class="thaw-transfer-list__item">{item}</div> }
+    };
+
+    view! {
+        <div class="thaw-transfer-list">
+            <div class="thaw-transfer-list__header">
+                <Checkbox value=(is_all_checked, on_all_toggle) indeterminate=is_some_checked/>
+                <span class="thaw-transfer-list__title">{move || title.get()}</span>
+                <span class="thaw-transfer-list__count">
+                    {move || format!("{}/{}", checked_count.get(), items.with(Vec::len))}
+                </span>
+            </div>
+            {move || {
+                filterable
+                    .get()
+                    .then(|| {
+                        view! {
+                            <div class="thaw-transfer-list__filter">
+                                <Input value=filter_text placeholder=filter_placeholder>
+                                    <InputPrefix slot>
+                                        <Icon icon=icondata_ai::AiSearchOutlined/>
+                                    </InputPrefix>
+                                </Input>
+                            </div>
+                        }
+                    })
+            }}
+            <div class="thaw-transfer-list__body" ref=body_ref on:scroll=on_scroll>
+                <CheckboxGroup value=checked>
+                    {if virtual_scroll {
+                        view! {
+                            <VirtualList
+                                each=Signal::derive(move || filtered_items.get())
+                                key=|(index, option)| (*index, option.value.clone(), option.label.clone())
+                                item_size=ITEM_HEIGHT
+                                scroll_offset=body_scroll.scroll_offset()
+                                viewport_size=Signal::derive(|| BODY_HEIGHT)
+                                children=render
+                            />
+                        }
+                            .into_view()
+                    } else {
+                        view! {
+                            <For
+                                each=move || filtered_items.get()
+                                key=|(index, option)| (*index, option.value.clone(), option.label.clone())
+                                children=render
+                            />
+                        }
+                            .into_view()
+                    }}
+                </CheckboxGroup>
+                {move || {
+                    filtered_items
+                        .with(Vec::is_empty)
+                        .then(|| {
+                            view! {
+                                <div class="thaw-transfer-list__empty">
+                                    {move || locale.with(|locale| locale.no_data.clone())}
+                                </div>
+                            }
+                        })
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,166 @@
+mod list;
+
+use crate::{
+    form::use_form_field, theme::mount_theme_vars, Button, ButtonSize, FormFieldValue, SelectOption,
+};
+use leptos::*;
+use list::TransferList;
+use std::{collections::HashSet, hash::Hash};
+use thaw_utils::{class_list, mount_style, use_direction, Model, OptionalProp};
+
+#[component]
+pub fn Transfer<T>(
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    /// The values of the options in the target list.
+    #[prop(optional, into)]
+    value: Model<Vec<T>>,
+    #[prop(into)] options: MaybeSignal<Vec<SelectOption<T>>>,
+    #[prop(optional, into)] source_title: MaybeSignal<String>,
+    #[prop(optional, into)] target_title: MaybeSignal<String>,
+    /// Shows a search input above each list, the labels are matched case-insensitively.
+    #[prop(optional, into)]
+    filterable: MaybeSignal<bool>,
+    /// Renders an option instead of its label.
+    #[prop(optional, into)]
+    render_item: Option<Callback<SelectOption<T>, View>>,
+    /// Only render the options in the visible part of the lists.
+    #[prop(optional)]
+    virtual_scroll: bool,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    T: Eq + Hash + Clone + 'static,
+{
+    mount_theme_vars();
+    mount_style("transfer", include_str!("./transfer.css"));
+
+    let invalid = use_form_field(
+        invalid,
+        {
+            let options = options.clone();
+            move || {
+                with!(|value, options| {
+                    FormFieldValue::List(
+                        options
+                            .iter()
+                            .filter(|option| value.contains(&option.value))
+                            .map(|option| option.label.clone())
+                            .collect(),
+                    )
+                })
+            }
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+
+    // Options are checked by their index in `options`.
+    let source_checked = create_rw_signal(HashSet::<String>::new());
+    let target_checked = create_rw_signal(HashSet::<String>::new());
+    create_effect({
+        let options = options.clone();
+        move |prev: Option<()>| {
+            options.track();
+            if prev.is_some() {
+                source_checked.set(HashSet::new());
+                target_checked.set(HashSet::new());
+            }
+        }
+    });
+
+    let list_items = move |in_target: bool| {
+        let options = options.clone();
+        Signal::derive(move || {
+            with!(|value, options| {
+                options
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, option)| value.contains(&option.value) == in_target)
+                    .map(|(index, option)| (index, option.clone()))
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+    let source_items = list_items(false);
+    let target_items = list_items(true);
+
+    let checked_values =
+        move |checked: RwSignal<HashSet<String>>, items: Signal<Vec<(usize, SelectOption<T>)>>| {
+            checked.with_untracked(|checked| {
+                items.with_untracked(|items| {
+                    items
+                        .iter()
+                        .filter(|(index, _)| checked.contains(&index.to_string()))
+                        .map(|(_, option)| option.value.clone())
+                        .collect::<Vec<_>>()
+                })
+            })
+        };
+    let move_to_target = move |_| {
+        let values = checked_values(source_checked, source_items);
+        value.update(|value| value.extend(values));
+        source_checked.set(HashSet::new());
+    };
+    let move_to_source = move |_| {
+        let values = checked_values(target_checked, target_items);
+        value.update(|value| value.retain(|value| !values.contains(value)));
+        target_checked.set(HashSet::new());
+    };
+
+    let dir = use_direction();
+    let is_rtl = move || dir.is_some_and(|dir| dir.get().is_rtl());
+    let to_target_icon = Signal::derive(move || {
+        Some(if is_rtl() {
+            icondata_ai::AiLeftOutlined
+        } else {
+            icondata_ai::AiRightOutlined
+        })
+    });
+    let to_source_icon = Signal::derive(move || {
+        Some(if is_rtl() {
+            icondata_ai::AiRightOutlined
+        } else {
+            icondata_ai::AiLeftOutlined
+        })
+    });
+
+    view! {
+        <div class=class_list![
+            "thaw-transfer", ("thaw-transfer--invalid", move || invalid.get()), class.map(| c | move
+            || c.get())
+        ]>
+            <TransferList
+                title=source_title
+                items=source_items
+                checked=source_checked
+                filterable
+                render_item
+                virtual_scroll
+            />
+            <div class="thaw-transfer__actions">
+                <Button
+                    size=ButtonSize::Small
+                    icon=to_target_icon
+                    disabled=Signal::derive(move || source_checked.with(HashSet::is_empty))
+                    on_click=move_to_target
+                />
+                <Button
+                    size=ButtonSize::Small
+                    icon=to_source_icon
+                    disabled=Signal::derive(move || target_checked.with(HashSet::is_empty))
+                    on_click=move_to_source
+                />
+            </div>
+            <TransferList
+                title=target_title
+                items=target_items
+                checked=target_checked
+                filterable
+                render_item
+                virtual_scroll
+            />
+        </div>
+    }
+}
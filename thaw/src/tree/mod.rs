@@ -0,0 +1,535 @@
+mod tree_item;
+
+use crate::theme::mount_theme_vars;
+use leptos::*;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+use thaw_utils::{class_list, mount_style, use_direction, Model, OptionalProp};
+use tree_item::TreeItem;
+
+#[derive(Clone, PartialEq)]
+pub struct TreeNode<K> {
+    pub key: K,
+    pub label: String,
+    pub children: Vec<TreeNode<K>>,
+    /// The children are loaded by the `on_load` of the tree when the node is
+    /// expanded for the first time.
+    pub lazy: bool,
+    pub disabled: bool,
+}
+
+impl<K> TreeNode<K> {
+    pub fn new(key: K, label: impl Into<String>) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            children: vec![],
+            lazy: false,
+            disabled: false,
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<TreeNode<K>>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Takes the key of the expanded node and a callback for its children.
+pub type TreeLoad<K> = Callback<(K, Callback<Vec<TreeNode<K>>>)>;
+
+#[component]
+pub fn Tree<K>(
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(into)] data: MaybeSignal<Vec<TreeNode<K>>>,
+    #[prop(optional, into)] expanded_keys: Model<HashSet<K>>,
+    /// Shows a checkbox in front of every node.
+    #[prop(optional, into)]
+    checkable: MaybeSignal<bool>,
+    /// Checking a node checks all of its descendants, a node whose children
+    /// are all checked is checked too.
+    #[prop(optional, into)]
+    checked_keys: Model<HashSet<K>>,
+    /// Only the nodes whose label contains the text, case-insensitively,
+    /// are shown together with their ancestors.
+    #[prop(optional, into)]
+    filter: MaybeSignal<String>,
+    /// The keys of the highlighted nodes.
+    #[prop(optional, into)]
+    selected_keys: MaybeSignal<HashSet<K>>,
+    /// Called with the key of a clicked node, the switcher still toggles it.
+    #[prop(optional, into)]
+    on_select: Option<Callback<K>>,
+    /// Called with the key of an expanded lazy node and a callback that takes
+    /// its loaded children.
+    #[prop(optional, into)]
+    on_load: Option<TreeLoad<K>>,
+) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    mount_theme_vars();
+    mount_style("tree", include_str!("./tree.css"));
+
+    let dir = use_direction();
+    let loaded = create_rw_signal(HashMap::<K, Vec<TreeNode<K>>>::new());
+    let index =
+        create_memo(move |_| loaded.with(|loaded| data.with(|data| TreeIndex::new(data, loaded))));
+    let filter_keys = create_memo(move |_| {
+        let filter = filter.with(|filter| filter.trim().to_lowercase());
+        (!filter.is_empty()).then(|| index.with(|index| index.filter(&filter)))
+    });
+    let tree = TreeInjection {
+        index,
+        filter_keys,
+        expanded_keys,
+        checked_keys,
+        checkable,
+        selected_keys: Signal::derive(move || selected_keys.get()),
+        on_select,
+        loaded,
+        loading: create_rw_signal(HashSet::new()),
+        focused: create_rw_signal(None),
+        on_load,
+    };
+
+    let on_keydown = move |event: ev::KeyboardEvent| {
+        let visible_keys = tree.visible_keys();
+        let focused = tree.focused.get_untracked();
+        let position = focused
+            .as_ref()
+            .and_then(|focused| visible_keys.iter().position(|key| key == focused));
+        let focus = |key: Option<&K>| {
+            if let Some(key) = key {
+                tree.focused.set(Some(key.clone()));
+            }
+        };
+        match event.key().as_str() {
+            "ArrowDown" => focus(match position {
+                Some(position) => visible_keys.get(position + 1),
+                None => visible_keys.first(),
+            }),
+            "ArrowUp" => focus(match position {
+                Some(position) => position.checked_sub(1).and_then(|p| visible_keys.get(p)),
+                None => visible_keys.last(),
+            }),
+            "Home" => focus(visible_keys.first()),
+            "End" => focus(visible_keys.last()),
+            "ArrowRight" => {
+                let Some(key) = focused else { return };
+                if tree.is_expanded(&key) {
+                    let child = index.with_untracked(|index| index.first_child(&key).cloned());
+                    focus(child.filter(|child| visible_keys.contains(child)).as_ref())
+                } else if tree.has_children(&key) {
+                    tree.toggle_expanded(key);
+                }
+            }
+            "ArrowLeft" => {
+                let Some(key) = focused else { return };
+                if tree.is_expanded(&key) && tree.filter_keys.with_untracked(Option::is_none) {
+                    tree.toggle_expanded(key);
+                } else {
+                    focus(index.with_untracked(|index| index.parent(&key)).as_ref());
+                }
+            }
+            "Enter" | " " => {
+                let Some(key) = focused else { return };
+                if tree.checkable.get_untracked() {
+                    tree.toggle_checked(key);
+                } else if let Some(on_select) = tree.on_select {
+                    tree.select(key, on_select);
+                } else if tree.has_children(&key) {
+                    tree.toggle_expanded(key);
+                }
+            }
+            _ => return,
+        }
+        event.prevent_default();
+    };
+    let on_focus = move |_| {
+        if tree.focused.with_untracked(Option::is_none) {
+            tree.focused.set(tree.visible_keys().into_iter().next());
+        }
+    };
+
+    view! {
+        <div
+            class=class_list![
+                "thaw-tree", ("thaw-tree--rtl", move || dir.is_some_and(| dir | dir.get().is_rtl())),
+                class.map(| c | move || c.get())
+            ]
+            role="tree"
+            tabindex="0"
+            aria-multiselectable=move || tree.checkable.get().then_some("true")
+            on:keydown=on_keydown
+            on:focus=on_focus
+        >
+            <For
+                each=move || index.with(|index| index.roots.clone())
+                key=|key| key.clone()
+                children=move |key| view! { <TreeItem tree key level=0/> }
+            />
+        </div>
+    }
+}
+
+pub(crate) struct TreeInjection<K: Eq + Hash + 'static> {
+    index: Memo<TreeIndex<K>>,
+    /// The keys that match the filter, `None` when there is no filter.
+    filter_keys: Memo<Option<HashSet<K>>>,
+    expanded_keys: Model<HashSet<K>>,
+    checked_keys: Model<HashSet<K>>,
+    checkable: MaybeSignal<bool>,
+    selected_keys: Signal<HashSet<K>>,
+    on_select: Option<Callback<K>>,
+    loaded: RwSignal<HashMap<K, Vec<TreeNode<K>>>>,
+    loading: RwSignal<HashSet<K>>,
+    focused: RwSignal<Option<K>>,
+    on_load: Option<TreeLoad<K>>,
+}
+
+impl<K: Eq + Hash + 'static> Clone for TreeInjection<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Eq + Hash + 'static> Copy for TreeInjection<K> {}
+
+impl<K: Eq + Hash + Clone + 'static> TreeInjection<K> {
+    fn is_expanded(&self, key: &K) -> bool {
+        self.filter_keys.with_untracked(Option::is_some)
+            || self.expanded_keys.with_untracked(|keys| keys.contains(key))
+    }
+
+    fn has_children(&self, key: &K) -> bool {
+        self.index.with_untracked(|index| {
+            index
+                .nodes
+                .get(key)
+                .is_some_and(|node| node.lazy || !node.children.is_empty())
+        })
+    }
+
+    fn visible_keys(&self) -> Vec<K> {
+        let expanded_keys = self.expanded_keys.get_untracked();
+        self.filter_keys.with_untracked(|filter_keys| {
+            self.index
+                .with_untracked(|index| index.visible_keys(&expanded_keys, filter_keys.as_ref()))
+        })
+    }
+
+    fn toggle_expanded(&self, key: K) {
+        if self
+            .expanded_keys
+            .with_untracked(|keys| keys.contains(&key))
+        {
+            self.expanded_keys.update(|keys| {
+                keys.remove(&key);
+            });
+            return;
+        }
+
+        let is_lazy = self
+            .index
+            .with_untracked(|index| index.nodes.get(&key).is_some_and(|node| node.lazy));
+        let is_loading = self
+            .loading
+            .with_untracked(|loading| loading.contains(&key));
+        if let (true, false, Some(on_load)) = (is_lazy, is_loading, self.on_load) {
+            self.loading.update(|loading| {
+                loading.insert(key.clone());
+            });
+            let (loaded, loading) = (self.loaded, self.loading);
+            let (index, checked_keys) = (self.index, self.checked_keys);
+            let loaded_key = key.clone();
+            let on_loaded = Callback::new(move |children| {
+                let key = loaded_key.clone();
+                loading.update(|loading| {
+                    loading.remove(&key);
+                });
+                loaded.update(|loaded| {
+                    loaded.insert(key.clone(), children);
+                });
+                if checked_keys.with_untracked(|keys| keys.contains(&key)) {
+                    checked_keys.update(|keys| {
+                        index.with_untracked(|index| index.check_loaded(keys, &key));
+                    });
+                }
+            });
+            on_load.call((key.clone(), on_loaded));
+        }
+        self.expanded_keys.update(|keys| {
+            keys.insert(key);
+        });
+    }
+
+    fn select(&self, key: K, on_select: Callback<K>) {
+        if self
+            .index
+            .with_untracked(|index| index.nodes.get(&key).is_some_and(|node| !node.disabled))
+        {
+            on_select.call(key);
+        }
+    }
+
+    fn toggle_checked(&self, key: K) {
+        let index = self.index;
+        self.checked_keys.update(|keys| {
+            index.with_untracked(|index| {
+                if index.nodes.get(&key).is_some_and(|node| !node.disabled) {
+                    let checked = !keys.contains(&key);
+                    index.set_checked(keys, &key, checked);
+                }
+            });
+        });
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct TreeIndexNode<K> {
+    label: String,
+    parent: Option<K>,
+    children: Vec<K>,
+    /// Not loaded yet.
+    lazy: bool,
+    disabled: bool,
+}
+
+/// The nodes of a tree by key, with the loaded children of the lazy nodes.
+#[derive(PartialEq)]
+struct TreeIndex<K: Eq + Hash> {
+    roots: Vec<K>,
+    nodes: HashMap<K, TreeIndexNode<K>>,
+}
+
+impl<K: Eq + Hash + Clone> TreeIndex<K> {
+    fn new(data: &[TreeNode<K>], loaded: &HashMap<K, Vec<TreeNode<K>>>) -> Self {
+        let mut index = Self {
+            roots: data.iter().map(|node| node.key.clone()).collect(),
+            nodes: HashMap::new(),
+        };
+        index.insert(data, None, loaded);
+        index
+    }
+
+    fn insert(
+        &mut self,
+        nodes: &[TreeNode<K>],
+        parent: Option<&K>,
+        loaded: &HashMap<K, Vec<TreeNode<K>>>,
+    ) {
+        for node in nodes {
+            let loaded_children = node.lazy.then(|| loaded.get(&node.key)).flatten();
+            let children = loaded_children.unwrap_or(&node.children);
+            self.nodes.insert(
+                node.key.clone(),
+                TreeIndexNode {
+                    label: node.label.clone(),
+                    parent: parent.cloned(),
+                    children: children.iter().map(|child| child.key.clone()).collect(),
+                    lazy: node.lazy && loaded_children.is_none(),
+                    disabled: node.disabled,
+                },
+            );
+            self.insert(children, Some(&node.key), loaded);
+        }
+    }
+
+    fn parent(&self, key: &K) -> Option<K> {
+        self.nodes.get(key)?.parent.clone()
+    }
+
+    fn first_child(&self, key: &K) -> Option<&K> {
+        self.nodes.get(key)?.children.first()
+    }
+
+    fn descendants<'a>(&'a self, key: &K, descendants: &mut Vec<&'a K>) {
+        if let Some(node) = self.nodes.get(key) {
+            for child in &node.children {
+                descendants.push(child);
+                self.descendants(child, descendants);
+            }
+        }
+    }
+
+    /// Checks or unchecks the node with its enabled descendants and updates
+    /// its ancestors.
+    fn set_checked(&self, keys: &mut HashSet<K>, key: &K, checked: bool) {
+        let mut descendants = vec![];
+        self.descendants(key, &mut descendants);
+        let enabled = std::iter::once(key).chain(
+            descendants
+                .into_iter()
+                .filter(|key| self.nodes.get(*key).is_some_and(|node| !node.disabled)),
+        );
+        for key in enabled {
+            if checked {
+                keys.insert(key.clone());
+            } else {
+                keys.remove(key);
+            }
+        }
+
+        let mut parent = self.parent(key);
+        while let Some(key) = parent {
+            let Some(node) = self.nodes.get(&key) else {
+                break;
+            };
+            let mut children = node
+                .children
+                .iter()
+                .filter(|child| self.nodes.get(*child).is_some_and(|node| !node.disabled))
+                .peekable();
+            if children.peek().is_some() {
+                if children.all(|child| keys.contains(child)) {
+                    keys.insert(key.clone());
+                } else {
+                    keys.remove(&key);
+                }
+            }
+            parent = node.parent.clone();
+        }
+    }
+
+    /// Checks the loaded children of a lazy node that was checked before they were loaded.
+    fn check_loaded(&self, keys: &mut HashSet<K>, key: &K) {
+        if keys.contains(key) {
+            self.set_checked(keys, key, true);
+        }
+    }
+
+    fn is_indeterminate(&self, keys: &HashSet<K>, key: &K) -> bool {
+        if keys.contains(key) {
+            return false;
+        }
+        let mut descendants = vec![];
+        self.descendants(key, &mut descendants);
+        descendants.into_iter().any(|key| keys.contains(key))
+    }
+
+    /// The keys of the nodes whose label contains `filter`, which is expected
+    /// in lowercase, and of their ancestors.
+    fn filter(&self, filter: &str) -> HashSet<K> {
+        let mut keys = HashSet::new();
+        for (key, node) in &self.nodes {
+            if keys.contains(key) || !node.label.to_lowercase().contains(filter) {
+                continue;
+            }
+            let mut key = Some(key.clone());
+            while let Some(current) = key {
+                key = self.parent(&current);
+                if !keys.insert(current) {
+                    break;
+                }
+            }
+        }
+        keys
+    }
+
+    /// The keys of the shown nodes in display order.
+    fn visible_keys(&self, expanded_keys: &HashSet<K>, filter_keys: Option<&HashSet<K>>) -> Vec<K> {
+        fn push<K: Eq + Hash + Clone>(
+            index: &TreeIndex<K>,
+            keys: &[K],
+            expanded_keys: &HashSet<K>,
+            filter_keys: Option<&HashSet<K>>,
+            visible_keys: &mut Vec<K>,
+        ) {
+            for key in keys {
+                if filter_keys.is_some_and(|filter_keys| !filter_keys.contains(key)) {
+                    continue;
+                }
+                visible_keys.push(key.clone());
+                if filter_keys.is_some() || expanded_keys.contains(key) {
+                    if let Some(node) = index.nodes.get(key) {
+                        push(
+                            index,
+                            &node.children,
+                            expanded_keys,
+                            filter_keys,
+                            visible_keys,
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut visible_keys = vec![];
+        push(
+            self,
+            &self.roots,
+            expanded_keys,
+            filter_keys,
+            &mut visible_keys,
+        );
+        visible_keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TreeIndex, TreeNode};
+    use std::collections::{HashMap, HashSet};
+
+    fn data() -> Vec<TreeNode<u32>> {
+        vec![
+            TreeNode::new(1, "Fruits").with_children(vec![
+                TreeNode::new(11, "Apple"),
+                TreeNode::new(12, "Banana"),
+                TreeNode {
+                    disabled: true,
+                    ..TreeNode::new(13, "Cherry")
+                },
+            ]),
+            TreeNode {
+                lazy: true,
+                ..TreeNode::new(2, "Vegetables")
+            },
+        ]
+    }
+
+    fn index() -> TreeIndex<u32> {
+        let loaded = HashMap::from([(2, vec![TreeNode::new(21, "Carrot")])]);
+        TreeIndex::new(&data(), &loaded)
+    }
+
+    #[test]
+    fn tree_check() {
+        let index = index();
+        let mut keys = HashSet::new();
+        index.set_checked(&mut keys, &11, true);
+        assert!(index.is_indeterminate(&keys, &1));
+        index.set_checked(&mut keys, &12, true);
+        assert_eq!(keys, HashSet::from([1, 11, 12]));
+        assert!(!index.is_indeterminate(&keys, &1));
+        index.set_checked(&mut keys, &1, false);
+        assert!(keys.is_empty());
+        index.set_checked(&mut keys, &2, true);
+        assert_eq!(keys, HashSet::from([2, 21]));
+
+        // Checked before its children were loaded.
+        let mut keys = HashSet::new();
+        TreeIndex::new(&data(), &HashMap::new()).set_checked(&mut keys, &2, true);
+        assert_eq!(keys, HashSet::from([2]));
+        index.check_loaded(&mut keys, &2);
+        assert_eq!(keys, HashSet::from([2, 21]));
+        assert!(!index.is_indeterminate(&keys, &2));
+    }
+
+    #[test]
+    fn tree_visible_keys() {
+        let index = index();
+        let expanded = HashSet::from([2]);
+        assert_eq!(index.visible_keys(&expanded, None), vec![1, 2, 21]);
+        let filter_keys = index.filter("an");
+        assert_eq!(filter_keys, HashSet::from([1, 12]));
+        assert_eq!(
+            index.visible_keys(&expanded, Some(&filter_keys)),
+            vec![1, 12]
+        );
+        assert_eq!(index.parent(&21), Some(2));
+    }
+}
@@ -0,0 +1,195 @@
+use super::TreeInjection;
+use crate::{Checkbox, Icon, Spinner, SpinnerSize};
+use leptos::*;
+use std::hash::Hash;
+use thaw_components::CSSTransition;
+use thaw_utils::class_list;
+
+#[component]
+pub(super) fn TreeItem<K>(tree: TreeInjection<K>, key: K, level: usize) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    let key = StoredValue::new(key);
+    let node = create_memo(move |_| {
+        tree.index
+            .with(|index| key.with_value(|key| index.nodes.get(key).cloned()))
+    });
+    let is_visible = create_memo(move |_| {
+        tree.filter_keys.with(|filter_keys| {
+            filter_keys
+                .as_ref()
+                .is_none_or(|filter_keys| key.with_value(|key| filter_keys.contains(key)))
+        })
+    });
+    let is_expanded = create_memo(move |_| {
+        tree.filter_keys.with(Option::is_some)
+            || tree
+                .expanded_keys
+                .with(|keys| key.with_value(|key| keys.contains(key)))
+    });
+    let is_checked = create_memo(move |_| {
+        tree.checked_keys
+            .with(|keys| key.with_value(|key| keys.contains(key)))
+    });
+    let is_indeterminate = create_memo(move |_| {
+        tree.checked_keys.with(|keys| {
+            tree.index
+                .with(|index| key.with_value(|key| index.is_indeterminate(keys, key)))
+        })
+    });
+    let is_loading = create_memo(move |_| {
+        tree.loading
+            .with(|loading| key.with_value(|key| loading.contains(key)))
+    });
+    let is_selected = create_memo(move |_| {
+        tree.selected_keys
+            .with(|keys| key.with_value(|key| keys.contains(key)))
+    });
+    let is_focused = create_memo(move |_| {
+        tree.focused
+            .with(|focused| key.with_value(|key| focused.as_ref() == Some(key)))
+    });
+    let has_children = create_memo(move |_| {
+        node.with(|node| {
+            node.as_ref()
+                .is_some_and(|node| node.lazy || !node.children.is_empty())
+        })
+    });
+    let is_disabled =
+        create_memo(move |_| node.with(|node| node.as_ref().is_some_and(|node| node.disabled)));
+
+    let toggle_expanded = move || {
+        if has_children.get_untracked() && tree.filter_keys.with_untracked(Option::is_none) {
+            tree.toggle_expanded(key.get_value());
+        }
+    };
+    let on_click = move |_| {
+        tree.focused.set(Some(key.get_value()));
+        match tree.on_select {
+            Some(on_select) => tree.select(key.get_value(), on_select),
+            None => toggle_expanded(),
+        }
+    };
+    let on_switcher_click = move |event: ev::MouseEvent| {
+        if tree.on_select.is_some() {
+            event.stop_propagation();
+            tree.focused.set(Some(key.get_value()));
+            toggle_expanded();
+        }
+    };
+
+    // `Checkbox` writes the toggled value, the tree decides what is checked.
+    let (check_toggled, on_check) = create_signal(false);
+    create_effect(move |prev: Option<()>| {
+        check_toggled.track();
+        if prev.is_some() {
+            tree.toggle_checked(key.get_value());
+        }
+    });
+
+    let children_ref = create_node_ref::<html::Div>();
+
+    view! {
+        <div
+            class="thaw-tree-node"
+            role="treeitem"
+            aria-level=level + 1
+            aria-expanded=move || has_children.get().then(|| is_expanded.get().to_string())
+            aria-checked=move || {
+                tree.checkable
+                    .get()
+                    .then(|| {
+                        if is_indeterminate.get() {
+                            "mixed"
+                        } else if is_checked.get() {
+                            "true"
+                        } else {
+                            "false"
+                        }
+                    })
+            }
+
+            aria-selected=move || tree.on_select.is_some().then(|| is_selected.get().to_string())
+            aria-disabled=move || is_disabled.get().then_some("true")
+            style:display=move || (!is_visible.get()).then_some("none")
+        >
+            <div
+                class=class_list![
+                    "thaw-tree-node__content", ("thaw-tree-node__content--focused", move ||
+                    is_focused.get()), ("thaw-tree-node__content--selected", move || is_selected.get()),
+                    ("thaw-tree-node__content--disabled", move || is_disabled
+                    .get())
+                ]
+
+                style=format!("--thaw-tree-node-level: {level}")
+                on:click=on_click
+            >
+                <span
+                    class=class_list![
+                        "thaw-tree-node__switcher", ("thaw-tree-node__switcher--expanded", move ||
+                        is_expanded.get()), ("thaw-tree-node__switcher--hidden", move || !
+                        has_children.get())
+                    ]
+                    on:click=on_switcher_click
+                >
+                    {move || {
+                        if is_loading.get() {
+                            view! { <Spinner size=SpinnerSize::Tiny/> }.into_view()
+                        } else {
+                            view! { <Icon icon=icondata_ai::AiRightOutlined/> }.into_view()
+                        }
+                    }}
+
+                </span>
+                {move || {
+                    tree.checkable
+                        .get()
+                        .then(|| {
+                            view! {
+                                <span
+                                    class="thaw-tree-node__checkbox"
+                                    on:click=move |event| {
+                                        event.stop_propagation();
+                                    }
+                                >
+
+                                    <Checkbox
+                                        value=(Signal::from(is_checked), on_check)
+                                        indeterminate=is_indeterminate
+                                    />
+                                </span>
+                            }
+                        })
+                }}
+                <span class="thaw-tree-node__label">
+                    {move || node.with(|node| node.as_ref().map(|node| node.label.clone()))}
+                </span>
+            </div>
+            <CSSTransition
+                node_ref=children_ref
+                show=Signal::derive(move || is_expanded.get() && has_children.get())
+                name="thaw-tree-node"
+                let:display
+            >
+                <div
+                    class="thaw-tree-node__children"
+                    role="group"
+                    ref=children_ref
+                    style=move || display.get()
+                >
+                    <For
+                        each=move || {
+                            node.with(|node| {
+                                node.as_ref().map(|node| node.children.clone()).unwrap_or_default()
+                            })
+                        }
+
+                        key=|key| key.clone()
+                        children=move |key| view! { <TreeItem tree key level=level + 1/> }
+                    />
+                </div>
+            </CSSTransition>
+        </div>
+    }
+}
@@ -0,0 +1,142 @@
+mod multi;
+
+pub use multi::*;
+
+use crate::{
+    form::use_form_field,
+    select::{RawSelect, SelectIcon, SelectMenu},
+    FormFieldValue, Icon, SelectLabel, SelectOption, Tree, TreeNode,
+};
+use leptos::*;
+use std::{collections::HashSet, hash::Hash, rc::Rc};
+use thaw_utils::{Model, OptionalProp};
+
+#[component]
+pub fn TreeSelect<K>(
+    #[prop(optional, into)] value: Model<Option<K>>,
+    #[prop(into)] data: MaybeSignal<Vec<TreeNode<K>>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    let value_label = Signal::derive({
+        let data = data.clone();
+        move || {
+            with!(|value, data| {
+                value
+                    .as_ref()
+                    .and_then(|value| find_node(data, value))
+                    .map_or(String::new(), |node| node.label.clone())
+            })
+        }
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            let label = value_label.get();
+            if label.is_empty() {
+                FormFieldValue::Empty
+            } else {
+                FormFieldValue::Text(label)
+            }
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+    let is_menu_visible = create_rw_signal(false);
+    let show_menu = move |_| is_menu_visible.set(true);
+    let hide_menu = move |_| is_menu_visible.set(false);
+    let expanded_keys = create_rw_signal(HashSet::new());
+    // The selected node is revealed when the menu is opened.
+    create_effect({
+        let data = data.clone();
+        move |_| {
+            if !is_menu_visible.get() {
+                return;
+            }
+            let ancestors = value.with_untracked(|value| {
+                data.with_untracked(|data| {
+                    value
+                        .as_ref()
+                        .and_then(|value| find_path(data, value))
+                        .unwrap_or_default()
+                })
+            });
+            expanded_keys.update(|expanded_keys| {
+                expanded_keys.extend(ancestors.into_iter().rev().skip(1));
+            });
+        }
+    });
+    let selected_keys = Signal::derive(move || value.get().into_iter().collect::<HashSet<_>>());
+    let on_select = Callback::new(move |key| {
+        value.set(Some(key));
+        hide_menu(());
+    });
+
+    let select_label = select_label.unwrap_or_else(|| SelectLabel {
+        children: Box::new(move || Fragment::new(vec![value_label.into_view()])),
+    });
+    let select_icon = SelectIcon {
+        children: Rc::new(move || {
+            Fragment::new(vec![
+                view! { <Icon class="thaw-select-dropdown-icon" icon=icondata_ai::AiDownOutlined/> }.into_view()
+            ])
+        }),
+    };
+    let select_menu = SelectMenu {
+        class: "",
+        children: Rc::new(move || {
+            let data = data.clone();
+            Fragment::new(vec![
+                view! { <Tree data expanded_keys selected_keys on_select/> }.into_view(),
+            ])
+        }),
+    };
+
+    view! {
+        <RawSelect
+            options={Vec::<SelectOption<K>>::new()}
+            class
+            select_label
+            select_icon
+            select_menu
+            is_menu_visible
+            on_select=|_| {}
+            show_menu
+            hide_menu
+            is_selected=|_: &K| false
+            invalid
+            filter=None
+            on_search=None
+            select_empty=None
+        />
+    }
+}
+
+fn find_node<'a, K: PartialEq>(nodes: &'a [TreeNode<K>], key: &K) -> Option<&'a TreeNode<K>> {
+    nodes.iter().find_map(|node| {
+        if &node.key == key {
+            Some(node)
+        } else {
+            find_node(&node.children, key)
+        }
+    })
+}
+
+/// The keys from the root to the node.
+fn find_path<K: PartialEq + Clone>(nodes: &[TreeNode<K>], key: &K) -> Option<Vec<K>> {
+    nodes.iter().find_map(|node| {
+        if &node.key == key {
+            Some(vec![node.key.clone()])
+        } else {
+            let mut path = find_path(&node.children, key)?;
+            path.insert(0, node.key.clone());
+            Some(path)
+        }
+    })
+}
@@ -0,0 +1,169 @@
+use super::find_node;
+use crate::{
+    form::use_form_field,
+    select::{multiple_class, multiple_select_icon, tags_select_label, RawSelect, SelectMenu},
+    FormFieldValue, SelectLabel, SelectOption, TagVariant, Tree, TreeNode,
+};
+use leptos::*;
+use std::{collections::HashSet, hash::Hash, rc::Rc};
+use thaw_utils::{Model, OptionalProp};
+
+#[component]
+pub fn MultiTreeSelect<K>(
+    /// The keys of the checked nodes, checking a node checks all of its descendants.
+    #[prop(optional, into)]
+    value: Model<HashSet<K>>,
+    #[prop(into)] data: MaybeSignal<Vec<TreeNode<K>>>,
+    #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] clearable: MaybeSignal<bool>,
+    #[prop(optional)] select_label: Option<SelectLabel>,
+    #[prop(optional, into)] invalid: MaybeSignal<bool>,
+) -> impl IntoView
+where
+    K: Eq + Hash + Clone + 'static,
+{
+    // A checked node is shown instead of its checked descendants.
+    let tags = Signal::derive({
+        let data = data.clone();
+        move || {
+            with!(|value, data| {
+                checked_nodes(data, value)
+                    .into_iter()
+                    .map(|node| (node.key.clone(), node.label.clone(), TagVariant::Default))
+                    .collect::<Vec<_>>()
+            })
+        }
+    });
+    let invalid = use_form_field(
+        invalid,
+        move || {
+            tags.with(|tags| {
+                FormFieldValue::List(tags.iter().map(|(_, label, _)| label.clone()).collect())
+            })
+        },
+        {
+            let initial_value = value.get_untracked();
+            move || value.set(initial_value.clone())
+        },
+    );
+    let class = multiple_class(class);
+    let is_menu_visible = create_rw_signal(false);
+    let show_menu = move |_| is_menu_visible.set(true);
+    let hide_menu = move |_| is_menu_visible.set(false);
+    let expanded_keys = create_rw_signal(HashSet::new());
+
+    let select_label = select_label.unwrap_or_else(|| {
+        let data = data.clone();
+        let on_remove = Callback::new(move |key: K| {
+            let mut keys = vec![];
+            data.with_untracked(|data| {
+                if let Some(node) = find_node(data, &key) {
+                    node_keys(node, &mut keys);
+                }
+            });
+            value.update(|value| {
+                for key in keys {
+                    value.remove(&key);
+                }
+            });
+        });
+        tags_select_label(tags, on_remove)
+    });
+    let is_hovered = RwSignal::new(false);
+    let show_clear_icon = Signal::derive(move || {
+        clearable.get()
+            && ((is_hovered.get() || is_menu_visible.get()) && with!(|value| !value.is_empty()))
+    });
+    let on_hover_enter = Callback::new(move |_| is_hovered.set(true));
+    let on_hover_exit = Callback::new(move |_| is_hovered.set(false));
+    let select_icon = multiple_select_icon(
+        show_clear_icon,
+        Callback::new(move |_| value.set(HashSet::new())),
+    );
+    let select_menu = SelectMenu {
+        class: "",
+        children: Rc::new(move || {
+            let data = data.clone();
+            Fragment::new(vec![view! {
+                <Tree data expanded_keys checkable=true checked_keys=value/>
+            }
+            .into_view()])
+        }),
+    };
+
+    // Trigger the following menu to resync when the value is updated
+    let _ = watch(
+        move || value.track(),
+        move |_, _, _| {
+            is_menu_visible.update(|_| {});
+        },
+        false,
+    );
+
+    view! {
+        <RawSelect
+            options={Vec::<SelectOption<K>>::new()}
+            class
+            select_label
+            select_icon
+            select_menu
+            is_menu_visible
+            on_select=|_| {}
+            show_menu
+            hide_menu
+            on_hover_enter
+            on_hover_exit
+            is_selected=|_: &K| false
+            invalid
+            filter=None
+            on_search=None
+            select_empty=None
+        />
+    }
+}
+
+/// The checked nodes whose parent is not checked.
+fn checked_nodes<'a, K: Eq + Hash>(
+    nodes: &'a [TreeNode<K>],
+    keys: &HashSet<K>,
+) -> Vec<&'a TreeNode<K>> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            if keys.contains(&node.key) {
+                vec![node]
+            } else {
+                checked_nodes(&node.children, keys)
+            }
+        })
+        .collect()
+}
+
+/// The key of the node and the keys of all of its descendants.
+fn node_keys<K: Clone>(node: &TreeNode<K>, keys: &mut Vec<K>) {
+    keys.push(node.key.clone());
+    for child in &node.children {
+        node_keys(child, keys);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checked_nodes;
+    use crate::TreeNode;
+    use std::collections::HashSet;
+
+    #[test]
+    fn tree_select_checked_nodes() {
+        let data = vec![
+            TreeNode::new(1, "1").with_children(vec![TreeNode::new(2, "2"), TreeNode::new(3, "3")]),
+            TreeNode::new(4, "4").with_children(vec![TreeNode::new(5, "5"), TreeNode::new(6, "6")]),
+        ];
+        let keys = HashSet::from([1, 2, 3, 5]);
+        let checked = checked_nodes(&data, &keys);
+        assert_eq!(
+            checked.into_iter().map(|node| node.key).collect::<Vec<_>>(),
+            vec![1, 5]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{use_theme, Theme};
+use crate::theme::mount_theme_vars;
 use leptos::*;
 use thaw_utils::{class_list, mount_style, OptionalProp};
 
@@ -8,24 +8,12 @@ pub fn Text(
     #[prop(optional, into)] class: OptionalProp<MaybeSignal<String>>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("text", include_str!("./text.css"));
-    let theme = use_theme(Theme::light);
-    let css_vars = create_memo(move |_| {
-        let mut css_vars = String::new();
-        theme.with(|theme| {
-            css_vars.push_str(&format!(
-                "--thaw-background-color: {}",
-                theme.typograph.code_background_color
-            ));
-        });
-        css_vars
-    });
-
     if code {
         view! {
             <code
                 class=class_list!["thaw-text thaw-text--code", class.map(| c | move || c.get())]
-                style=move || css_vars.get()
             >
                 {children()}
             </code>
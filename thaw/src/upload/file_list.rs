@@ -0,0 +1,105 @@
+use super::{UploadFileInfo, UploadFileStatus};
+use crate::{Icon, Progress};
+use leptos::*;
+
+#[component]
+pub(super) fn UploadFileList(
+    file_list: Signal<Vec<UploadFileInfo>>,
+    #[prop(into)] can_retry: Signal<bool>,
+    on_retry: Callback<String>,
+    on_remove: Callback<String>,
+) -> impl IntoView {
+    view! {
+        <ul class="thaw-upload-file-list">
+            <For
+                each=move || file_list.get()
+                key=|file| file.id.clone()
+                children=move |file| {
+                    let id = file.id;
+                    let file = create_memo({
+                        let id = id.clone();
+                        move |_| {
+                            file_list
+                                .with(|file_list| {
+                                    file_list.iter().find(|file| file.id == id).cloned()
+                                })
+                        }
+                    });
+                    let status = Signal::derive(move || {
+                        file.with(|file| file.as_ref().map(|file| file.status))
+                    });
+                    let id = StoredValue::new(id);
+                    view! {
+                        <li class=move || {
+                            format!(
+                                "thaw-upload-file-list__item thaw-upload-file-list__item--{}",
+                                status.get().map_or("pending", |status| status.as_str()),
+                            )
+                        }>
+                            <div class="thaw-upload-file-list__info">
+                                <Icon icon=icondata_ai::AiPaperClipOutlined/>
+                                <span class="thaw-upload-file-list__name">
+                                    {move || file.with(|file| file.as_ref().map(|file| file.name.clone()))}
+                                </span>
+                                <span class="thaw-upload-file-list__size">
+                                    {move || {
+                                        file.with(|file| file.as_ref().map(|file| format_file_size(file.size)))
+                                    }}
+                                </span>
+                                <Show when=move || {
+                                    can_retry.get() && status.get() == Some(UploadFileStatus::Error)
+                                }>
+                                    <Icon
+                                        class="thaw-upload-file-list__action"
+                                        icon=icondata_ai::AiReloadOutlined
+                                        on_click=move |_| on_retry.call(id.get_value())
+                                    />
+                                </Show>
+                                <Icon
+                                    class="thaw-upload-file-list__action"
+                                    icon=icondata_ai::AiDeleteOutlined
+                                    on_click=move |_| on_remove.call(id.get_value())
+                                />
+                            </div>
+                            <Show when=move || status.get() == Some(UploadFileStatus::Uploading)>
+                                <Progress
+                                    percentage=Signal::derive(move || {
+                                        file.with(|file| file.as_ref().map_or(0.0, |file| file.percentage))
+                                    })
+                                    show_indicator=false
+                                />
+                            </Show>
+                        </li>
+                    }
+                }
+            />
+        </ul>
+    }
+}
+
+fn format_file_size(size: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = size;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{size} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_file_size;
+
+    #[test]
+    fn file_size() {
+        assert_eq!(format_file_size(512.0), "512 B");
+        assert_eq!(format_file_size(1536.0), "1.5 KB");
+        assert_eq!(format_file_size(5.0 * 1024.0 * 1024.0), "5.0 MB");
+    }
+}
@@ -1,20 +1,94 @@
+mod file_list;
+mod request;
 mod theme;
 mod upload_dragger;
 
 pub use theme::UploadTheme;
 pub use upload_dragger::UploadDragger;
-pub use web_sys::FileList;
+pub use web_sys::{File, FileList};
 
+use crate::theme::mount_theme_vars;
+use file_list::UploadFileList;
 use leptos::*;
-use thaw_utils::{add_event_listener, mount_style};
+use std::collections::HashMap;
+use thaw_utils::{add_event_listener, mount_style, Model, OptionalProp};
+use web_sys::{DataTransfer, XmlHttpRequest};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadFileStatus {
+    Pending,
+    Uploading,
+    Finished,
+    Error,
+}
+
+impl UploadFileStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Uploading => "uploading",
+            Self::Finished => "finished",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFileInfo {
+    pub id: String,
+    pub name: String,
+    /// Size in bytes.
+    pub size: f64,
+    pub status: UploadFileStatus,
+    /// Between 0 and 100.
+    pub percentage: f32,
+    /// The selected file, `None` for files that were not added through the component.
+    pub file: Option<File>,
+}
+
+impl UploadFileInfo {
+    pub fn new(file: File) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: file.name(),
+            size: file.size(),
+            status: UploadFileStatus::Pending,
+            percentage: 0.0,
+            file: Some(file),
+        }
+    }
+}
 
 #[component]
 pub fn Upload(
     #[prop(optional, into)] accept: MaybeSignal<String>,
     #[prop(optional, into)] multiple: MaybeSignal<bool>,
-    #[prop(optional, into)] custom_request: Option<Callback<FileList, ()>>,
+    /// Handles the accepted files instead of the built-in uploader,
+    /// they are not added to `file_list`.
+    #[prop(optional, into)]
+    custom_request: Option<Callback<FileList, ()>>,
+    /// Uploads the accepted files instead of the built-in uploader. The files are
+    /// already in `file_list`, their status and progress are updated there by `id`.
+    /// Also called with a single file when it is retried.
+    #[prop(optional, into)]
+    custom_upload: Option<Callback<Vec<UploadFileInfo>, ()>>,
+    #[prop(optional, into)] file_list: Option<Model<Vec<UploadFileInfo>>>,
+    /// Defaults to `true`, with `custom_upload` only when `file_list` is passed.
+    #[prop(optional, into)]
+    show_file_list: Option<MaybeSignal<bool>>,
+    /// Returning `false` rejects the file.
+    #[prop(optional, into)]
+    before_upload: Option<Callback<File, bool>>,
+    /// URL the files are sent to by the built-in uploader.
+    #[prop(optional, into)]
+    action: OptionalProp<MaybeSignal<String>>,
+    #[prop(optional, into)] headers: MaybeSignal<Vec<(String, String)>>,
+    /// Form field name of the file.
+    #[prop(into, default = MaybeSignal::Static(String::from("file")))]
+    name: MaybeSignal<String>,
     children: Children,
 ) -> impl IntoView {
+    mount_theme_vars();
     mount_style("upload", include_str!("./upload.css"));
 
     let input_ref = create_node_ref::<html::Input>();
@@ -31,9 +105,130 @@ pub fn Upload(
         });
     });
 
+    // Without a bound `file_list` nothing could update the files of `custom_upload`.
+    let show_file_list =
+        show_file_list.unwrap_or_else(|| (custom_upload.is_none() || file_list.is_some()).into());
+    let file_list = file_list.unwrap_or_default();
+    let requests = StoredValue::new(HashMap::<String, XmlHttpRequest>::new());
+    on_cleanup(move || {
+        requests.update_value(|requests| {
+            for (_, xhr) in requests.drain() {
+                _ = xhr.abort();
+            }
+        });
+    });
+    let update_file = move |id: &str, f: &dyn Fn(&mut UploadFileInfo)| {
+        file_list.try_update(|file_list| {
+            if let Some(file) = file_list.iter_mut().find(|file| file.id == id) {
+                f(file);
+            }
+        });
+    };
+    let action = StoredValue::new(action.into_option());
+    let can_retry = Signal::derive(move || {
+        custom_upload.is_some() || action.with_value(|action| action.is_some())
+    });
+    let start_upload = Callback::new(move |id: String| {
+        if let Some(custom_upload) = custom_upload {
+            update_file(&id, &|file| {
+                file.status = UploadFileStatus::Pending;
+                file.percentage = 0.0;
+            });
+            let file = file_list
+                .with_untracked(|file_list| file_list.iter().find(|file| file.id == id).cloned());
+            if let Some(file) = file {
+                custom_upload.call(vec![file]);
+            }
+            return;
+        }
+        let Some(action) = action.with_value(|action| action.as_ref().map(|a| a.get_untracked()))
+        else {
+            return;
+        };
+        let file = file_list.with_untracked(|file_list| {
+            file_list
+                .iter()
+                .find(|file| file.id == id)
+                .and_then(|file| file.file.clone())
+        });
+        let Some(file) = file else {
+            return;
+        };
+        update_file(&id, &|file| {
+            file.status = UploadFileStatus::Uploading;
+            file.percentage = 0.0;
+        });
+        let result = request::upload_file(
+            &action,
+            &headers.get_untracked(),
+            &name.get_untracked(),
+            &file,
+            {
+                let id = id.clone();
+                move |percentage| update_file(&id, &|file| file.percentage = percentage)
+            },
+            {
+                let id = id.clone();
+                move |is_ok| {
+                    requests.try_update_value(|requests| requests.remove(&id));
+                    update_file(&id, &|file| {
+                        if is_ok {
+                            file.status = UploadFileStatus::Finished;
+                            file.percentage = 100.0;
+                        } else {
+                            file.status = UploadFileStatus::Error;
+                        }
+                    });
+                }
+            },
+        );
+        match result {
+            Ok(xhr) => requests.update_value(|requests| {
+                requests.insert(id, xhr);
+            }),
+            Err(_) => update_file(&id, &|file| file.status = UploadFileStatus::Error),
+        }
+    });
+    let on_remove = Callback::new(move |id: String| {
+        if let Some(xhr) = requests
+            .try_update_value(|requests| requests.remove(&id))
+            .flatten()
+        {
+            _ = xhr.abort();
+        }
+        file_list.update(|file_list| file_list.retain(|file| file.id != id));
+    });
+
     let on_file_addition = move |files: FileList| {
+        let files = (0..files.length())
+            .filter_map(|index| files.get(index))
+            .filter(|file| match before_upload {
+                Some(before_upload) => before_upload.call(file.clone()),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+        if files.is_empty() {
+            return;
+        }
         if let Some(custom_request) = custom_request {
-            custom_request.call(files);
+            if let Some(files) = to_file_list(&files) {
+                custom_request.call(files);
+            }
+            return;
+        }
+        let files = files
+            .into_iter()
+            .map(UploadFileInfo::new)
+            .collect::<Vec<_>>();
+        if let Some(custom_upload) = custom_upload {
+            file_list.update(|file_list| file_list.extend(files.iter().cloned()));
+            custom_upload.call(files);
+            return;
+        }
+        let ids = files.iter().map(|file| file.id.clone()).collect::<Vec<_>>();
+        file_list.update(|file_list| file_list.extend(files));
+        for id in ids {
+            start_upload.call(id);
         }
     };
 
@@ -91,6 +286,19 @@ pub fn Upload(
             >
                 {children()}
             </div>
+            <Show when=move || show_file_list.get() && file_list.with(|file_list| !file_list.is_empty())>
+                <UploadFileList file_list=file_list.signal() can_retry on_retry=start_upload on_remove/>
+            </Show>
         </div>
     }
 }
+
+/// Collects the files accepted by `before_upload` into a `FileList` again.
+fn to_file_list(files: &[File]) -> Option<FileList> {
+    let data_transfer = DataTransfer::new().ok()?;
+    let items = data_transfer.items();
+    for file in files {
+        items.add_with_file(file).ok()?;
+    }
+    data_transfer.files()
+}
@@ -0,0 +1,86 @@
+use leptos::ev;
+use std::{cell::RefCell, rc::Rc};
+use thaw_utils::{add_event_listener_with_bool, EventListenerHandle};
+use wasm_bindgen::JsValue;
+use web_sys::{EventTarget, File, FormData, XmlHttpRequest};
+
+/// Sends `file` as `multipart/form-data` to `action`.
+///
+/// `on_progress` receives the uploaded percentage,
+/// `on_finish` is called once with whether the server accepted the file.
+pub(super) fn upload_file(
+    action: &str,
+    headers: &[(String, String)],
+    name: &str,
+    file: &File,
+    on_progress: impl Fn(f32) + 'static,
+    on_finish: impl Fn(bool) + 'static,
+) -> Result<XmlHttpRequest, JsValue> {
+    let xhr = XmlHttpRequest::new()?;
+    xhr.open_with_async("POST", action, true)?;
+    for (key, value) in headers {
+        xhr.set_request_header(key, value)?;
+    }
+    let form_data = FormData::new()?;
+    form_data.append_with_blob_and_filename(name, file, &file.name())?;
+
+    let handles = Rc::new(RefCell::new(Vec::<EventListenerHandle>::new()));
+    let remove_listeners = {
+        let handles = handles.clone();
+        move || {
+            for handle in handles.take() {
+                handle.remove();
+            }
+        }
+    };
+    let on_finish = Rc::new(on_finish);
+
+    let upload_target: EventTarget = xhr.upload()?.into();
+    let progress_handle = add_event_listener_with_bool(
+        upload_target,
+        ev::progress,
+        move |ev| {
+            if ev.length_computable() && ev.total() > 0.0 {
+                on_progress((ev.loaded() / ev.total() * 100.0) as f32);
+            }
+        },
+        false,
+    );
+    let xhr_target: EventTarget = xhr.clone().into();
+    let load_handle = add_event_listener_with_bool(
+        xhr_target.clone(),
+        ev::load,
+        {
+            let xhr = xhr.clone();
+            let on_finish = on_finish.clone();
+            let remove_listeners = remove_listeners.clone();
+            move |_| {
+                remove_listeners();
+                let status = xhr.status().unwrap_or_default();
+                on_finish((200..300).contains(&status));
+            }
+        },
+        false,
+    );
+    let error_handle = add_event_listener_with_bool(
+        xhr_target.clone(),
+        ev::error,
+        {
+            let remove_listeners = remove_listeners.clone();
+            move |_| {
+                remove_listeners();
+                on_finish(false);
+            }
+        },
+        false,
+    );
+    // An aborted request is not reported.
+    let abort_handle =
+        add_event_listener_with_bool(xhr_target, ev::abort, move |_| remove_listeners(), false);
+    handles
+        .borrow_mut()
+        .extend([progress_handle, load_handle, error_handle, abort_handle]);
+
+    xhr.send_with_opt_form_data(Some(&form_data))?;
+    Ok(xhr)
+}
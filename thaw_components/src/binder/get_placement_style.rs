@@ -1,7 +1,7 @@
 use leptos::window;
 use web_sys::DomRect;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum FollowerPlacement {
     Top,
     Bottom,
@@ -37,6 +37,17 @@ impl FollowerPlacement {
         }
     }
 
+    /// The placement for right-to-left layouts, the start and the end are swapped.
+    pub fn mirror(&self) -> Self {
+        match self {
+            Self::TopStart => Self::TopEnd,
+            Self::TopEnd => Self::TopStart,
+            Self::BottomStart => Self::BottomEnd,
+            Self::BottomEnd => Self::BottomStart,
+            placement => *placement,
+        }
+    }
+
     pub fn transform_origin(&self) -> &'static str {
         match self {
             Self::Top => "bottom center",
@@ -53,324 +64,266 @@ impl FollowerPlacement {
             Self::BottomEnd => "top right",
         }
     }
+
+    fn side(&self) -> Side {
+        match self {
+            Self::Top | Self::TopStart | Self::TopEnd => Side::Top,
+            Self::Bottom | Self::BottomStart | Self::BottomEnd => Side::Bottom,
+            Self::Left | Self::LeftStart | Self::LeftEnd => Side::Left,
+            Self::Right | Self::RightStart | Self::RightEnd => Side::Right,
+        }
+    }
+
+    fn align(&self) -> Align {
+        match self {
+            Self::Top | Self::Bottom | Self::Left | Self::Right => Align::Center,
+            Self::TopStart | Self::BottomStart | Self::LeftStart | Self::RightStart => Align::Start,
+            Self::TopEnd | Self::BottomEnd | Self::LeftEnd | Self::RightEnd => Align::End,
+        }
+    }
+
+    fn from_side_align(side: Side, align: Align) -> Self {
+        match (side, align) {
+            (Side::Top, Align::Center) => Self::Top,
+            (Side::Top, Align::Start) => Self::TopStart,
+            (Side::Top, Align::End) => Self::TopEnd,
+            (Side::Bottom, Align::Center) => Self::Bottom,
+            (Side::Bottom, Align::Start) => Self::BottomStart,
+            (Side::Bottom, Align::End) => Self::BottomEnd,
+            (Side::Left, Align::Center) => Self::Left,
+            (Side::Left, Align::Start) => Self::LeftStart,
+            (Side::Left, Align::End) => Self::LeftEnd,
+            (Side::Right, Align::Center) => Self::Right,
+            (Side::Right, Align::Start) => Self::RightStart,
+            (Side::Right, Align::End) => Self::RightEnd,
+        }
+    }
+}
+
+/// The side of the target the follower is placed on.
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// How the follower is aligned with the target along the side.
+#[derive(Clone, Copy)]
+enum Align {
+    Start,
+    Center,
+    End,
+}
+
+/// A rectangle relative to the viewport, in pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// The overlapping part of both rectangles, empty when they do not overlap.
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.right().min(other.right()) - x).max(0.0),
+            height: (self.bottom().min(other.bottom()) - y).max(0.0),
+        }
+    }
+}
+
+impl From<DomRect> for Rect {
+    fn from(rect: DomRect) -> Self {
+        Self {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+/// The steps applied after the follower was placed on the requested side.
+#[derive(Clone, Copy)]
+pub struct FollowerMiddleware {
+    /// Flips the follower to the opposite side when it overflows the boundary.
+    pub flip: bool,
+    /// Shifts the follower along the side to keep it inside the boundary.
+    pub shift: bool,
 }
 
+#[derive(PartialEq, Debug)]
 pub struct FollowerPlacementOffset {
     pub top: f64,
     pub left: f64,
-    pub transform: String,
+    /// The placement after flipping, which may differ from the requested one.
     pub placement: FollowerPlacement,
+    /// How far the follower was moved along the side to stay inside the
+    /// boundary, e.g. for an arrow that keeps pointing at the target.
+    pub shift: f64,
+    /// The width of the boundary left for the follower on its side of the target.
+    pub available_width: f64,
+    /// The height of the boundary left for the follower on its side of the target.
+    pub available_height: f64,
 }
 
+/// Positions the follower inside the viewport, or inside the part of `boundary_rect`
+/// that is visible in the viewport.
 pub fn get_follower_placement_offset(
     placement: FollowerPlacement,
     target_rect: DomRect,
     follower_rect: DomRect,
+    boundary_rect: Option<DomRect>,
+    middleware: FollowerMiddleware,
 ) -> Option<FollowerPlacementOffset> {
-    match placement {
-        FollowerPlacement::Top => {
-            let left = target_rect.x() + target_rect.width() / 2.0;
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y - follower_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top < 0.0 && target_y + target_height + follower_height <= inner_height {
-                    (target_y + target_height, FollowerPlacement::Bottom)
-                } else {
-                    (top, FollowerPlacement::Top)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateX(-50%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::TopStart => {
-            let left = target_rect.x();
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y - follower_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top < 0.0 && target_y + target_height + follower_height <= inner_height {
-                    (target_y + target_height, FollowerPlacement::BottomStart)
-                } else {
-                    (top, FollowerPlacement::TopStart)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::new(),
-                placement,
-            })
-        }
-        FollowerPlacement::TopEnd => {
-            let left = target_rect.x() + target_rect.width();
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y - follower_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top < 0.0 && target_y + target_height + follower_height <= inner_height {
-                    (target_y + target_height, FollowerPlacement::BottomEnd)
-                } else {
-                    (top, FollowerPlacement::TopEnd)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateX(-100%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::Left => {
-            let top = target_rect.y() + target_rect.height() / 2.0;
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x - follower_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left < 0.0 && target_x + target_width + follower_width > inner_width {
-                    (target_x + follower_width, FollowerPlacement::Right)
-                } else {
-                    (left, FollowerPlacement::Left)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateY(-50%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::LeftStart => {
-            let top = target_rect.y();
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x - follower_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left < 0.0 && target_x + target_width + follower_width > inner_width {
-                    (target_x + follower_width, FollowerPlacement::RightStart)
-                } else {
-                    (left, FollowerPlacement::LeftStart)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::new(),
-                placement,
-            })
-        }
-        FollowerPlacement::LeftEnd => {
-            let top = target_rect.y() + target_rect.height();
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x - follower_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left < 0.0 && target_x + target_width + follower_width > inner_width {
-                    (target_x + follower_width, FollowerPlacement::RightEnd)
-                } else {
-                    (left, FollowerPlacement::LeftEnd)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateY(-100%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::Right => {
-            let top = target_rect.y() + target_rect.height() / 2.0;
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x + target_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left + follower_width > inner_width && target_x - follower_width >= 0.0 {
-                    (target_x - follower_width, FollowerPlacement::Left)
-                } else {
-                    (left, FollowerPlacement::Right)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateY(-50%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::RightStart => {
-            let top = target_rect.y();
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x + target_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left + follower_width > inner_width && target_x - follower_width >= 0.0 {
-                    (target_x - follower_width, FollowerPlacement::LeftStart)
-                } else {
-                    (left, FollowerPlacement::RightStart)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::new(),
-                placement,
-            })
-        }
-        FollowerPlacement::RightEnd => {
-            let top = target_rect.y() + target_rect.height();
-            let (left, placement) = {
-                let follower_width = follower_rect.width();
-                let target_x = target_rect.x();
-                let target_width = target_rect.width();
-                let left = target_x + target_width;
-
-                let Some(inner_width) = window_inner_width() else {
-                    return None;
-                };
-
-                if left + follower_width > inner_width && target_x - follower_width >= 0.0 {
-                    (target_x - follower_width, FollowerPlacement::LeftEnd)
-                } else {
-                    (left, FollowerPlacement::RightEnd)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateY(-100%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::Bottom => {
-            let left = target_rect.x() + target_rect.width() / 2.0;
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y + target_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top + follower_height > inner_height && target_y - follower_height >= 0.0 {
-                    (target_y - follower_height, FollowerPlacement::Top)
-                } else {
-                    (top, FollowerPlacement::Bottom)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateX(-50%)"),
-                placement,
-            })
-        }
-        FollowerPlacement::BottomStart => {
-            let left = target_rect.x();
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y + target_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top + follower_height > inner_height && target_y - follower_height >= 0.0 {
-                    (target_y - follower_height, FollowerPlacement::TopStart)
-                } else {
-                    (top, FollowerPlacement::BottomStart)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::new(),
-                placement,
-            })
+    let viewport = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: window_inner_width()?,
+        height: window_inner_height()?,
+    };
+    let boundary = boundary_rect.map_or(viewport, |boundary_rect| {
+        Rect::from(boundary_rect).intersect(&viewport)
+    });
+    Some(compute_follower_placement_offset(
+        placement,
+        target_rect.into(),
+        follower_rect.into(),
+        boundary,
+        middleware,
+    ))
+}
+
+/// Places the follower on the requested side of the target. With `flip` it moves to
+/// the opposite side when it overflows the boundary and the opposite side fits it or
+/// has more room, with `shift` it is moved along the side to stay inside the boundary.
+pub fn compute_follower_placement_offset(
+    placement: FollowerPlacement,
+    target: Rect,
+    follower: Rect,
+    boundary: Rect,
+    middleware: FollowerMiddleware,
+) -> FollowerPlacementOffset {
+    let align = placement.align();
+    let mut side = placement.side();
+    if middleware.flip && overflows_side(side, target, follower, boundary) {
+        let opposite = side.opposite();
+        if !overflows_side(opposite, target, follower, boundary)
+            || side_space(opposite, target, boundary) > side_space(side, target, boundary)
+        {
+            side = opposite;
         }
-        FollowerPlacement::BottomEnd => {
-            let left = target_rect.x() + target_rect.width();
-            let (top, placement) = {
-                let follower_height = follower_rect.height();
-                let target_y = target_rect.y();
-                let target_height = target_rect.height();
-                let top = target_y + target_height;
-
-                let Some(inner_height) = window_inner_height() else {
-                    return None;
-                };
-
-                if top + follower_height > inner_height && target_y - follower_height >= 0.0 {
-                    (target_y - follower_height, FollowerPlacement::TopEnd)
-                } else {
-                    (top, FollowerPlacement::BottomEnd)
-                }
-            };
-            Some(FollowerPlacementOffset {
-                top,
-                left,
-                transform: String::from("translateX(-100%)"),
-                placement,
-            })
+    }
+    let shift_into = |start: f64, size: f64, boundary_start: f64, boundary_size: f64| {
+        if middleware.shift {
+            shift_into(start, size, boundary_start, boundary_size)
+        } else {
+            start
         }
+    };
+
+    let (top, left, shift) = if side.is_vertical() {
+        let top = match side {
+            Side::Top => target.y - follower.height,
+            _ => target.y + target.height,
+        };
+        let left = align_start(align, target.x, target.width, follower.width);
+        let shifted = shift_into(left, follower.width, boundary.x, boundary.width);
+        (top, shifted, shifted - left)
+    } else {
+        let left = match side {
+            Side::Left => target.x - follower.width,
+            _ => target.x + target.width,
+        };
+        let top = align_start(align, target.y, target.height, follower.height);
+        let shifted = shift_into(top, follower.height, boundary.y, boundary.height);
+        (shifted, left, shifted - top)
+    };
+    let space = side_space(side, target, boundary).max(0.0);
+    let (available_width, available_height) = if side.is_vertical() {
+        (boundary.width, space)
+    } else {
+        (space, boundary.height)
+    };
+
+    FollowerPlacementOffset {
+        top,
+        left,
+        placement: FollowerPlacement::from_side_align(side, align),
+        shift,
+        available_width,
+        available_height,
+    }
+}
+
+/// The room between the target and the boundary edge on `side`.
+fn side_space(side: Side, target: Rect, boundary: Rect) -> f64 {
+    match side {
+        Side::Top => target.y - boundary.y,
+        Side::Bottom => boundary.bottom() - target.bottom(),
+        Side::Left => target.x - boundary.x,
+        Side::Right => boundary.right() - target.right(),
     }
 }
 
+/// Whether the follower placed on `side` of the target crosses the boundary edge on that side.
+fn overflows_side(side: Side, target: Rect, follower: Rect, boundary: Rect) -> bool {
+    let size = if side.is_vertical() {
+        follower.height
+    } else {
+        follower.width
+    };
+    side_space(side, target, boundary) < size
+}
+
+/// The start of the follower along the side of the target.
+fn align_start(align: Align, target_start: f64, target_size: f64, follower_size: f64) -> f64 {
+    match align {
+        Align::Start => target_start,
+        Align::Center => target_start + (target_size - follower_size) / 2.0,
+        Align::End => target_start + target_size - follower_size,
+    }
+}
+
+/// Moves `start` so that `size` fits between the boundary edges, the start edge
+/// wins when it does not fit.
+fn shift_into(start: f64, size: f64, boundary_start: f64, boundary_size: f64) -> f64 {
+    start
+        .min(boundary_start + boundary_size - size)
+        .max(boundary_start)
+}
+
 fn window_inner_width() -> Option<f64> {
     let Ok(inner_width) = window().inner_width() else {
         return None;
@@ -390,3 +343,133 @@ fn window_inner_height() -> Option<f64> {
     };
     Some(inner_height)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compute_follower_placement_offset, FollowerMiddleware, FollowerPlacement,
+        FollowerPlacementOffset, Rect,
+    };
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 1000.0,
+        height: 800.0,
+    };
+    const FOLLOWER: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    const MIDDLEWARE: FollowerMiddleware = FollowerMiddleware {
+        flip: true,
+        shift: true,
+    };
+
+    fn place(placement: FollowerPlacement, target: Rect) -> FollowerPlacementOffset {
+        compute_follower_placement_offset(placement, target, FOLLOWER, VIEWPORT, MIDDLEWARE)
+    }
+
+    fn target(x: f64, y: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width: 100.0,
+            height: 40.0,
+        }
+    }
+
+    #[test]
+    fn placement_without_overflow() {
+        let offset = place(FollowerPlacement::Top, target(400.0, 300.0));
+        assert_eq!(offset.placement, FollowerPlacement::Top);
+        assert_eq!((offset.top, offset.left, offset.shift), (200.0, 350.0, 0.0));
+
+        let offset = place(FollowerPlacement::RightEnd, target(400.0, 300.0));
+        assert_eq!(offset.placement, FollowerPlacement::RightEnd);
+        assert_eq!((offset.top, offset.left), (240.0, 500.0));
+    }
+
+    #[test]
+    fn placement_flips_to_the_side_with_room() {
+        let offset = place(FollowerPlacement::TopStart, target(400.0, 50.0));
+        assert_eq!(offset.placement, FollowerPlacement::BottomStart);
+        assert_eq!((offset.top, offset.left), (90.0, 400.0));
+
+        let offset = place(FollowerPlacement::Left, target(100.0, 300.0));
+        assert_eq!(offset.placement, FollowerPlacement::Right);
+        assert_eq!(offset.left, 200.0);
+    }
+
+    #[test]
+    fn placement_shifts_along_the_side() {
+        let offset = place(FollowerPlacement::Bottom, target(920.0, 300.0));
+        assert_eq!(offset.placement, FollowerPlacement::Bottom);
+        assert_eq!((offset.left, offset.shift), (800.0, -70.0));
+
+        let offset = place(FollowerPlacement::Right, target(400.0, 10.0));
+        assert_eq!((offset.top, offset.shift), (0.0, 20.0));
+    }
+
+    #[test]
+    fn placement_middleware() {
+        let boundary = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 1000.0,
+            height: 300.0,
+        };
+        // Neither side fits, the one with more room is used.
+        let offset = compute_follower_placement_offset(
+            FollowerPlacement::Bottom,
+            target(400.0, 180.0),
+            Rect {
+                height: 200.0,
+                ..FOLLOWER
+            },
+            boundary,
+            MIDDLEWARE,
+        );
+        assert_eq!(offset.placement, FollowerPlacement::Top);
+        assert_eq!(
+            (offset.available_width, offset.available_height),
+            (1000.0, 180.0)
+        );
+
+        let offset = compute_follower_placement_offset(
+            FollowerPlacement::Bottom,
+            target(920.0, 250.0),
+            FOLLOWER,
+            boundary,
+            FollowerMiddleware {
+                flip: false,
+                shift: false,
+            },
+        );
+        assert_eq!(offset.placement, FollowerPlacement::Bottom);
+        assert_eq!((offset.left, offset.shift), (870.0, 0.0));
+        assert_eq!(offset.available_height, 10.0);
+    }
+
+    #[test]
+    fn rect_intersect() {
+        let rect = Rect {
+            x: -50.0,
+            y: 100.0,
+            width: 300.0,
+            height: 1000.0,
+        };
+        assert_eq!(
+            rect.intersect(&VIEWPORT),
+            Rect {
+                x: 0.0,
+                y: 100.0,
+                width: 250.0,
+                height: 700.0,
+            }
+        );
+    }
+}
@@ -3,10 +3,13 @@ mod get_placement_style;
 pub use get_placement_style::FollowerPlacement;
 
 use crate::Teleport;
-use get_placement_style::{get_follower_placement_offset, FollowerPlacementOffset};
+use get_placement_style::{
+    get_follower_placement_offset, FollowerMiddleware, FollowerPlacementOffset,
+};
 use leptos::{html::ElementDescriptor, leptos_dom::helpers::WindowListenerHandle, *};
 use thaw_utils::{
-    add_event_listener, get_scroll_parent, mount_style, with_hydration_off, EventListenerHandle,
+    add_event_listener_with_bool, mount_style, use_direction, use_theme_vars, with_hydration_off,
+    EventListenerHandle,
 };
 
 #[slot]
@@ -17,9 +20,49 @@ pub struct Follower {
     width: Option<FollowerWidth>,
     #[prop(into)]
     placement: FollowerPlacement,
+    /// Whether to flip to the opposite side of the target when there is no room.
+    #[prop(default = true)]
+    flip: bool,
+    /// Whether to shift along the side of the target to stay inside the boundary.
+    #[prop(default = true)]
+    shift: bool,
+    /// Whether to report the room left on the side of the target as the
+    /// `--thaw-follower-available-width` and `--thaw-follower-available-height` variables.
+    #[prop(default = true)]
+    size: bool,
+    /// The area the popup is kept in.
+    #[prop(optional, into)]
+    boundary: FollowerBoundary,
     children: Children,
 }
 
+/// The area the popup is kept in.
+#[derive(Clone, Default)]
+pub enum FollowerBoundary {
+    #[default]
+    Viewport,
+    /// The part of the element that is visible in the viewport.
+    Element(NodeRef<html::Div>),
+}
+
+impl Copy for FollowerBoundary {}
+
+impl From<NodeRef<html::Div>> for FollowerBoundary {
+    fn from(node_ref: NodeRef<html::Div>) -> Self {
+        Self::Element(node_ref)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FollowerInjection {
+    placement: ReadSignal<FollowerPlacement>,
+}
+
+/// The placement of the popup that contains the caller, after it was flipped.
+pub fn use_follower_placement() -> Option<ReadSignal<FollowerPlacement>> {
+    use_context::<FollowerInjection>().map(|injection| injection.placement)
+}
+
 #[derive(Clone)]
 pub enum FollowerWidth {
     /// The popup width is the same as the target DOM width.
@@ -66,6 +109,10 @@ pub fn Binder<El: ElementDescriptor + Clone + 'static>(
         show: follower_show,
         width: follower_width,
         placement: follower_placement,
+        flip: follower_flip,
+        shift: follower_shift,
+        size: follower_size,
+        boundary: follower_boundary,
         children: follower_children,
     } = follower;
 
@@ -73,28 +120,20 @@ pub fn Binder<El: ElementDescriptor + Clone + 'static>(
     let scrollable_element_handle_vec = store_value::<Vec<EventListenerHandle>>(vec![]);
     let resize_handle = store_value(None::<WindowListenerHandle>);
 
+    // Scroll events do not bubble, capturing them on the document catches every
+    // scroll container, including the page and the ones scrolled from code.
     let ensure_scroll_listener = move || {
-        let Some(el) = target_ref.get_untracked().map(|target| target.into_any()) else {
-            return;
-        };
-
-        let mut handle_vec = vec![];
-        let mut cursor = get_scroll_parent(&el);
-        loop {
-            if let Some(el) = cursor.take() {
-                cursor = get_scroll_parent(&el);
-
-                let handle = add_event_listener(el, ev::scroll, move |_| {
-                    if let Some(scroll_listener) = scroll_listener.get_value() {
-                        scroll_listener.call(());
-                    }
-                });
-                handle_vec.push(handle);
-            } else {
-                break;
-            }
-        }
-        scrollable_element_handle_vec.set_value(handle_vec);
+        let handle = add_event_listener_with_bool(
+            document(),
+            ev::scroll,
+            move |_| {
+                if let Some(scroll_listener) = scroll_listener.get_value() {
+                    scroll_listener.call(());
+                }
+            },
+            true,
+        );
+        scrollable_element_handle_vec.set_value(vec![handle]);
     };
 
     let add_scroll_listener = Callback::new(move |listener: Callback<()>| {
@@ -145,6 +184,12 @@ pub fn Binder<El: ElementDescriptor + Clone + 'static>(
             target_ref
             width=follower_width
             placement=follower_placement
+            middleware=FollowerMiddleware {
+                flip: follower_flip,
+                shift: follower_shift,
+            }
+            size=follower_size
+            boundary=follower_boundary
             add_scroll_listener
             remove_scroll_listener
             add_resize_listener
@@ -161,6 +206,9 @@ fn FollowerContainer<El: ElementDescriptor + Clone + 'static>(
     target_ref: NodeRef<El>,
     width: Option<FollowerWidth>,
     placement: FollowerPlacement,
+    middleware: FollowerMiddleware,
+    size: bool,
+    boundary: FollowerBoundary,
     #[prop(into)] add_scroll_listener: Callback<Callback<()>>,
     #[prop(into)] remove_scroll_listener: Callback<()>,
     #[prop(into)] add_resize_listener: Callback<Callback<()>>,
@@ -169,7 +217,17 @@ fn FollowerContainer<El: ElementDescriptor + Clone + 'static>(
 ) -> impl IntoView {
     let content_ref = create_node_ref::<html::Div>();
     let content_style = create_rw_signal(String::new());
-    let placement_str = create_rw_signal(placement.as_str());
+    // The follower is teleported out of the providers of the direction and the theme.
+    let dir = use_direction();
+    let theme_vars = use_theme_vars();
+    let placement = Signal::derive(move || {
+        if dir.is_some_and(|dir| dir.get().is_rtl()) {
+            placement.mirror()
+        } else {
+            placement
+        }
+    });
+    let actual_placement = create_rw_signal(placement.get_untracked());
     let sync_position: Callback<()> = Callback::new(move |_| {
         let Some(content_ref) = content_ref.get_untracked() else {
             return;
@@ -177,37 +235,70 @@ fn FollowerContainer<El: ElementDescriptor + Clone + 'static>(
         let Some(target_ref) = target_ref.get_untracked().map(|target| target.into_any()) else {
             return;
         };
-        let target_rect = target_ref.get_bounding_client_rect();
-        let content_rect = content_ref.get_bounding_client_rect();
-        let mut style = String::new();
-        if let Some(width) = width {
-            let width = match width {
-                FollowerWidth::Target => format!("width: {}px;", target_rect.width()),
-                FollowerWidth::MinTarget => format!("min-width: {}px;", target_rect.width()),
-                FollowerWidth::Px(width) => format!("width: {width}px;"),
-            };
-            style.push_str(&width);
-        }
-        if let Some(FollowerPlacementOffset {
-            top,
-            left,
-            transform,
-            placement,
-        }) = get_follower_placement_offset(placement, target_rect, content_rect)
-        {
-            placement_str.set(placement.as_str());
-            style.push_str(&format!(
-                "transform-origin: {};",
-                placement.transform_origin()
-            ));
-            style.push_str(&format!(
-                "transform: translateX({left}px) translateY({top}px) {transform};"
-            ));
-        } else {
-            logging::error!("Thaw-Binder: get_follower_placement_style return None");
-        }
+        let boundary_rect = match boundary {
+            FollowerBoundary::Viewport => None,
+            FollowerBoundary::Element(boundary_ref) => boundary_ref
+                .get_untracked()
+                .map(|boundary_ref| boundary_ref.get_bounding_client_rect()),
+        };
+        // The width and the available size change the size of the content,
+        // which is measured again once they are applied.
+        for _ in 0..2 {
+            let target_rect = target_ref.get_bounding_client_rect();
+            let content_rect = content_ref.get_bounding_client_rect();
+            let content_size = (content_rect.width(), content_rect.height());
+            let mut style = String::new();
+            if let Some(width) = width {
+                let width = match width {
+                    FollowerWidth::Target => format!("width: {}px;", target_rect.width()),
+                    FollowerWidth::MinTarget => format!("min-width: {}px;", target_rect.width()),
+                    FollowerWidth::Px(width) => format!("width: {width}px;"),
+                };
+                style.push_str(&width);
+            }
+            if let Some(FollowerPlacementOffset {
+                top,
+                left,
+                placement,
+                shift,
+                available_width,
+                available_height,
+            }) = get_follower_placement_offset(
+                placement.get_untracked(),
+                target_rect,
+                content_rect,
+                boundary_rect.clone(),
+                middleware,
+            ) {
+                if actual_placement.get_untracked() != placement {
+                    actual_placement.set(placement);
+                }
+                style.push_str(&format!(
+                    "transform-origin: {};",
+                    placement.transform_origin()
+                ));
+                style.push_str(&format!(
+                    "transform: translateX({left}px) translateY({top}px);"
+                ));
+                style.push_str(&format!("--thaw-follower-shift: {shift}px;"));
+                if size {
+                    style.push_str(&format!(
+                        "--thaw-follower-available-width: {available_width}px;"
+                    ));
+                    style.push_str(&format!(
+                        "--thaw-follower-available-height: {available_height}px;"
+                    ));
+                }
+            } else {
+                logging::error!("Thaw-Binder: get_follower_placement_style return None");
+            }
 
-        content_style.set(style);
+            content_style.set(style);
+            let content_rect = content_ref.get_bounding_client_rect();
+            if (content_rect.width(), content_rect.height()) == content_size {
+                break;
+            }
+        }
     });
 
     Effect::new(move |_| {
@@ -229,15 +320,27 @@ fn FollowerContainer<El: ElementDescriptor + Clone + 'static>(
         }
     });
 
+    provide_context(FollowerInjection {
+        placement: actual_placement.read_only(),
+    });
     let children = with_hydration_off(|| {
-        html::div().classes("thaw-binder-follower-container").child(
-            html::div()
-                .classes("thaw-binder-follower-content")
-                .attr("data-thaw-placement", move || placement_str.get())
-                .node_ref(content_ref)
-                .attr("style", move || content_style.get())
-                .child(children()),
-        )
+        html::div()
+            .classes("thaw-binder-follower-container")
+            .attr("dir", dir.map(|dir| move || dir.get().as_str()))
+            .attr(
+                "style",
+                theme_vars.map(|theme_vars| move || theme_vars.get()),
+            )
+            .child(
+                html::div()
+                    .classes("thaw-binder-follower-content")
+                    .attr("data-thaw-placement", move || {
+                        actual_placement.get().as_str()
+                    })
+                    .node_ref(content_ref)
+                    .attr("style", move || content_style.get())
+                    .child(children()),
+            )
     });
 
     view! { <Teleport element=children immediate=show/> }
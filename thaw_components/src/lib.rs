@@ -4,14 +4,20 @@ mod focus_trap;
 mod if_comp;
 mod option_comp;
 mod teleport;
+mod virtual_list;
 mod wave;
 
-pub use binder::{Binder, Follower, FollowerPlacement, FollowerWidth};
+pub use binder::{
+    use_follower_placement, Binder, Follower, FollowerBoundary, FollowerPlacement, FollowerWidth,
+};
 pub use css_transition::CSSTransition;
 pub use focus_trap::FocusTrap;
 pub use if_comp::{ElseIf, If, Then};
 pub use option_comp::OptionComp;
 pub use teleport::Teleport;
+pub use virtual_list::{
+    get_reveal_scroll_offset, get_virtual_range, VirtualList, VirtualListScroll, VirtualRange,
+};
 pub use wave::{Wave, WaveRef};
 
 use leptos::*;
@@ -11,6 +11,7 @@ pub fn Teleport(
 ) -> impl IntoView {
     cfg_if! { if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
         let mount_fn = StoredValue::new(None::<Box<dyn FnOnce() -> ()>>);
+        let theme_vars = thaw_utils::use_theme_vars();
 
         mount_fn.set_value(Some(Box::new(move || {
             let mount = mount.unwrap_or_else(|| {
@@ -31,6 +32,13 @@ pub fn Teleport(
                 let container = document()
                     .create_element("div")
                     .expect("element creation to work");
+                // The tokens of a nested theme are not inherited from outside of the provider.
+                if let Some(theme_vars) = theme_vars {
+                    let container = container.clone();
+                    create_render_effect(move |_| {
+                        _ = container.set_attribute("style", &theme_vars.get());
+                    });
+                }
 
                 thaw_utils::with_hydration_off(|| {
                     use leptos::leptos_dom::Mountable;
@@ -0,0 +1,217 @@
+use leptos::*;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VirtualRange {
+    /// Index of the first rendered item.
+    pub start: usize,
+    /// Index after the last rendered item.
+    pub end: usize,
+    /// Space taken by the items before `start`.
+    pub offset_before: f64,
+    /// Space taken by the items after `end`.
+    pub offset_after: f64,
+}
+
+/// Computes which items are visible when `len` items of `item_size` each are
+/// scrolled by `scroll_offset` inside a viewport of `viewport_size`.
+/// `overscan` extra items are kept on both sides to avoid flashes while scrolling.
+pub fn get_virtual_range(
+    len: usize,
+    item_size: f64,
+    scroll_offset: f64,
+    viewport_size: f64,
+    overscan: usize,
+) -> VirtualRange {
+    if len == 0 || item_size <= 0.0 {
+        return VirtualRange::default();
+    }
+    let scroll_offset = scroll_offset.max(0.0);
+    let first_visible = ((scroll_offset / item_size).floor() as usize).min(len - 1);
+    let visible_count = (viewport_size.max(0.0) / item_size).ceil() as usize + 1;
+
+    let start = first_visible.saturating_sub(overscan);
+    let end = (first_visible + visible_count + overscan).min(len);
+
+    VirtualRange {
+        start,
+        end,
+        offset_before: start as f64 * item_size,
+        offset_after: (len - end) as f64 * item_size,
+    }
+}
+
+/// The scroll offset at which the item at `index` is fully visible inside the
+/// viewport, `None` when it already is.
+pub fn get_reveal_scroll_offset(
+    index: usize,
+    item_size: f64,
+    scroll_offset: f64,
+    viewport_size: f64,
+) -> Option<f64> {
+    let item_start = index as f64 * item_size;
+    let item_end = item_start + item_size;
+    if item_start < scroll_offset {
+        Some(item_start)
+    } else if item_end > scroll_offset + viewport_size {
+        Some((item_end - viewport_size).ceil())
+    } else {
+        None
+    }
+}
+
+/// Tracks the scroll position of the container of a [`VirtualList`].
+#[derive(Clone, Copy)]
+pub struct VirtualListScroll {
+    container_ref: NodeRef<html::Div>,
+    item_size: f64,
+    scroll_offset: RwSignal<f64>,
+}
+
+impl VirtualListScroll {
+    pub fn new(container_ref: NodeRef<html::Div>, item_size: f64) -> Self {
+        Self {
+            container_ref,
+            item_size,
+            scroll_offset: RwSignal::new(0.0),
+        }
+    }
+
+    /// Scroll top of the container in px, passed to `scroll_offset` of [`VirtualList`].
+    pub fn scroll_offset(&self) -> Signal<f64> {
+        self.scroll_offset.into()
+    }
+
+    /// Called on the `scroll` event of the container.
+    pub fn on_scroll(&self) {
+        if let Some(container_el) = self.container_ref.get_untracked() {
+            self.scroll_offset.set(f64::from(container_el.scroll_top()));
+        }
+    }
+
+    /// Keeps the item at the index returned by `index` visible.
+    ///
+    /// Items outside the rendered window have no element to scroll into view,
+    /// so the container is scrolled to the computed position of the item.
+    pub fn reveal(&self, index: impl Fn() -> Option<usize> + 'static) {
+        let Self {
+            container_ref,
+            item_size,
+            ..
+        } = *self;
+        create_effect(move |_| {
+            let Some(index) = index() else {
+                return;
+            };
+            let Some(container_el) = container_ref.get() else {
+                return;
+            };
+            if let Some(scroll_offset) = get_reveal_scroll_offset(
+                index,
+                item_size,
+                f64::from(container_el.scroll_top()),
+                f64::from(container_el.client_height()),
+            ) {
+                container_el.set_scroll_top(scroll_offset as i32);
+            }
+        });
+    }
+}
+
+/// # Virtual List
+///
+/// Only renders the items inside the visible window of a scroll container.
+/// Every item must have the same size (`item_size`), the scroll position and
+/// the size of the container are passed in by the scrolling component.
+#[component]
+pub fn VirtualList<T, K, KF, CF, IV>(
+    #[prop(into)] each: MaybeSignal<Vec<T>>,
+    key: KF,
+    /// Height of each item in px.
+    item_size: f64,
+    /// Scroll top of the scroll container in px.
+    #[prop(into)]
+    scroll_offset: Signal<f64>,
+    /// Visible height of the scroll container in px.
+    #[prop(into)]
+    viewport_size: Signal<f64>,
+    #[prop(default = 4)] overscan: usize,
+    children: CF,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+    CF: Fn(T) -> IV + 'static,
+    IV: IntoView + 'static,
+{
+    let range = Memo::new({
+        let each = each.clone();
+        move |_| {
+            let len = each.with(|each| each.len());
+            get_virtual_range(
+                len,
+                item_size,
+                scroll_offset.get(),
+                viewport_size.get(),
+                overscan,
+            )
+        }
+    });
+
+    view! {
+        <div style=move || {
+            let range = range.get();
+            format!(
+                "padding-top: {}px; padding-bottom: {}px; box-sizing: border-box;",
+                range.offset_before,
+                range.offset_after,
+            )
+        }>
+            <For
+                each=move || {
+                    let VirtualRange { start, end, .. } = range.get();
+                    each.with(|each| each[start..end].to_vec())
+                }
+                key
+                children
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_reveal_scroll_offset, get_virtual_range, VirtualRange};
+
+    #[test]
+    fn virtual_range() {
+        assert_eq!(
+            get_virtual_range(0, 30.0, 0.0, 300.0, 2),
+            VirtualRange::default()
+        );
+
+        let range = get_virtual_range(1000, 30.0, 0.0, 300.0, 2);
+        assert_eq!((range.start, range.end), (0, 13));
+        assert_eq!(range.offset_before, 0.0);
+        assert_eq!(range.offset_after, 987.0 * 30.0);
+
+        let range = get_virtual_range(1000, 30.0, 3000.0, 300.0, 2);
+        assert_eq!((range.start, range.end), (98, 113));
+        assert_eq!(range.offset_before, 98.0 * 30.0);
+
+        let range = get_virtual_range(20, 30.0, 5000.0, 300.0, 2);
+        assert_eq!((range.start, range.end), (17, 20));
+        assert_eq!(range.offset_after, 0.0);
+    }
+
+    #[test]
+    fn reveal_scroll_offset() {
+        assert_eq!(get_reveal_scroll_offset(5, 30.0, 100.0, 300.0), None);
+        assert_eq!(get_reveal_scroll_offset(2, 30.0, 100.0, 300.0), Some(60.0));
+        assert_eq!(
+            get_reveal_scroll_offset(20, 30.0, 100.0, 300.0),
+            Some(330.0)
+        );
+    }
+}
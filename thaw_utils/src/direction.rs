@@ -0,0 +1,31 @@
+use leptos::*;
+
+/// Text and layout direction of the components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        *self == Self::Rtl
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DirectionInjection(pub Signal<Direction>);
+
+/// The direction set by the closest provider, `None` when no direction was set
+/// and the one of the document applies.
+pub fn use_direction() -> Option<Signal<Direction>> {
+    use_context::<DirectionInjection>().map(|injection| injection.0)
+}
@@ -1,7 +1,11 @@
 mod use_click_position;
 mod use_lock_html_scroll;
 mod use_next_frame;
+mod use_roving_focus;
 
 pub use use_click_position::use_click_position;
 pub use use_lock_html_scroll::use_lock_html_scroll;
 pub use use_next_frame::{use_next_frame, NextFrame};
+pub use use_roving_focus::{
+    get_roving_focus_index, use_roving_focus, RovingFocus, RovingFocusOrientation,
+};
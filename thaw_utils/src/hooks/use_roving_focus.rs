@@ -0,0 +1,221 @@
+use crate::{use_direction, Direction};
+use leptos::{ev, html, NodeRef, Signal};
+
+/// The arrow keys that move the focus between the items.
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum RovingFocusOrientation {
+    Horizontal,
+    #[default]
+    Vertical,
+    /// Both the horizontal and the vertical arrow keys, e.g. in a radio group.
+    Both,
+}
+
+/// Moves the focus between the items of a composite widget, like the tabs of a tab list,
+/// with the arrow, `Home` and `End` keys.
+/// The focused item is the only one with `tabindex="0"`, so that `Tab` leaves the widget.
+pub fn use_roving_focus(
+    container: NodeRef<html::Div>,
+    selector: &'static str,
+    orientation: RovingFocusOrientation,
+) -> RovingFocus {
+    RovingFocus {
+        container,
+        selector,
+        orientation,
+        click_on_focus: false,
+        direction: use_direction(),
+    }
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(not(any(feature = "csr", feature = "hydrate")), allow(dead_code))]
+pub struct RovingFocus {
+    container: NodeRef<html::Div>,
+    /// Selects the items inside the container, the ones that are hidden or
+    /// `aria-disabled` are skipped.
+    selector: &'static str,
+    orientation: RovingFocusOrientation,
+    click_on_focus: bool,
+    direction: Option<Signal<Direction>>,
+}
+
+impl RovingFocus {
+    /// Clicks the newly focused item, for the widgets whose selection follows the focus.
+    pub fn click_on_focus(mut self) -> Self {
+        self.click_on_focus = true;
+        self
+    }
+
+    pub fn on_keydown(&self, ev: ev::KeyboardEvent) {
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        {
+            use leptos::SignalGetUntracked;
+
+            let items = self.items();
+            let active_element = leptos::document().active_element();
+            let current = items.iter().position(|item| {
+                active_element
+                    .as_ref()
+                    .is_some_and(|active_element| item.is_same_node(Some(active_element)))
+            });
+            let is_rtl = self
+                .direction
+                .is_some_and(|direction| direction.get_untracked().is_rtl());
+            let Some(index) =
+                get_roving_focus_index(&ev.key(), self.orientation, is_rtl, current, items.len())
+            else {
+                return;
+            };
+            ev.prevent_default();
+            self.focus(&items, index);
+        }
+        #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+        {
+            let _ = ev;
+        }
+    }
+
+    /// Focuses the first item, e.g. when a menu was opened with the keyboard.
+    pub fn focus_first(&self) {
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        {
+            let items = self.items();
+            if !items.is_empty() {
+                self.focus(&items, 0);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    fn items(&self) -> Vec<web_sys::HtmlElement> {
+        use leptos::wasm_bindgen::JsCast;
+
+        let Some(container) = self.container.get_untracked() else {
+            return vec![];
+        };
+        let Ok(node_list) = container.query_selector_all(self.selector) else {
+            return vec![];
+        };
+        (0..node_list.length())
+            .filter_map(|index| node_list.item(index))
+            .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+            .filter(|item| {
+                // Hidden items have no offset parent.
+                item.offset_parent().is_some()
+                    && item.get_attribute("aria-disabled").as_deref() != Some("true")
+            })
+            .collect()
+    }
+
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    fn focus(&self, items: &[web_sys::HtmlElement], index: usize) {
+        for (item_index, item) in items.iter().enumerate() {
+            let tabindex = if item_index == index { "0" } else { "-1" };
+            let _ = item.set_attribute("tabindex", tabindex);
+        }
+        let item = &items[index];
+        let _ = item.focus();
+        if self.click_on_focus {
+            item.click();
+        }
+    }
+}
+
+/// The index of the item to focus after `key` was pressed, `None` when the key
+/// does not move the focus. The focus wraps around at both ends and the
+/// horizontal arrow keys are swapped for right-to-left text.
+pub fn get_roving_focus_index(
+    key: &str,
+    orientation: RovingFocusOrientation,
+    is_rtl: bool,
+    current: Option<usize>,
+    len: usize,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let horizontal = orientation != RovingFocusOrientation::Vertical;
+    let vertical = orientation != RovingFocusOrientation::Horizontal;
+    let (prev_key, next_key) = if is_rtl {
+        ("ArrowRight", "ArrowLeft")
+    } else {
+        ("ArrowLeft", "ArrowRight")
+    };
+    let is_prev = (horizontal && key == prev_key) || (vertical && key == "ArrowUp");
+    let is_next = (horizontal && key == next_key) || (vertical && key == "ArrowDown");
+
+    if is_next {
+        Some(current.map_or(0, |current| (current + 1) % len))
+    } else if is_prev {
+        Some(current.map_or(len - 1, |current| (current + len - 1) % len))
+    } else if key == "Home" {
+        Some(0)
+    } else if key == "End" {
+        Some(len - 1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_roving_focus_index, RovingFocusOrientation};
+
+    #[test]
+    fn roving_focus_index() {
+        use RovingFocusOrientation::*;
+
+        assert_eq!(
+            get_roving_focus_index("ArrowDown", Vertical, false, Some(1), 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowDown", Vertical, false, Some(2), 3),
+            Some(0)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowUp", Vertical, false, Some(0), 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowUp", Vertical, false, None, 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowRight", Vertical, false, Some(0), 3),
+            None
+        );
+
+        assert_eq!(
+            get_roving_focus_index("ArrowRight", Horizontal, false, Some(0), 3),
+            Some(1)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowRight", Horizontal, true, Some(0), 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowDown", Horizontal, false, Some(0), 3),
+            None
+        );
+
+        assert_eq!(
+            get_roving_focus_index("ArrowLeft", Both, false, Some(1), 3),
+            Some(0)
+        );
+        assert_eq!(
+            get_roving_focus_index("ArrowDown", Both, false, Some(1), 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("End", Both, false, Some(0), 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_roving_focus_index("Home", Both, false, None, 3),
+            Some(0)
+        );
+        assert_eq!(get_roving_focus_index("Home", Both, false, None, 0), None);
+    }
+}
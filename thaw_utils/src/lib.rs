@@ -1,23 +1,30 @@
 pub mod class_list;
+mod direction;
 mod dom;
 mod event_listener;
 mod hooks;
 mod on_click_outside;
 mod optional_prop;
 mod signals;
+mod theme_vars;
 mod throttle;
 mod time;
 
+pub use direction::{use_direction, Direction, DirectionInjection};
 pub use dom::{get_scroll_parent, mount_style};
 pub use event_listener::{
     add_event_listener, add_event_listener_with_bool, EventListenerHandle, IntoEventTarget,
 };
-pub use hooks::{use_click_position, use_lock_html_scroll, use_next_frame, NextFrame};
+pub use hooks::{
+    get_roving_focus_index, use_click_position, use_lock_html_scroll, use_next_frame,
+    use_roving_focus, NextFrame, RovingFocus, RovingFocusOrientation,
+};
 pub use on_click_outside::call_on_click_outside;
 pub use optional_prop::OptionalProp;
 pub use signals::{
     create_component_ref, ComponentRef, Model, OptionalMaybeSignal, SignalWatch, StoredMaybeSignal,
 };
+pub use theme_vars::{use_theme_vars, ThemeVarsInjection};
 pub use throttle::throttle;
 pub use time::now_date;
 
@@ -0,0 +1,12 @@
+use leptos::*;
+
+/// The design tokens of a nested theme provider, as a CSS declaration list.
+#[derive(Clone, Copy)]
+pub struct ThemeVarsInjection(pub Signal<String>);
+
+/// The tokens scoped by the closest nested theme provider, `None` when the
+/// tokens declared on `:root` apply. Teleported elements set them as their style
+/// since they are mounted outside of the provider.
+pub fn use_theme_vars() -> Option<Signal<String>> {
+    use_context::<ThemeVarsInjection>().map(|injection| injection.0)
+}